@@ -1,11 +1,15 @@
 //! This module contains the base elements of an OrbTk application (Application, WindowBuilder and Window).
 
-use std::sync::mpsc;
+use std::{path::PathBuf, sync::mpsc};
 
 use dces::prelude::Entity;
 
 use crate::{
-    core::{application::WindowAdapter, localization::*, *},
+    core::{
+        application::{Recording, WindowAdapter},
+        localization::*,
+        *,
+    },
     shell::{Shell, ShellRequest},
 };
 
@@ -17,6 +21,8 @@ pub struct Application {
     name: Box<str>,
     theme: Rc<Theme>,
     localization: Option<Rc<RefCell<Box<dyn Localization>>>>,
+    record_path: Option<PathBuf>,
+    replay: Option<Recording>,
 }
 
 impl Default for Application {
@@ -45,6 +51,19 @@ impl Application {
         self
     }
 
+    /// Records the inputs of the next created window and saves them to the given file
+    /// when the window is closed.
+    pub fn record_input(mut self, path: impl Into<PathBuf>) -> Self {
+        self.record_path = Some(path.into());
+        self
+    }
+
+    /// Replays the given recording into the next created window.
+    pub fn replay_input(mut self, recording: Recording) -> Self {
+        self.replay = Some(recording);
+        self
+    }
+
     /// Create a new application with the given name.
     pub fn from_name(name: impl Into<Box<str>>) -> Self {
         let (sender, receiver) = mpsc::channel();
@@ -55,12 +74,14 @@ impl Application {
             shell: Shell::new(receiver),
             theme: Rc::new(crate::widgets::themes::theme_orbtk::theme_default()),
             localization: None,
+            record_path: None,
+            replay: None,
         }
     }
 
     /// Creates a new window and add it to the application.
     pub fn window<F: Fn(&mut BuildContext) -> Entity + 'static>(mut self, create_fn: F) -> Self {
-        let (adapter, settings, receiver) = create_window(
+        let (mut adapter, settings, receiver) = create_window(
             self.name.clone(),
             &self.theme,
             self.request_sender.clone(),
@@ -68,6 +89,14 @@ impl Application {
             self.localization.clone(),
         );
 
+        if let Some(path) = self.record_path.take() {
+            adapter.record_to(path);
+        }
+
+        if let Some(recording) = self.replay.take() {
+            adapter.replay(recording);
+        }
+
         self.shell
             .create_window_from_settings(settings, adapter)
             .request_receiver(receiver)
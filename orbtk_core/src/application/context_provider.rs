@@ -3,6 +3,7 @@ use std::{
     collections::BTreeMap,
     rc::Rc,
     sync::mpsc,
+    time::Instant,
 };
 
 use dces::prelude::*;
@@ -14,11 +15,25 @@ use crate::{
     layout::*,
     localization::Localization,
     render_object::*,
-    shell::{ShellRequest, WindowRequest},
+    shell::{ShellRequest, WindowId, WindowRequest},
     utils::Point,
     widget_base::*,
 };
 
+/// Describes the owner of a window, that is the window and the widget
+/// that have opened it.
+#[derive(Clone, Debug)]
+pub struct WindowOwner {
+    /// Id of the owner window.
+    pub window_id: WindowId,
+
+    /// The widget that has opened the window. It receives the result of the window as message.
+    pub entity: Entity,
+
+    /// Message adapter of the owner window.
+    pub message_adapter: MessageAdapter,
+}
+
 /// Temporary solution to share dependencies and needs to be refactored.
 #[derive(Clone)]
 pub struct ContextProvider {
@@ -43,12 +58,24 @@ pub struct ContextProvider {
     /// Reference counted cells of mouse_positions defined as `points`
     pub mouse_position: Rc<Cell<Point>>,
 
+    /// The widget that has captured the mouse.
+    pub mouse_capture: Rc<Cell<Option<Entity>>>,
+
+    /// The recorded time of the replayed input or frame, `None` if the inputs are live.
+    pub clock: Rc<Cell<Option<Instant>>>,
+
     /// A window_sender object, used for multiparty session-typed communication.
     pub window_sender: mpsc::Sender<WindowRequest>,
 
     /// A shell_sender object, used for multiparty session-typed communication.
     pub shell_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
 
+    /// Unique id of the window.
+    pub window_id: WindowId,
+
+    /// The owner of the window, if the window is an owned window.
+    pub owner: Option<WindowOwner>,
+
     /// Holds the application name.
     pub application_name: String,
 
@@ -79,12 +106,22 @@ impl ContextProvider {
             event_adapter: EventAdapter::new(window_sender.clone()),
             message_adapter: MessageAdapter::new(window_sender.clone()),
             mouse_position: Rc::new(Cell::new(Point::new(0.0, 0.0))),
+            mouse_capture: Rc::new(Cell::new(None)),
+            clock: Rc::new(Cell::new(None)),
             window_sender,
             shell_sender,
+            window_id: WindowId::new(),
+            owner: None,
             application_name: application_name.into(),
             first_run: Rc::new(Cell::new(true)),
             raw_window_handle: None,
             localization,
         }
     }
+
+    /// Returns the current time. During a replay it is the recorded time, so time dependent
+    /// states like tooltips behave the same as in the recorded session.
+    pub fn now(&self) -> Instant {
+        self.clock.get().unwrap_or_else(Instant::now)
+    }
 }
@@ -0,0 +1,86 @@
+pub use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    rc::Rc,
+};
+
+use dces::prelude::*;
+
+use crate::{
+    event::*,
+    layout::{FixedSizeLayout, Layout},
+    proc_macros::WidgetCtx,
+    properties::*,
+    render_object::{DragVisualRenderObject, RenderObject},
+    theming::Selector,
+    utils::*,
+    widget,
+    widget_base::*,
+};
+
+/// Style key of the `DragVisual` widget.
+pub static STYLE_DRAG_VISUAL: &str = "drag_visual";
+
+widget!(
+    /// The `DragVisual` follows the mouse while data is dragged. It is part of the `Overlay`
+    /// and displays the text of the dragged data.
+    ///
+    /// **style:** `drag_visual`
+    DragVisual {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border radius property.
+        border_radius: f64,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or shares the text of the dragged data.
+        text: String,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the font size property.
+        font_size: f64,
+
+        /// Sets or shares the text offset.
+        offset: f64,
+
+        /// Indicates if the dragged data is accepted by the drop target under the mouse.
+        accepted: bool
+    }
+);
+
+impl Template for DragVisual {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("DragVisual")
+            .style(STYLE_DRAG_VISUAL)
+            .background("#3b434a")
+            .border_brush("#646464")
+            .border_width(1.0)
+            .foreground("#dfebf5")
+            .font("Roboto-Regular")
+            .font_size(12.0)
+            .min_width(32.0)
+            .min_height(20.0)
+            .visibility(Visibility::Collapsed)
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        DragVisualRenderObject.into()
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        FixedSizeLayout::new().into()
+    }
+}
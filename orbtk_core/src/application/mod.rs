@@ -1,9 +1,15 @@
 //! This module contains the base elements of an OrbTk application (Application, WindowBuilder and Window).
 
 pub use self::context_provider::*;
+pub use self::drag_visual::*;
 pub use self::overlay::*;
+pub use self::recording::*;
+pub use self::tooltip_visual::*;
 pub use self::window_adapter::*;
 
 mod context_provider;
+mod drag_visual;
 mod overlay;
+mod recording;
+mod tooltip_visual;
 mod window_adapter;
@@ -9,6 +9,7 @@ pub use std::{
 use dces::prelude::*;
 
 use crate::{
+    application::{DragVisual, TooltipVisual},
     event::*,
     layout::{AbsoluteLayout, Layout},
     proc_macros::WidgetCtx,
@@ -21,13 +22,27 @@ use crate::{
 
 widget!(
     /// The `Overlay` is used to draw its children on the top of all
-    /// other widgets in the tree.
-    Overlay
+    /// other widgets in the tree. It also contains the visual of drag
+    /// and drop operations and the visual of tooltips.
+    Overlay {
+        /// Sets or shares the entity of the drag visual.
+        drag_visual: u32,
+
+        /// Sets or shares the entity of the tooltip visual.
+        tooltip_visual: u32
+    }
 );
 
 impl Template for Overlay {
-    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+    fn template(self, _: Entity, ctx: &mut BuildContext) -> Self {
+        let drag_visual = DragVisual::new().build(ctx);
+        let tooltip_visual = TooltipVisual::new().build(ctx);
+
         self.name("Overlay")
+            .drag_visual(drag_visual.0)
+            .tooltip_visual(tooltip_visual.0)
+            .child(tooltip_visual)
+            .child(drag_visual)
     }
 
     fn layout(&self) -> Box<dyn Layout> {
@@ -0,0 +1,350 @@
+use std::{
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use ron::{
+    de::{from_reader, from_str},
+    ser::{to_string_pretty, PrettyConfig},
+};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::shell::{ButtonState, Key, MouseButton, PointerKind, PointerPhase};
+
+/// Used to report meaningful error messages when saving or loading a `Recording`.
+#[derive(Debug)]
+pub enum RecordingError {
+    Saved(String),
+    Loaded(String),
+}
+
+/// Returns a value or the error message.
+pub type RecordingResult<T> = Result<T, RecordingError>;
+
+/// Describes a single input the shell has passed to a window.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RecordedInput {
+    /// The active state of the window is changed.
+    Active(bool),
+
+    /// The state of a keyboard key is changed.
+    Key {
+        state: ButtonState,
+        key: Key,
+        text: String,
+    },
+
+    /// The mouse is moved.
+    MouseMove { x: f64, y: f64 },
+
+    /// The state of a mouse button is changed.
+    Mouse {
+        button: MouseButton,
+        state: ButtonState,
+        x: f64,
+        y: f64,
+    },
+
+    /// A pointer e.g. a finger on a touch screen begins, moves or ends its contact.
+    Pointer {
+        id: u64,
+        kind: PointerKind,
+        phase: PointerPhase,
+        x: f64,
+        y: f64,
+    },
+
+    /// The mouse wheel or trackpad is scrolled.
+    Scroll { delta_x: f64, delta_y: f64 },
+
+    /// The keyboard emits a text input.
+    TextInput(String),
+
+    /// The window is resized.
+    Resize { width: f64, height: f64 },
+
+    /// A file is dropped on the window.
+    FileDrop(String),
+
+    /// A text is dropped on the window.
+    TextDrop(String),
+
+    /// The user requests to close the window.
+    CloseRequested,
+
+    /// The application should quit.
+    Quit,
+
+    /// The window has updated its widget tree and rendered a frame.
+    Frame,
+}
+
+/// A recorded input together with the time it has occurred.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedEvent {
+    /// The time in milliseconds since the recording has started.
+    pub time: u64,
+
+    /// The recorded input.
+    pub input: RecordedInput,
+}
+
+/// A `Recording` contains the inputs of a window session in the order they have occurred.
+/// It is stored in `ron` file format and could be replayed into a window to reproduce
+/// the same widget state and frames.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Application::new()
+///     .record_input("session.ron")
+///     .window(|ctx| Window::new().child(MainView::new().build(ctx)).build(ctx))
+///     .run();
+///
+/// // later
+/// Application::new()
+///     .replay_input(Recording::load("session.ron").unwrap())
+///     .window(|ctx| Window::new().child(MainView::new().build(ctx)).build(ctx))
+///     .run();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Recording {
+    /// The recorded events ordered by time.
+    pub events: Vec<RecordedEvent>,
+}
+
+impl Recording {
+    /// Creates a new empty recording.
+    pub fn new() -> Self {
+        Recording::default()
+    }
+
+    /// Appends an input that has occurred at the given time in milliseconds.
+    pub fn push(&mut self, time: u64, input: RecordedInput) {
+        self.events.push(RecordedEvent { time, input });
+    }
+
+    /// Returns the time of the last event in milliseconds.
+    pub fn duration(&self) -> u64 {
+        self.events.last().map_or(0, |event| event.time)
+    }
+
+    /// Returns the number of recorded frames.
+    pub fn frames(&self) -> usize {
+        self.events
+            .iter()
+            .filter(|event| event.input == RecordedInput::Frame)
+            .count()
+    }
+
+    /// Reads a recording from a `ron` string.
+    pub fn from_ron(content: &str) -> RecordingResult<Self> {
+        from_str(content).map_err(|e| {
+            RecordingError::Loaded(format!("Recording.from_ron: Could not read data: {}", e))
+        })
+    }
+
+    /// Writes the recording to a `ron` string.
+    pub fn to_ron(&self) -> RecordingResult<String> {
+        to_string_pretty(self, PrettyConfig::default()).map_err(|e| {
+            RecordingError::Saved(format!("Recording.to_ron: Could not write data: {}", e))
+        })
+    }
+
+    /// Loads a recording from the given file.
+    pub fn load(path: impl AsRef<Path>) -> RecordingResult<Self> {
+        let path = path.as_ref();
+
+        let file = File::open(path).map_err(|_| {
+            RecordingError::Loaded(format!(
+                "Recording.load: Could not open recording file {:?}",
+                path
+            ))
+        })?;
+
+        from_reader(file).map_err(|_| {
+            RecordingError::Loaded(format!(
+                "Recording.load: Could not read data from recording file {:?}",
+                path
+            ))
+        })
+    }
+
+    /// Saves the recording to the given file.
+    pub fn save(&self, path: impl AsRef<Path>) -> RecordingResult<()> {
+        let path = path.as_ref();
+        let content = self.to_ron()?;
+
+        let mut file = File::create(path).map_err(|_| {
+            RecordingError::Saved(format!(
+                "Recording.save: Could not create recording file {:?}",
+                path
+            ))
+        })?;
+
+        file.write_all(content.as_bytes()).map_err(|_| {
+            RecordingError::Saved(format!(
+                "Recording.save: Could not write to recording file {:?}",
+                path
+            ))
+        })
+    }
+}
+
+/// Records the inputs of a window with the time they have occurred.
+#[derive(Debug)]
+pub(crate) struct InputRecorder {
+    start: Instant,
+    recording: Recording,
+    path: Option<PathBuf>,
+}
+
+impl InputRecorder {
+    pub fn new(path: Option<PathBuf>) -> Self {
+        InputRecorder {
+            start: Instant::now(),
+            recording: Recording::new(),
+            path,
+        }
+    }
+
+    pub fn record(&mut self, input: RecordedInput) {
+        let time = self.start.elapsed().as_millis() as u64;
+        self.recording.push(time, input);
+    }
+
+    /// Finishes the recording and saves it if a file is given.
+    pub fn finish(self) -> RecordingResult<Recording> {
+        if let Some(path) = &self.path {
+            self.recording.save(path)?;
+        }
+
+        Ok(self.recording)
+    }
+}
+
+/// Replays a recording frame by frame.
+#[derive(Debug)]
+pub(crate) struct InputReplayer {
+    start: Option<Instant>,
+    recording: Recording,
+    index: usize,
+}
+
+impl InputReplayer {
+    pub fn new(recording: Recording) -> Self {
+        InputReplayer {
+            start: None,
+            recording,
+            index: 0,
+        }
+    }
+
+    /// Returns `true` if all events are replayed.
+    pub fn is_finished(&self) -> bool {
+        self.index >= self.recording.events.len()
+    }
+
+    /// Returns the inputs up to the next recorded frame together with the time they have been
+    /// recorded at. The time of the recording starts with the first call.
+    pub fn next_frame(&mut self, now: Instant) -> Vec<(Instant, RecordedInput)> {
+        let start = *self.start.get_or_insert(now);
+        let mut inputs = vec![];
+
+        while let Some(event) = self.recording.events.get(self.index) {
+            self.index += 1;
+
+            if event.input == RecordedInput::Frame {
+                break;
+            }
+
+            inputs.push((
+                start + Duration::from_millis(event.time),
+                event.input.clone(),
+            ));
+        }
+
+        inputs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ron() {
+        let mut recording = Recording::new();
+        recording.push(
+            0,
+            RecordedInput::Resize {
+                width: 100.0,
+                height: 50.0,
+            },
+        );
+        recording.push(
+            12,
+            RecordedInput::Mouse {
+                button: MouseButton::Left,
+                state: ButtonState::Down,
+                x: 4.0,
+                y: 8.0,
+            },
+        );
+        recording.push(
+            20,
+            RecordedInput::Key {
+                state: ButtonState::Down,
+                key: Key::A(false),
+                text: "a".to_string(),
+            },
+        );
+        recording.push(21, RecordedInput::Frame);
+
+        let content = recording.to_ron().unwrap();
+
+        assert_eq!(Recording::from_ron(&content).unwrap(), recording);
+        assert_eq!(recording.duration(), 21);
+        assert_eq!(recording.frames(), 1);
+        assert!(Recording::from_ron("events: 5").is_err());
+    }
+
+    #[test]
+    fn test_replayer() {
+        let mut recording = Recording::new();
+        recording.push(0, RecordedInput::Active(true));
+        recording.push(0, RecordedInput::Frame);
+        recording.push(10, RecordedInput::TextInput("a".to_string()));
+        recording.push(16, RecordedInput::Frame);
+        recording.push(16, RecordedInput::Frame);
+        recording.push(30, RecordedInput::Quit);
+
+        let start = Instant::now();
+        let mut replayer = InputReplayer::new(recording);
+
+        assert_eq!(
+            replayer.next_frame(start),
+            vec![(start, RecordedInput::Active(true))]
+        );
+
+        // the recorded time is used, not the time the frame is replayed at
+        assert_eq!(
+            replayer.next_frame(start + Duration::from_millis(1)),
+            vec![(
+                start + Duration::from_millis(10),
+                RecordedInput::TextInput("a".to_string())
+            )]
+        );
+        assert!(replayer
+            .next_frame(start + Duration::from_millis(2))
+            .is_empty());
+        assert!(!replayer.is_finished());
+        assert_eq!(
+            replayer.next_frame(start + Duration::from_millis(3)),
+            vec![(start + Duration::from_millis(30), RecordedInput::Quit)]
+        );
+        assert!(replayer.is_finished());
+    }
+}
@@ -0,0 +1,139 @@
+pub use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    rc::Rc,
+};
+
+use dces::prelude::*;
+
+use crate::{
+    event::*,
+    layout::{FixedSizeLayout, Layout, PopupLayout},
+    proc_macros::WidgetCtx,
+    properties::*,
+    render_object::{Placement, PopupRenderObject, PopupTarget, RenderObject, TextRenderObject},
+    theming::Selector,
+    utils::*,
+    widget,
+    widget_base::*,
+};
+
+/// Style key of the `TooltipVisual` widget.
+pub static STYLE_TOOLTIP: &str = "tooltip";
+
+widget!(
+    /// The `TooltipVisual` displays the tooltip of the widget under the mouse. It is part of
+    /// the `Overlay` and is placed relative to the mouse position like a `Popup`.
+    ///
+    /// The first child displays the text of a text tooltip, the content of a custom tooltip is
+    /// appended as second child while the tooltip is shown.
+    ///
+    /// **style:** `tooltip`
+    TooltipVisual {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the border radius property.
+        border_radius: f64,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or shares the text of a text tooltip.
+        text: String,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the font size property.
+        font_size: f64,
+
+        /// Sets or shares the placement relative to the mouse position.
+        placement: Placement,
+
+        /// Sets or shares the distance between the mouse position and the tooltip.
+        offset: f64,
+
+        /// Sets or shares the mouse position the tooltip is placed to.
+        target: PopupTarget
+    }
+);
+
+impl Template for TooltipVisual {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let text = TooltipText::new()
+            .text(id)
+            .foreground(id)
+            .font(id)
+            .font_size(id)
+            .build(ctx);
+
+        self.name("TooltipVisual")
+            .style(STYLE_TOOLTIP)
+            .background("#3b434a")
+            .border_brush("#646464")
+            .border_width(1.0)
+            .padding(4.0)
+            .foreground("#dfebf5")
+            .font("Roboto-Regular")
+            .font_size(12.0)
+            .offset(4.0)
+            .h_align("start")
+            .v_align("start")
+            .visibility(Visibility::Collapsed)
+            .child(text)
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        PopupRenderObject::new().into()
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        PopupLayout::new().into()
+    }
+}
+
+widget!(
+    /// The `TooltipText` displays the text of a text tooltip inside of the `TooltipVisual`.
+    TooltipText {
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or shares the text property.
+        text: String,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the font size property.
+        font_size: f64,
+
+        /// Sets or shares the text offset.
+        offset: f64
+    }
+);
+
+impl Template for TooltipText {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("TooltipText")
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        TextRenderObject.into()
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        FixedSizeLayout::new().into()
+    }
+}
@@ -1,4 +1,9 @@
-use std::{cell::RefCell, sync::mpsc};
+use std::{
+    cell::RefCell,
+    path::PathBuf,
+    sync::mpsc,
+    time::{Duration, Instant},
+};
 
 use dces::prelude::*;
 
@@ -7,7 +12,7 @@ use crate::{
     event::*,
     localization::Localization,
     render,
-    services::{Clipboard, Settings},
+    services::{Clipboard, Settings, Shortcuts},
     shell,
     shell::{ShellRequest, WindowRequest, WindowSettings},
     systems::*,
@@ -21,11 +26,17 @@ use crate::{
 ///
 /// Each window has associated its unique tree of enities, an event pipeline and a shell.
 ///
+/// The inputs the shell passes to the window could be recorded and replayed later on to
+/// reproduce a session, see `Recording`.
 pub struct WindowAdapter {
     world: World<Tree, render::RenderContext2D>,
     ctx: ContextProvider,
     registry: Rc<RefCell<Registry>>,
     old_clipboard_value: Option<String>,
+    click_counter: ClickCounter,
+    gesture_recognizer: GestureRecognizer,
+    recorder: Option<InputRecorder>,
+    replayer: Option<InputReplayer>,
 }
 
 impl WindowAdapter {
@@ -40,6 +51,108 @@ impl WindowAdapter {
             ctx,
             registry,
             old_clipboard_value: None,
+            click_counter: ClickCounter::new(),
+            gesture_recognizer: GestureRecognizer::new(),
+            recorder: None,
+            replayer: None,
+        }
+    }
+
+    /// Starts to record the inputs of the window. A running recording is discarded.
+    pub fn start_recording(&mut self) {
+        self.recorder = Some(InputRecorder::new(None));
+    }
+
+    /// Starts to record the inputs of the window. The recording is saved to the given file
+    /// if it is stopped or the window is closed.
+    pub fn record_to(&mut self, path: impl Into<PathBuf>) {
+        self.recorder = Some(InputRecorder::new(Some(path.into())));
+    }
+
+    /// Stops the current recording and returns it, `None` if no recording is running. Returns an
+    /// error if the recording could not be saved to its file.
+    pub fn stop_recording(&mut self) -> RecordingResult<Option<Recording>> {
+        self.recorder
+            .take()
+            .map(|recorder| recorder.finish())
+            .transpose()
+    }
+
+    /// Returns `true` if the inputs of the window are recorded.
+    pub fn is_recording(&self) -> bool {
+        self.recorder.is_some()
+    }
+
+    /// Replays the given recording frame by frame: each frame of the shell applies the inputs
+    /// of the next recorded frame. The inputs of the shell are ignored until all recorded inputs
+    /// are replayed.
+    pub fn replay(&mut self, recording: Recording) {
+        self.replayer = Some(InputReplayer::new(recording));
+        let _ = self.ctx.window_sender.send(WindowRequest::Redraw);
+    }
+
+    /// Returns `true` if a recording is replayed.
+    pub fn is_replaying(&self) -> bool {
+        self.replayer.is_some()
+    }
+
+    /// Replays the given recording without a shell as fast as possible. A frame is rendered
+    /// into the given render context for each recorded frame and passed to `on_frame`.
+    /// Used to reproduce a session e.g. inside of a test.
+    pub fn replay_headless<F: FnMut(&render::RenderContext2D)>(
+        &mut self,
+        recording: &Recording,
+        render_context: &mut render::RenderContext2D,
+        mut on_frame: F,
+    ) {
+        let start = Instant::now();
+
+        for event in &recording.events {
+            self.ctx
+                .clock
+                .set(Some(start + Duration::from_millis(event.time)));
+
+            match &event.input {
+                RecordedInput::Frame => {
+                    self.frame(render_context);
+                    on_frame(render_context);
+                }
+                RecordedInput::Resize { width, height } => {
+                    render_context.resize(*width, *height);
+                    self.handle_input(event.input.clone());
+                }
+                input => self.handle_input(input.clone()),
+            }
+        }
+
+        self.ctx.clock.set(None);
+    }
+
+    // Returns the time of the current input. During a replay it is the recorded time.
+    fn now(&self) -> Instant {
+        self.ctx.now()
+    }
+
+    // Reads the double click time and distance from the window widget.
+    fn double_click_settings(&mut self) -> (Duration, f64) {
+        let root = self.root();
+        let store = self.world.entity_component_manager().component_store();
+
+        let time = store
+            .get::<u32>("double_click_time", root)
+            .map_or(DEFAULT_DOUBLE_CLICK_TIME, |time| *time);
+        let distance = store
+            .get::<f64>("double_click_distance", root)
+            .map_or(DEFAULT_DOUBLE_CLICK_DISTANCE, |distance| *distance);
+
+        (Duration::from_millis(time as u64), distance)
+    }
+
+    fn push_gestures(&mut self, gestures: Vec<GestureEvent>) {
+        let root = self.root();
+
+        for gesture in gestures {
+            self.ctx.event_adapter.push_event(root, gesture);
         }
     }
 
@@ -50,15 +163,265 @@ impl WindowAdapter {
             .root
             .unwrap()
     }
+
+    // Handles an input of the shell. Live inputs are ignored while a recording is replayed,
+    // except of the requests to close the window or to quit.
+    fn input(&mut self, input: RecordedInput) {
+        if self.replayer.is_some()
+            && !matches!(input, RecordedInput::CloseRequested | RecordedInput::Quit)
+        {
+            return;
+        }
+
+        self.handle_input(input);
+    }
+
+    // Applies the inputs of the replayed recording up to its next frame.
+    fn replay_frame(&mut self, render_context: &mut render::RenderContext2D) {
+        let inputs = match &mut self.replayer {
+            Some(replayer) => replayer.next_frame(Instant::now()),
+            None => return,
+        };
+
+        for (time, input) in inputs {
+            self.ctx.clock.set(Some(time));
+
+            if let RecordedInput::Resize { width, height } = input {
+                render_context.resize(width, height);
+                let _ = self
+                    .ctx
+                    .window_sender
+                    .send(WindowRequest::Resize(width as u32, height as u32));
+            }
+
+            self.handle_input(input);
+        }
+
+        self.ctx.clock.set(None);
+
+        if self
+            .replayer
+            .as_ref()
+            .map_or(false, |replayer| replayer.is_finished())
+        {
+            self.replayer = None;
+        } else {
+            // keeps the window updating until all inputs are replayed
+            let _ = self.ctx.window_sender.send(WindowRequest::Redraw);
+        }
+    }
+
+    fn frame(&mut self, render_context: &mut render::RenderContext2D) {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.record(RecordedInput::Frame);
+        }
+
+        let gestures = self.gesture_recognizer.update(self.now());
+        self.push_gestures(gestures);
+
+        if self.gesture_recognizer.is_pending() {
+            // keeps the window updating until a resting touch becomes a long press
+            let _ = self.ctx.window_sender.send(WindowRequest::Redraw);
+        }
+
+        self.world.run_with_context(render_context);
+    }
+
+    fn handle_input(&mut self, input: RecordedInput) {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.record(input.clone());
+        }
+
+        let root = self.root();
+
+        match input {
+            RecordedInput::Active(active) => {
+                self.ctx
+                    .event_adapter
+                    .push_event_direct(root, WindowEvent::ActiveChanged(active));
+            }
+            RecordedInput::Key { state, key, text } => {
+                let event = shell::KeyEvent { state, key, text };
+
+                match state {
+                    shell::ButtonState::Up => self
+                        .ctx
+                        .event_adapter
+                        .push_event(root, KeyUpEvent { event }),
+                    shell::ButtonState::Down => {
+                        self.ctx
+                            .event_adapter
+                            .push_event(root, KeyDownEvent { event });
+                    }
+                }
+            }
+            RecordedInput::MouseMove { x, y } => {
+                self.ctx.mouse_position.set(Point::new(x, y));
+                self.ctx.event_adapter.push_event(
+                    root,
+                    MouseMoveEvent {
+                        position: Point::new(x, y),
+                    },
+                );
+            }
+            RecordedInput::Mouse {
+                button,
+                state,
+                x,
+                y,
+            } => self.push_mouse_event(root, button, state, Point::new(x, y)),
+            RecordedInput::Pointer {
+                id,
+                kind,
+                phase,
+                x,
+                y,
+            } => self.push_pointer_event(root, id, kind, phase, Point::new(x, y)),
+            RecordedInput::Scroll { delta_x, delta_y } => {
+                self.ctx.event_adapter.push_event(
+                    root,
+                    ScrollEvent {
+                        delta: Point::new(delta_x, delta_y),
+                    },
+                );
+            }
+            RecordedInput::TextInput(text) => {
+                self.ctx
+                    .event_adapter
+                    .push_event(root, TextInputEvent { text });
+            }
+            RecordedInput::Resize { width, height } => {
+                self.ctx
+                    .event_adapter
+                    .push_event_direct(root, WindowEvent::Resize { width, height });
+            }
+            RecordedInput::FileDrop(file_name) => {
+                self.ctx.event_adapter.push_event(
+                    root,
+                    DropFileEvent {
+                        file_name,
+                        position: self.ctx.mouse_position.get(),
+                    },
+                );
+            }
+            RecordedInput::TextDrop(text) => {
+                self.ctx.event_adapter.push_event(
+                    root,
+                    DropTextEvent {
+                        text,
+                        position: self.ctx.mouse_position.get(),
+                    },
+                );
+            }
+            RecordedInput::CloseRequested => {
+                self.ctx
+                    .event_adapter
+                    .push_event_direct(root, WindowEvent::CloseRequested);
+            }
+            RecordedInput::Quit => {
+                self.ctx
+                    .event_adapter
+                    .push_event_direct(root, SystemEvent::Quit);
+            }
+            RecordedInput::Frame => {}
+        }
+    }
+
+    fn push_mouse_event(
+        &mut self,
+        root: Entity,
+        button: shell::MouseButton,
+        state: shell::ButtonState,
+        position: Point,
+    ) {
+        match state {
+            shell::ButtonState::Up => {
+                let click_count = self.click_counter.count();
+
+                self.ctx.event_adapter.push_event(
+                    root,
+                    MouseUpEvent {
+                        position,
+                        button,
+                        click_count,
+                    },
+                );
+                self.ctx.event_adapter.push_event(
+                    root,
+                    GlobalMouseUpEvent {
+                        position,
+                        button,
+                        click_count,
+                    },
+                );
+            }
+            shell::ButtonState::Down => {
+                let (max_time, max_distance) = self.double_click_settings();
+                let now = self.now();
+                let click_count =
+                    self.click_counter
+                        .press(button, position, now, max_time, max_distance);
+
+                self.ctx.event_adapter.push_event(
+                    root,
+                    MouseDownEvent {
+                        position,
+                        button,
+                        click_count,
+                    },
+                )
+            }
+        }
+    }
+
+    fn push_pointer_event(
+        &mut self,
+        root: Entity,
+        id: u64,
+        kind: shell::PointerKind,
+        phase: shell::PointerPhase,
+        position: Point,
+    ) {
+        let now = self.now();
+        let pointer = Pointer { id, kind, position };
+
+        let gestures = match phase {
+            shell::PointerPhase::Begin => {
+                self.ctx
+                    .event_adapter
+                    .push_event(root, TouchBeginEvent { pointer });
+                self.gesture_recognizer.touch_begin(pointer, now)
+            }
+            shell::PointerPhase::Move => {
+                self.ctx
+                    .event_adapter
+                    .push_event(root, TouchMoveEvent { pointer });
+                self.gesture_recognizer.touch_move(pointer, now)
+            }
+            shell::PointerPhase::End => {
+                self.ctx
+                    .event_adapter
+                    .push_event(root, TouchEndEvent { pointer });
+                self.gesture_recognizer.touch_end(pointer, now)
+            }
+        };
+
+        self.push_gestures(gestures);
+    }
+}
+
+impl Drop for WindowAdapter {
+    fn drop(&mut self) {
+        // saves a running recording, the error could not be returned on drop
+        if let Err(RecordingError::Saved(message)) = self.stop_recording() {
+            eprintln!("{}", message);
+        }
+    }
 }
 
 impl shell::WindowAdapter for WindowAdapter {
     fn active(&mut self, active: bool) {
-        let root = self.root();
-
-        self.ctx
-            .event_adapter
-            .push_event_direct(root, WindowEvent::ActiveChanged(active));
+        self.input(RecordedInput::Active(active));
     }
 
     fn clipboard_update(&mut self, value: &mut Option<String>) {
@@ -81,110 +444,76 @@ impl shell::WindowAdapter for WindowAdapter {
         }
     }
 
+    fn close_requested(&mut self) -> bool {
+        self.input(RecordedInput::CloseRequested);
+
+        // the window is closed by the event state system if no handler vetoes the request.
+        false
+    }
+
     fn file_drop_event(&mut self, file_name: String) {
-        let root = self.root();
-        self.ctx.event_adapter.push_event(
-            root,
-            DropFileEvent {
-                file_name,
-                position: self.mouse_position(),
-            },
-        );
+        self.input(RecordedInput::FileDrop(file_name));
     }
 
     fn key_event(&mut self, event: shell::KeyEvent) {
-        let root = self.root();
-        match event.state {
-            shell::ButtonState::Up => self
-                .ctx
-                .event_adapter
-                .push_event(root, KeyUpEvent { event }),
-            shell::ButtonState::Down => {
-                self.ctx
-                    .event_adapter
-                    .push_event(root, KeyDownEvent { event });
-            }
-        }
+        self.input(RecordedInput::Key {
+            state: event.state,
+            key: event.key,
+            text: event.text,
+        });
     }
 
     fn mouse(&mut self, x: f64, y: f64) {
-        let root = self.root();
-        self.ctx.mouse_position.set(Point::new(x, y));
-        self.ctx.event_adapter.push_event(
-            root,
-            MouseMoveEvent {
-                position: Point::new(x, y),
-            },
-        );
+        self.input(RecordedInput::MouseMove { x, y });
     }
 
     fn mouse_event(&mut self, event: shell::MouseEvent) {
-        let root = self.root();
-        match event.state {
-            shell::ButtonState::Up => {
-                self.ctx.event_adapter.push_event(
-                    root,
-                    MouseUpEvent {
-                        position: event.position,
-                        button: event.button,
-                    },
-                );
-                self.ctx.event_adapter.push_event(
-                    root,
-                    GlobalMouseUpEvent {
-                        position: event.position,
-                        button: event.button,
-                    },
-                );
-            }
-            shell::ButtonState::Down => self.ctx.event_adapter.push_event(
-                root,
-                MouseDownEvent {
-                    position: event.position,
-                    button: event.button,
-                },
-            ),
-        }
+        self.input(RecordedInput::Mouse {
+            button: event.button,
+            state: event.state,
+            x: event.position.x(),
+            y: event.position.y(),
+        });
     }
 
     fn mouse_position(&self) -> Point {
         self.ctx.mouse_position.get()
     }
 
-    fn quit_event(&mut self) {
-        let root = self.root();
+    fn pointer_event(&mut self, event: shell::PointerEvent) {
+        // mouse pointers are handled by mouse and mouse_event
+        if event.kind == shell::PointerKind::Mouse {
+            return;
+        }
+
+        self.input(RecordedInput::Pointer {
+            id: event.id,
+            kind: event.kind,
+            phase: event.phase,
+            x: event.position.x(),
+            y: event.position.y(),
+        });
+    }
 
-        self.ctx
-            .event_adapter
-            .push_event_direct(root, SystemEvent::Quit);
+    fn quit_event(&mut self) {
+        self.input(RecordedInput::Quit);
     }
 
     fn resize(&mut self, width: f64, height: f64) {
-        let root = self.root();
-        self.ctx
-            .event_adapter
-            .push_event_direct(root, WindowEvent::Resize { width, height });
+        self.input(RecordedInput::Resize { width, height });
     }
 
     fn run(&mut self, render_context: &mut render::RenderContext2D) {
-        self.world.run_with_context(render_context);
+        self.replay_frame(render_context);
+        self.frame(render_context);
     }
 
     fn scroll(&mut self, delta_x: f64, delta_y: f64) {
-        let root = self.root();
-        self.ctx.event_adapter.push_event(
-            root,
-            ScrollEvent {
-                delta: Point::new(delta_x, delta_y),
-            },
-        );
+        self.input(RecordedInput::Scroll { delta_x, delta_y });
     }
 
     fn text_input(&mut self, text: String) {
-        let root = self.root();
-        self.ctx
-            .event_adapter
-            .push_event(root, TextInputEvent { text });
+        self.input(RecordedInput::TextInput(text));
     }
 
     fn set_raw_window_handle(&mut self, raw_window_handle: raw_window_handle::RawWindowHandle) {
@@ -192,14 +521,7 @@ impl shell::WindowAdapter for WindowAdapter {
     }
 
     fn text_drop_event(&mut self, text: String) {
-        let root = self.root();
-        self.ctx.event_adapter.push_event(
-            root,
-            DropTextEvent {
-                text,
-                position: self.ctx.mouse_position.get(),
-            },
-        );
+        self.input(RecordedInput::TextDrop(text));
     }
 }
 
@@ -214,6 +536,29 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
     request_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
     create_fn: F,
     localization: Option<Rc<RefCell<Box<dyn Localization>>>>,
+) -> (WindowAdapter, WindowSettings, mpsc::Receiver<WindowRequest>) {
+    create_owned_window(
+        app_name,
+        theme,
+        request_sender,
+        create_fn,
+        localization,
+        None,
+        false,
+    )
+}
+
+/// Creates a new `WindowAdapter` like `create_window`. If an owner is given,
+/// the window will be closed together with the owner window. A modal window
+/// blocks the input of its owner window as long as it is open.
+pub fn create_owned_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
+    app_name: impl Into<String>,
+    theme: &Rc<Theme>,
+    request_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
+    create_fn: F,
+    localization: Option<Rc<RefCell<Box<dyn Localization>>>>,
+    owner: Option<WindowOwner>,
+    modal: bool,
 ) -> (WindowAdapter, WindowSettings, mpsc::Receiver<WindowRequest>) {
     #[cfg(not(feature = "debug"))]
     let _debug = false;
@@ -225,8 +570,10 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
 
     let registry = Rc::new(RefCell::new(Registry::new()));
 
-    let context_provider =
+    let mut context_provider =
         ContextProvider::new(sender, request_sender, app_name.clone(), localization);
+    let owner_id = owner.as_ref().map(|owner| owner.window_id);
+    context_provider.owner = owner;
 
     // Register the window settings via the context_provider.
     if app_name.is_empty() {
@@ -246,6 +593,22 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
         .borrow_mut()
         .register("clipboard", Clipboard::new());
 
+    // Register the keyboard shortcuts of the window.
+    registry
+        .borrow_mut()
+        .register("shortcuts", Shortcuts::new());
+
+    // Register the layout debugger of the window.
+    registry
+        .borrow_mut()
+        .register("layout_debugger", LayoutDebugger::new());
+
+    // Register the event tracer of the window.
+    #[cfg(feature = "debug")]
+    registry
+        .borrow_mut()
+        .register("event_tracer", EventTracer::new());
+
     // Assing an Overlay, that draws the root window on top of all
     // other childs of the window widget tree.
     let window = {
@@ -322,6 +685,9 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
             .component_store()
             .get::<bool>("always_on_top", window)
             .unwrap(),
+        id: context_provider.window_id,
+        owner: owner_id,
+        modal: modal && owner_id.is_some(),
         position: (position.x(), position.y()),
         size: (constraint.width(), constraint.height()),
         fonts,
@@ -357,6 +723,9 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
             context_provider.clone(),
             registry.clone(),
             RefCell::new(vec![]),
+            RefCell::new(vec![]),
+            RefCell::new(DragDropState::default()),
+            RefCell::new(TooltipState::default()),
         ))
         .with_priority(0)
         .build();
@@ -375,7 +744,10 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
         .build();
 
     world
-        .create_system(RenderSystem::new(context_provider.clone()))
+        .create_system(RenderSystem::new(
+            context_provider.clone(),
+            registry.clone(),
+        ))
         .with_priority(3)
         .build();
 
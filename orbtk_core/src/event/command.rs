@@ -0,0 +1,47 @@
+use std::rc::Rc;
+
+use crate::{
+    prelude::*,
+    proc_macros::{Event, IntoHandler},
+};
+
+/// `CommandEvent` occurs if the key chord of a shortcut registered in the `Shortcuts` service
+/// is pressed. It is sent to the focused widget and bubbles up to the window until it is handled.
+#[derive(Event, Clone)]
+pub struct CommandEvent {
+    /// The name of the command.
+    pub command: String,
+}
+
+pub type CommandHandlerFn = dyn Fn(&mut StatesContext, &str) -> bool + 'static;
+
+/// Used to handle command events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct CommandEventHandler {
+    handler: Rc<CommandHandlerFn>,
+}
+
+impl EventHandler for CommandEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<CommandEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, event.command.as_str())
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<CommandEvent>()
+    }
+}
+
+/// Implement this trait if your widget should handle commands of keyboard shortcuts.
+pub trait CommandHandler: Sized + Widget {
+    /// Inserts a command handler. The handler returns `true` if the command is handled.
+    fn on_command<H: Fn(&mut StatesContext, &str) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(CommandEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
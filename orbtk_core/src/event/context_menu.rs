@@ -0,0 +1,65 @@
+use std::rc::Rc;
+
+use crate::{
+    prelude::*,
+    proc_macros::{Event, IntoHandler},
+    utils::Point,
+};
+
+/// Describes the input that has requested a context menu.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ContextMenuSource {
+    /// The right mouse button is released.
+    Mouse,
+
+    /// The menu key or `Shift+F10` is pressed.
+    Keyboard,
+}
+
+/// `ContextMenuEvent` occurs if the right mouse button is released or the menu key or `Shift+F10`
+/// is pressed. It starts at the widget under the mouse (or at the focused widget if it is raised by
+/// the keyboard) and bubbles up to the window until it is handled.
+#[derive(Event, Clone)]
+pub struct ContextMenuEvent {
+    /// The position the context menu should be shown at. If the event is raised by the keyboard
+    /// it is the center of the focused widget.
+    pub position: Point,
+
+    /// The input that has requested the context menu.
+    pub source: ContextMenuSource,
+}
+
+pub type ContextMenuHandlerFn = dyn Fn(&mut StatesContext, Point) -> bool + 'static;
+
+/// Used to handle context menu events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct ContextMenuEventHandler {
+    handler: Rc<ContextMenuHandlerFn>,
+}
+
+impl EventHandler for ContextMenuEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<ContextMenuEvent>()
+            .ok()
+            .map_or(false, |event| (self.handler)(state_context, event.position))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<ContextMenuEvent>()
+    }
+}
+
+/// Implement this trait if your widget should show a context menu.
+pub trait ContextMenuHandler: Sized + Widget {
+    /// Inserts a context menu handler. The handler returns `true` if the context menu is handled,
+    /// otherwise the event bubbles up to the parent.
+    fn on_context_menu<H: Fn(&mut StatesContext, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(ContextMenuEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
@@ -0,0 +1,368 @@
+use std::{any::Any, cell::RefCell, rc::Rc, sync::Arc};
+
+use dces::prelude::*;
+
+use crate::{
+    prelude::*,
+    proc_macros::{Event, IntoHandler},
+    utils::*,
+};
+
+/// Distance in pixels the mouse has to be moved with pressed left button to start a drag operation.
+pub const DRAG_THRESHOLD: f64 = 4.0;
+
+/// Describes the data that is dragged from a drag source to a drop target.
+///
+/// The payload could be of any type. Drop targets use `payload` or `is` to check if they
+/// accept the dragged data. The text is displayed by the drag visual.
+#[derive(Clone)]
+pub struct DragData {
+    source: Entity,
+    text: String,
+    payload: Arc<dyn Any + Send + Sync>,
+}
+
+impl DragData {
+    /// Creates new drag data with the given payload.
+    pub fn new<P: Any + Send + Sync>(payload: P) -> Self {
+        DragData {
+            source: Entity::default(),
+            text: String::default(),
+            payload: Arc::new(payload),
+        }
+    }
+
+    /// Builder method that sets the text that is displayed while dragging.
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.text = text.into();
+        self
+    }
+
+    /// Gets the entity of the drag source. Is set after the data is provided by the drag start handler.
+    pub fn source(&self) -> Entity {
+        self.source
+    }
+
+    /// Gets the text that is displayed while dragging.
+    pub fn text(&self) -> &str {
+        self.text.as_str()
+    }
+
+    /// Gets a reference of the payload if it is of the requested type.
+    pub fn payload<P: Any>(&self) -> Option<&P> {
+        self.payload.downcast_ref::<P>()
+    }
+
+    /// Checks if the payload is of the requested type.
+    pub fn is<P: Any>(&self) -> bool {
+        self.payload.is::<P>()
+    }
+}
+
+impl std::fmt::Debug for DragData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DragData")
+            .field("source", &self.source)
+            .field("text", &self.text)
+            .finish()
+    }
+}
+
+/// `DragStartEvent` occurs if the mouse is moved with pressed left button over a drag source.
+#[derive(Event)]
+pub struct DragStartEvent {
+    /// The position where the left mouse button was pressed.
+    pub position: Point,
+
+    data: RefCell<Option<DragData>>,
+}
+
+impl DragStartEvent {
+    /// Creates a new drag start event.
+    pub fn new(position: Point) -> Self {
+        DragStartEvent {
+            position,
+            data: RefCell::new(None),
+        }
+    }
+
+    /// Takes the drag data that is provided by a drag source.
+    pub fn take_data(&self) -> Option<DragData> {
+        self.data.borrow_mut().take()
+    }
+}
+
+/// `DragEnterEvent` occurs if the dragged data is moved over a drop target.
+#[derive(Event)]
+pub struct DragEnterEvent {
+    /// The dragged data.
+    pub data: DragData,
+
+    /// The current mouse position.
+    pub position: Point,
+}
+
+/// `DragOverEvent` occurs if the dragged data is moved inside of a drop target.
+#[derive(Event)]
+pub struct DragOverEvent {
+    /// The dragged data.
+    pub data: DragData,
+
+    /// The current mouse position.
+    pub position: Point,
+}
+
+/// `DragLeaveEvent` occurs if the dragged data leaves a drop target.
+#[derive(Event)]
+pub struct DragLeaveEvent {
+    /// The dragged data.
+    pub data: DragData,
+
+    /// The current mouse position.
+    pub position: Point,
+}
+
+/// `DragDropEvent` occurs if the dragged data is dropped on a drop target, that has accepted the data.
+#[derive(Event)]
+pub struct DragDropEvent {
+    /// The dropped data.
+    pub data: DragData,
+
+    /// The mouse position of the drop.
+    pub position: Point,
+}
+
+/// `DragEndEvent` is sent to the drag source after the drag operation is finished.
+#[derive(Event)]
+pub struct DragEndEvent {
+    /// The dragged data.
+    pub data: DragData,
+
+    /// `true` if the data was dropped on a drop target, that has accepted it.
+    pub accepted: bool,
+}
+
+/// Defines the drag start handler function.
+pub type DragStartHandlerFn = dyn Fn(&mut StatesContext, Point) -> Option<DragData> + 'static;
+
+/// Defines the handler function of drop targets. The drag over handler returns `true` if the data is accepted.
+pub type DragHandlerFn = dyn Fn(&mut StatesContext, &DragData, Point) -> bool + 'static;
+
+/// Defines the drag end handler function.
+pub type DragEndHandlerFn = dyn Fn(&mut StatesContext, &DragData, bool) + 'static;
+
+/// Used to handle drag start events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct DragStartEventHandler {
+    handler: Rc<DragStartHandlerFn>,
+}
+
+impl EventHandler for DragStartEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event_box: &EventBox) -> bool {
+        if let Ok(event) = event_box.downcast_ref::<DragStartEvent>() {
+            if let Some(mut data) = (self.handler)(state_context, event.position) {
+                data.source = event_box.source;
+                *event.data.borrow_mut() = Some(data);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DragStartEvent>()
+    }
+}
+
+/// Used to handle drag end events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct DragEndEventHandler {
+    handler: Rc<DragEndHandlerFn>,
+}
+
+impl EventHandler for DragEndEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<DragEndEvent>() {
+            (self.handler)(state_context, &event.data, event.accepted);
+            return true;
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DragEndEvent>()
+    }
+}
+
+/// Used to handle drag enter events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct DragEnterEventHandler {
+    handler: Rc<DragHandlerFn>,
+}
+
+impl EventHandler for DragEnterEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<DragEnterEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, &event.data, event.position)
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DragEnterEvent>()
+    }
+}
+
+/// Used to handle drag over events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct DragOverEventHandler {
+    handler: Rc<DragHandlerFn>,
+}
+
+impl EventHandler for DragOverEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<DragOverEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, &event.data, event.position)
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DragOverEvent>()
+    }
+}
+
+/// Used to handle drag leave events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct DragLeaveEventHandler {
+    handler: Rc<DragHandlerFn>,
+}
+
+impl EventHandler for DragLeaveEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<DragLeaveEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, &event.data, event.position)
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DragLeaveEvent>()
+    }
+}
+
+/// Used to handle drag drop events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct DragDropEventHandler {
+    handler: Rc<DragHandlerFn>,
+}
+
+impl EventHandler for DragDropEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<DragDropEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, &event.data, event.position)
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DragDropEvent>()
+    }
+}
+
+/// Implement this trait if the data of your widget could be dragged.
+pub trait DragSourceHandler: Sized + Widget {
+    /// Inserts a drag start handler. The handler returns the data that should be dragged
+    /// or `None` if no drag operation should be started.
+    fn on_drag_start<H: Fn(&mut StatesContext, Point) -> Option<DragData> + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(DragStartEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a drag end handler. The handler is called with `true` if the data was accepted by a drop target.
+    fn on_drag_end<H: Fn(&mut StatesContext, &DragData, bool) + 'static>(self, handler: H) -> Self {
+        self.insert_handler(DragEndEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
+/// Implement this trait if your widget should accept dragged data.
+///
+/// A widget is only a drop target if it has a drag over handler. The drag over handler is called on
+/// each mouse move and returns `true` to accept the dragged data, which is signaled by the drag visual.
+pub trait DropTargetHandler: Sized + Widget {
+    /// Inserts a drag enter handler.
+    fn on_drag_enter<H: Fn(&mut StatesContext, &DragData, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(DragEnterEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a drag over handler.
+    fn on_drag_over<H: Fn(&mut StatesContext, &DragData, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(DragOverEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a drag leave handler.
+    fn on_drag_leave<H: Fn(&mut StatesContext, &DragData, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(DragLeaveEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a drag drop handler. Is only called if the data was accepted by the target.
+    fn on_drag_drop<H: Fn(&mut StatesContext, &DragData, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(DragDropEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Item(usize);
+
+    #[test]
+    fn test_payload() {
+        let data = DragData::new(Item(3)).with_text("item");
+
+        assert_eq!(data.source(), Entity::default());
+        assert_eq!(data.text(), "item");
+        assert!(data.is::<Item>());
+        assert!(!data.is::<String>());
+        assert_eq!(data.payload::<Item>(), Some(&Item(3)));
+        assert_eq!(data.payload::<String>(), None);
+    }
+}
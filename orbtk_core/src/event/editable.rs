@@ -4,7 +4,7 @@ use dces::prelude::*;
 
 use super::*;
 
-use crate::{proc_macros::*, widget_base::*};
+use crate::{proc_macros::*, properties::Blocks, widget_base::*};
 
 crate::trigger_event!(
     ActivateEvent,
@@ -55,6 +55,91 @@ pub trait SelectionChangedHandler: Sized + Widget {
     }
 }
 
+/// Structure to handle events that occures, if an item of a widget is moved from one index to another.
+#[derive(Clone, Event)]
+pub struct ReorderedEvent(pub Entity, pub usize, pub usize);
+
+/// Used to define a reordered callback. Is called with the widget, the old and the new index.
+pub type ReorderedHandlerFn = dyn Fn(&mut StatesContext, Entity, usize, usize) + 'static;
+
+/// Structure for the handler, that is used if items are reordered.
+#[derive(IntoHandler)]
+pub struct ReorderedEventHandler {
+    /// A reference counted handler.
+    pub handler: Rc<ReorderedHandlerFn>,
+}
+
+impl EventHandler for ReorderedEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<ReorderedEvent>() {
+            (self.handler)(states, event.0, event.1, event.2);
+            return true;
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<ReorderedEvent>()
+    }
+}
+
+/// Methods for the `ReorderedHandler` type.
+pub trait ReorderedHandler: Sized + Widget {
+    /// Inserts a handler that is called if an item is moved by drag and drop.
+    fn on_reorder<H: Fn(&mut StatesContext, Entity, usize, usize) + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(ReorderedEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
+/// Structure to handle events that occures, if a `GridSplitter` has resized the blocks of its grid.
+/// Contains the splitter and the resized columns or rows.
+#[derive(Clone, Event)]
+pub struct SplitterChangedEvent(pub Entity, pub Blocks);
+
+/// Used to define a splitter changed callback. Is called with the splitter and the resized blocks.
+pub type SplitterChangedHandlerFn = dyn Fn(&mut StatesContext, Entity, Blocks) + 'static;
+
+/// Structure for the handler, that is used if a splitter has resized the blocks of its grid.
+#[derive(IntoHandler)]
+pub struct SplitterChangedEventHandler {
+    /// A reference counted handler.
+    pub handler: Rc<SplitterChangedHandlerFn>,
+}
+
+impl EventHandler for SplitterChangedEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<SplitterChangedEvent>() {
+            (self.handler)(states, event.0, event.1.clone());
+            return true;
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<SplitterChangedEvent>()
+    }
+}
+
+/// Methods for the `SplitterChangedHandler` type.
+pub trait SplitterChangedHandler: Sized + Widget {
+    /// Inserts a handler that is called if the user has finished to resize the blocks.
+    fn on_splitter_changed<H: Fn(&mut StatesContext, Entity, Blocks) + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(SplitterChangedEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
 /// Structure to handle event changes, if a property of a widget is updated.
 #[derive(Clone, Event)]
 pub struct ChangedEvent(pub Entity, pub String);
@@ -63,6 +63,64 @@ impl EventAdapter {
         self.redraw();
     }
 
+    /// Sets the priority of the events of type `E`. Events with a higher priority are
+    /// processed first.
+    pub fn set_priority<E: Event>(&self, priority: EventPriority) {
+        self.event_queue
+            .lock()
+            .expect("EventAdapter::set_priority: Cannot lock event queue.")
+            .set_priority::<E>(priority);
+    }
+
+    /// Defines if consecutive events of type `E` with the same source are coalesced to the
+    /// latest event. `MouseMoveEvent`s are coalesced by default.
+    pub fn set_coalesced<E: Event>(&self, coalesced: bool) {
+        self.event_queue
+            .lock()
+            .expect("EventAdapter::set_coalesced: Cannot lock event queue.")
+            .set_coalesced::<E>(coalesced);
+    }
+
+    /// Sets the maximum number of events that are processed per frame, the remaining events are
+    /// processed in the next frame. `None` disables the bound.
+    pub fn set_max_events_per_frame(&self, max_events_per_frame: Option<usize>) {
+        self.event_queue
+            .lock()
+            .expect("EventAdapter::set_max_events_per_frame: Cannot lock event queue.")
+            .set_max_events_per_frame(max_events_per_frame);
+    }
+
+    // Starts a new frame of event processing.
+    pub(crate) fn start_frame(&self) {
+        self.event_queue
+            .lock()
+            .expect("EventAdapter::start_frame: Cannot lock event queue.")
+            .start_frame();
+    }
+
+    // Returns `true` if there are no more events to process in the current frame. If events are
+    // left because the bound of the frame is reached, a redraw is requested to process them.
+    pub(crate) fn is_frame_done(&self) -> bool {
+        let exhausted = {
+            let event_queue = self
+                .event_queue
+                .lock()
+                .expect("EventAdapter::is_frame_done: Cannot lock event queue.");
+
+            if event_queue.is_empty() {
+                return true;
+            }
+
+            event_queue.is_frame_exhausted()
+        };
+
+        if exhausted {
+            self.redraw();
+        }
+
+        exhausted
+    }
+
     fn redraw(&self) {
         if let Some(window_sender) = &self.window_sender {
             window_sender.send(WindowRequest::Redraw).unwrap();
@@ -94,7 +152,7 @@ impl EventAdapter {
 }
 
 /// Reader is a thread safe iterator that dequeue events from the
-/// event adapter.
+/// event adapter until the bound of events per frame is reached.
 pub struct EventReader {
     event_adapter: EventAdapter,
 }
@@ -107,6 +165,6 @@ impl Iterator for EventReader {
             .event_queue
             .lock()
             .expect("DequeueIterator::next: Cannot lock event queue.")
-            .dequeue()
+            .dequeue_in_frame()
     }
 }
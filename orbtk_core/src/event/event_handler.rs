@@ -1,4 +1,9 @@
-use crate::{event::EventBox, widget_base::StatesContext};
+use std::{marker::PhantomData, rc::Rc};
+
+use crate::{
+    event::{Event, EventBox},
+    widget_base::StatesContext,
+};
 
 /// This trait is used to define an event handler.
 pub trait EventHandler {
@@ -7,4 +12,100 @@ pub trait EventHandler {
 
     /// Check if the handler could handle the given event box.
     fn handles_event(&self, event: &EventBox) -> bool;
+
+    /// Returns `true` if the handler is called in the tunneling phase, before the regular handlers.
+    fn is_preview(&self) -> bool {
+        false
+    }
+}
+
+/// Defines the preview handler function of events of type `E`.
+pub type PreviewHandlerFn<E> = dyn Fn(&mut StatesContext, &E) -> bool + 'static;
+
+/// Used to handle events of type `E` in the tunneling phase from the root to the target.
+/// If the handler returns `true`, the event is marked as handled and no other handler is called.
+/// Could be attached to a widget with `on_preview`.
+pub struct PreviewEventHandler<E: Event> {
+    handler: Rc<PreviewHandlerFn<E>>,
+    _event: PhantomData<E>,
+}
+
+impl<E: Event> PreviewEventHandler<E> {
+    /// Creates a new preview handler.
+    pub fn new<H: Fn(&mut StatesContext, &E) -> bool + 'static>(handler: H) -> Self {
+        PreviewEventHandler {
+            handler: Rc::new(handler),
+            _event: PhantomData,
+        }
+    }
+}
+
+impl<E: Event> EventHandler for PreviewEventHandler<E> {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<E>()
+            .ok()
+            .map_or(false, |event| (self.handler)(state_context, event))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<E>()
+    }
+
+    fn is_preview(&self) -> bool {
+        true
+    }
+}
+
+impl<E: Event> From<PreviewEventHandler<E>> for Rc<dyn EventHandler> {
+    fn from(handler: PreviewEventHandler<E>) -> Rc<dyn EventHandler> {
+        Rc::new(handler)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dces::prelude::Entity;
+
+    use super::*;
+    use crate::event::{EventQueue, EventStrategy};
+
+    struct PreviewTestEvent;
+
+    impl Event for PreviewTestEvent {
+        fn strategy(&self) -> EventStrategy {
+            EventStrategy::TopDown
+        }
+    }
+
+    struct OtherTestEvent;
+
+    impl Event for OtherTestEvent {}
+
+    #[test]
+    fn test_preview_handler() {
+        let handler = PreviewEventHandler::<PreviewTestEvent>::new(|_, _| true);
+
+        assert!(handler.is_preview());
+        assert!(handler.handles_event(&EventBox::new(
+            PreviewTestEvent,
+            EventStrategy::BottomUp,
+            Entity(0)
+        )));
+        assert!(!handler.handles_event(&EventBox::new(
+            OtherTestEvent,
+            EventStrategy::BottomUp,
+            Entity(0)
+        )));
+    }
+
+    #[test]
+    fn test_event_strategy() {
+        let mut queue = EventQueue::new();
+        queue.register_event(PreviewTestEvent, Entity(0));
+        queue.register_event(OtherTestEvent, Entity(0));
+
+        assert_eq!(queue.dequeue().unwrap().strategy, EventStrategy::TopDown);
+        assert_eq!(queue.dequeue().unwrap().strategy, EventStrategy::BottomUp);
+    }
 }
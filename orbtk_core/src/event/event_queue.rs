@@ -1,8 +1,11 @@
-use std::any::{Any, TypeId};
+use std::{
+    any::{Any, TypeId},
+    collections::{HashMap, HashSet, VecDeque},
+};
 
 use dces::prelude::Entity;
 
-use super::{Event, EventStrategy};
+use super::{Event, EventStrategy, MouseMoveEvent};
 
 #[derive(Debug)]
 pub enum EventError {
@@ -14,6 +17,7 @@ pub enum EventError {
 pub struct EventBox {
     event: Box<dyn Any + Send>,
     event_type: TypeId,
+    event_name: &'static str,
     /// The source of the entity
     pub source: Entity,
     /// The stratagy handlef for an entity
@@ -27,6 +31,7 @@ impl EventBox {
             event: Box::new(event),
             source,
             event_type: TypeId::of::<E>(),
+            event_name: std::any::type_name::<E>(),
             strategy,
         }
     }
@@ -41,6 +46,11 @@ impl EventBox {
         self.event_type
     }
 
+    /// Returns the type name of the event.
+    pub fn event_name(&self) -> &'static str {
+        self.event_name
+    }
+
     /// Downcasts the box to an concrete event.
     pub fn downcast<E: Event>(self) -> Result<E, EventError> {
         if self.event_type == TypeId::of::<E>() {
@@ -60,10 +70,65 @@ impl EventBox {
     }
 }
 
+/// The default number of events that are processed per frame.
+pub const DEFAULT_MAX_EVENTS_PER_FRAME: usize = 500;
+
+/// Defines the priority of an event type inside of the `EventQueue`. Events with a higher
+/// priority are dequeued first, events with the same priority in the order they are registered.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum EventPriority {
+    /// Dequeued after all other events.
+    Low,
+
+    /// The default priority of all events.
+    #[default]
+    Normal,
+
+    /// Dequeued before all other events.
+    High,
+}
+
+impl EventPriority {
+    fn index(self) -> usize {
+        match self {
+            EventPriority::High => 0,
+            EventPriority::Normal => 1,
+            EventPriority::Low => 2,
+        }
+    }
+}
+
 /// The  `EventQueue` is used to register and read new events.
-#[derive(Default, Debug)]
+///
+/// Events of coalesced types (by default `MouseMoveEvent`) replace the last registered event if
+/// it has the same type and source, e.g. a flood of mouse moves is reduced to the latest position.
+/// The number of events that are dequeued per frame could be bounded, the remaining events are
+/// kept for the next frame.
+#[derive(Debug)]
 pub struct EventQueue {
-    event_queue: Vec<EventBox>,
+    queues: [VecDeque<EventBox>; 3],
+    priorities: HashMap<TypeId, EventPriority>,
+    coalesced: HashSet<TypeId>,
+    // the priority queue of the last registered event, if it could be coalesced
+    coalesce_last: Option<usize>,
+    max_events_per_frame: Option<usize>,
+    frame_events: usize,
+}
+
+impl Default for EventQueue {
+    fn default() -> Self {
+        let mut coalesced = HashSet::new();
+        coalesced.insert(TypeId::of::<MouseMoveEvent>());
+
+        EventQueue {
+            queues: [VecDeque::new(), VecDeque::new(), VecDeque::new()],
+            priorities: HashMap::new(),
+            coalesced,
+            coalesce_last: None,
+            max_events_per_frame: Some(DEFAULT_MAX_EVENTS_PER_FRAME),
+            frame_events: 0,
+        }
+    }
 }
 
 impl EventQueue {
@@ -72,9 +137,78 @@ impl EventQueue {
         Self::default()
     }
 
+    /// Sets the priority of the events of type `E`.
+    pub fn set_priority<E: Event>(&mut self, priority: EventPriority) {
+        self.priorities.insert(TypeId::of::<E>(), priority);
+    }
+
+    /// Gets the priority of the events of type `E`.
+    pub fn priority<E: Event>(&self) -> EventPriority {
+        self.priority_of(TypeId::of::<E>())
+    }
+
+    fn priority_of(&self, event_type: TypeId) -> EventPriority {
+        self.priorities
+            .get(&event_type)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Defines if consecutive events of type `E` with the same source are coalesced to the
+    /// latest event.
+    pub fn set_coalesced<E: Event>(&mut self, coalesced: bool) {
+        if coalesced {
+            self.coalesced.insert(TypeId::of::<E>());
+        } else {
+            self.coalesced.remove(&TypeId::of::<E>());
+        }
+    }
+
+    /// Sets the maximum number of events that are dequeued per frame. `None` disables the bound.
+    pub fn set_max_events_per_frame(&mut self, max_events_per_frame: Option<usize>) {
+        self.max_events_per_frame = max_events_per_frame;
+    }
+
+    /// Resets the number of events that are dequeued in the current frame.
+    pub fn start_frame(&mut self) {
+        self.frame_events = 0;
+    }
+
+    /// Returns `true` if the bound of events for the current frame is reached.
+    pub fn is_frame_exhausted(&self) -> bool {
+        self.max_events_per_frame
+            .map_or(false, |max| self.frame_events >= max)
+    }
+
+    fn enqueue(&mut self, event: EventBox) {
+        let priority = self.priority_of(event.event_type).index();
+
+        let coalesce = self.coalesced.contains(&event.event_type)
+            && self.coalesce_last == Some(priority)
+            && self.queues[priority].back().map_or(false, |last| {
+                last.event_type == event.event_type
+                    && last.source == event.source
+                    && last.strategy == event.strategy
+            });
+
+        if coalesce {
+            self.queues[priority].pop_back();
+        }
+
+        self.coalesce_last = if self.coalesced.contains(&event.event_type) {
+            Some(priority)
+        } else {
+            None
+        };
+
+        self.queues[priority].push_back(event);
+    }
+
     /// Appends a new event box.
     pub fn append(&mut self, other: &mut Vec<EventBox>) {
-        self.event_queue.append(other);
+        for event in other.drain(..) {
+            self.enqueue(event);
+        }
     }
 
     /// Registers an event with a given event strategy and a source (Entity of a widget) where the event should start.
@@ -84,33 +218,49 @@ impl EventQueue {
         strategy: EventStrategy,
         source: Entity,
     ) {
-        self.event_queue
-            .push(EventBox::new::<E>(event, strategy, source));
+        self.enqueue(EventBox::new::<E>(event, strategy, source));
     }
 
     // todo rename to enqueue event
     pub fn register_event<E: Event + Send>(&mut self, event: E, source: Entity) {
-        self.event_queue
-            .push(EventBox::new::<E>(event, EventStrategy::BottomUp, source));
+        let strategy = event.strategy();
+        self.enqueue(EventBox::new::<E>(event, strategy, source));
     }
 
     /// Dequeue an event.
     pub fn dequeue(&mut self) -> Option<EventBox> {
-        if !self.event_queue.is_empty() {
-            return Some(self.event_queue.remove(0));
+        for priority in 0..self.queues.len() {
+            if let Some(event) = self.queues[priority].pop_front() {
+                // the coalesced event is dequeued
+                if self.queues[priority].is_empty() && self.coalesce_last == Some(priority) {
+                    self.coalesce_last = None;
+                }
+
+                self.frame_events += 1;
+                return Some(event);
+            }
         }
 
         None
     }
 
+    /// Dequeue an event if the bound of events for the current frame is not reached.
+    pub fn dequeue_in_frame(&mut self) -> Option<EventBox> {
+        if self.is_frame_exhausted() {
+            return None;
+        }
+
+        self.dequeue()
+    }
+
     /// Returns the number of events in the `EventQueue`.
     pub fn len(&self) -> usize {
-        self.event_queue.len()
+        self.queues.iter().map(|queue| queue.len()).sum()
     }
 
     /// If the `EventQueue` has more then zero events it will return `true` otherwise `false`.
     pub fn is_empty(&self) -> bool {
-        self.event_queue.is_empty()
+        self.queues.iter().all(|queue| queue.is_empty())
     }
 }
 
@@ -142,3 +292,95 @@ impl<'a> Iterator for EventQueueIterator<'a> {
         self.event_queue.dequeue()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::Point;
+
+    struct TestEvent;
+
+    impl Event for TestEvent {}
+
+    fn mouse_move(x: f64) -> MouseMoveEvent {
+        MouseMoveEvent {
+            position: Point::new(x, 0.0),
+        }
+    }
+
+    #[test]
+    fn test_coalescing() {
+        let mut queue = EventQueue::new();
+        queue.register_event(mouse_move(1.0), Entity(0));
+        queue.register_event(mouse_move(2.0), Entity(0));
+        queue.register_event(mouse_move(3.0), Entity(1));
+        queue.register_event(TestEvent, Entity(0));
+        queue.register_event(mouse_move(4.0), Entity(1));
+        queue.register_event(mouse_move(5.0), Entity(1));
+
+        assert_eq!(queue.len(), 4);
+        assert_eq!(
+            queue
+                .dequeue()
+                .unwrap()
+                .downcast::<MouseMoveEvent>()
+                .unwrap()
+                .position,
+            Point::new(2.0, 0.0)
+        );
+        assert_eq!(
+            queue
+                .dequeue()
+                .unwrap()
+                .downcast::<MouseMoveEvent>()
+                .unwrap()
+                .position,
+            Point::new(3.0, 0.0)
+        );
+        assert!(queue.dequeue().unwrap().is_type::<TestEvent>());
+        assert_eq!(
+            queue
+                .dequeue()
+                .unwrap()
+                .downcast::<MouseMoveEvent>()
+                .unwrap()
+                .position,
+            Point::new(5.0, 0.0)
+        );
+        assert!(queue.dequeue().is_none());
+    }
+
+    #[test]
+    fn test_priority() {
+        let mut queue = EventQueue::new();
+        queue.set_priority::<MouseMoveEvent>(EventPriority::Low);
+        queue.register_event(mouse_move(1.0), Entity(0));
+        queue.register_event(TestEvent, Entity(0));
+
+        assert_eq!(queue.priority::<MouseMoveEvent>(), EventPriority::Low);
+        assert_eq!(queue.priority::<TestEvent>(), EventPriority::Normal);
+        assert!(queue.dequeue().unwrap().is_type::<TestEvent>());
+        assert!(queue.dequeue().unwrap().is_type::<MouseMoveEvent>());
+    }
+
+    #[test]
+    fn test_frame_bound() {
+        let mut queue = EventQueue::new();
+        queue.set_max_events_per_frame(Some(2));
+
+        for _ in 0..3 {
+            queue.register_event(TestEvent, Entity(0));
+        }
+
+        queue.start_frame();
+        assert!(queue.dequeue_in_frame().is_some());
+        assert!(queue.dequeue_in_frame().is_some());
+        assert!(queue.dequeue_in_frame().is_none());
+        assert!(queue.is_frame_exhausted());
+        assert_eq!(queue.len(), 1);
+
+        queue.start_frame();
+        assert!(queue.dequeue_in_frame().is_some());
+        assert!(queue.is_empty());
+    }
+}
@@ -0,0 +1,351 @@
+use std::{
+    collections::BTreeMap,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    prelude::*,
+    proc_macros::{Event, IntoHandler},
+    utils::*,
+};
+
+/// Maximum distance in pixels a pointer could move and still produce a tap.
+pub const TAP_DISTANCE: f64 = 10.0;
+
+/// Maximum time in milliseconds between touch begin and end of a tap.
+pub const TAP_TIME: u64 = 300;
+
+/// Time in milliseconds a pointer has to rest to produce a long press.
+pub const LONG_PRESS_TIME: u64 = 500;
+
+/// The enumeration of gestures recognized from touch events.
+#[derive(Debug, Copy, Clone, PartialEq, Event)]
+pub enum GestureEvent {
+    /// A short touch without movement.
+    Tap(Point),
+
+    /// A touch that rests on the same position.
+    LongPress(Point),
+
+    /// A single pointer is moved. `start` is the position where the pointer touched the window first.
+    Pan {
+        start: Point,
+        position: Point,
+        delta: Point,
+    },
+
+    /// Two pointers are moved towards or away from each other. `scale` is the change of the
+    /// pointer distance since the last pinch event.
+    Pinch { center: Point, scale: f64 },
+}
+
+impl GestureEvent {
+    /// Gets the position that is used to find the widget under the gesture.
+    pub fn position(&self) -> Point {
+        match self {
+            GestureEvent::Tap(position) => *position,
+            GestureEvent::LongPress(position) => *position,
+            GestureEvent::Pan { start, .. } => *start,
+            GestureEvent::Pinch { center, .. } => *center,
+        }
+    }
+}
+
+/// Defines the gesture handler function.
+pub type GestureHandlerFunction = dyn Fn(&mut StatesContext, GestureEvent) -> bool + 'static;
+
+/// Used to handle gesture events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct GestureEventHandler {
+    handler: Rc<GestureHandlerFunction>,
+}
+
+impl EventHandler for GestureEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<GestureEvent>()
+            .ok()
+            .map_or(false, |event| (self.handler)(state_context, *event))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<GestureEvent>()
+    }
+}
+
+/// Implement this trait if you want that your widget can handle gestures.
+pub trait GestureHandler: Sized + Widget {
+    /// Inserts a gesture handler.
+    fn on_gesture<H: Fn(&mut StatesContext, GestureEvent) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(GestureEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
+// Internal state of a pointer that touches the window.
+#[derive(Debug, Copy, Clone)]
+struct TouchPoint {
+    start: Point,
+    position: Point,
+    start_time: Instant,
+    panning: bool,
+    tap_candidate: bool,
+    long_pressed: bool,
+}
+
+/// Recognizes tap, long press, pan and pinch gestures from touch events.
+#[derive(Debug, Default)]
+pub struct GestureRecognizer {
+    touches: BTreeMap<u64, TouchPoint>,
+    pinch_distance: Option<f64>,
+}
+
+impl GestureRecognizer {
+    /// Creates a new gesture recognizer.
+    pub fn new() -> Self {
+        GestureRecognizer::default()
+    }
+
+    /// Registers the begin of a touch.
+    pub fn touch_begin(&mut self, pointer: Pointer, time: Instant) -> Vec<GestureEvent> {
+        // a multi touch could not be a tap
+        let tap_candidate = self.touches.is_empty();
+
+        for touch in self.touches.values_mut() {
+            touch.tap_candidate = false;
+        }
+
+        self.touches.insert(
+            pointer.id,
+            TouchPoint {
+                start: pointer.position,
+                position: pointer.position,
+                start_time: time,
+                panning: false,
+                tap_candidate,
+                long_pressed: false,
+            },
+        );
+        self.pinch_distance = self.current_pinch_distance();
+
+        vec![]
+    }
+
+    /// Registers the movement of a touch.
+    pub fn touch_move(&mut self, pointer: Pointer, _time: Instant) -> Vec<GestureEvent> {
+        let mut gestures = vec![];
+        let single_touch = self.touches.len() == 1;
+
+        if let Some(touch) = self.touches.get_mut(&pointer.id) {
+            let delta = Point::new(
+                pointer.position.x() - touch.position.x(),
+                pointer.position.y() - touch.position.y(),
+            );
+            touch.position = pointer.position;
+
+            if touch.start.distance(pointer.position) > TAP_DISTANCE {
+                touch.tap_candidate = false;
+                touch.panning = true;
+            }
+
+            if single_touch && touch.panning && !touch.long_pressed {
+                gestures.push(GestureEvent::Pan {
+                    start: touch.start,
+                    position: pointer.position,
+                    delta,
+                });
+            }
+        } else {
+            return gestures;
+        }
+
+        if let (Some(old_distance), Some(distance)) =
+            (self.pinch_distance, self.current_pinch_distance())
+        {
+            if old_distance > 0.0 {
+                gestures.push(GestureEvent::Pinch {
+                    center: self.pinch_center(),
+                    scale: distance / old_distance,
+                });
+            }
+            self.pinch_distance = Some(distance);
+        }
+
+        gestures
+    }
+
+    /// Registers the end of a touch.
+    pub fn touch_end(&mut self, pointer: Pointer, time: Instant) -> Vec<GestureEvent> {
+        let mut gestures = vec![];
+
+        if let Some(touch) = self.touches.remove(&pointer.id) {
+            if touch.tap_candidate
+                && !touch.long_pressed
+                && time.duration_since(touch.start_time) <= Duration::from_millis(TAP_TIME)
+            {
+                gestures.push(GestureEvent::Tap(touch.start));
+            }
+        }
+
+        self.pinch_distance = self.current_pinch_distance();
+
+        gestures
+    }
+
+    /// Checks for long presses. Should be called on each run of the window.
+    pub fn update(&mut self, time: Instant) -> Vec<GestureEvent> {
+        let mut gestures = vec![];
+
+        if self.touches.len() != 1 {
+            return gestures;
+        }
+
+        for touch in self.touches.values_mut() {
+            if touch.tap_candidate
+                && !touch.long_pressed
+                && time.duration_since(touch.start_time) >= Duration::from_millis(LONG_PRESS_TIME)
+            {
+                touch.long_pressed = true;
+                gestures.push(GestureEvent::LongPress(touch.start));
+            }
+        }
+
+        gestures
+    }
+
+    /// Returns `true` if a touch could still become a long press. The window has to be updated
+    /// until then, also if the touch does not move.
+    pub fn is_pending(&self) -> bool {
+        self.touches.len() == 1
+            && self
+                .touches
+                .values()
+                .any(|touch| touch.tap_candidate && !touch.long_pressed)
+    }
+
+    // Returns the distance between the pointers if exactly two pointers touches the window.
+    fn current_pinch_distance(&self) -> Option<f64> {
+        if self.touches.len() != 2 {
+            return None;
+        }
+
+        let mut touches = self.touches.values();
+        let first = touches.next()?;
+        let second = touches.next()?;
+
+        Some(first.position.distance(second.position))
+    }
+
+    // Returns the center between the touching pointers.
+    fn pinch_center(&self) -> Point {
+        let count = self.touches.len().max(1) as f64;
+        let (x, y) = self.touches.values().fold((0.0, 0.0), |(x, y), touch| {
+            (x + touch.position.x(), y + touch.position.y())
+        });
+
+        Point::new(x / count, y / count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shell::PointerKind;
+
+    fn pointer(id: u64, x: f64, y: f64) -> Pointer {
+        Pointer {
+            id,
+            kind: PointerKind::Touch,
+            position: Point::new(x, y),
+        }
+    }
+
+    #[test]
+    fn test_tap() {
+        let mut recognizer = GestureRecognizer::new();
+        let start = Instant::now();
+
+        recognizer.touch_begin(pointer(0, 10.0, 10.0), start);
+        recognizer.touch_move(pointer(0, 12.0, 10.0), start);
+
+        assert_eq!(
+            recognizer.touch_end(pointer(0, 12.0, 10.0), start + Duration::from_millis(100)),
+            vec![GestureEvent::Tap(Point::new(10.0, 10.0))]
+        );
+
+        recognizer.touch_begin(pointer(0, 10.0, 10.0), start);
+        assert!(recognizer
+            .touch_end(pointer(0, 10.0, 10.0), start + Duration::from_millis(400))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_long_press() {
+        let mut recognizer = GestureRecognizer::new();
+        let start = Instant::now();
+
+        recognizer.touch_begin(pointer(0, 10.0, 10.0), start);
+        assert!(recognizer.is_pending());
+        assert!(recognizer
+            .update(start + Duration::from_millis(100))
+            .is_empty());
+        assert_eq!(
+            recognizer.update(start + Duration::from_millis(600)),
+            vec![GestureEvent::LongPress(Point::new(10.0, 10.0))]
+        );
+        assert!(!recognizer.is_pending());
+        assert!(recognizer
+            .update(start + Duration::from_millis(700))
+            .is_empty());
+        assert!(recognizer
+            .touch_end(pointer(0, 10.0, 10.0), start + Duration::from_millis(800))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_pan() {
+        let mut recognizer = GestureRecognizer::new();
+        let start = Instant::now();
+
+        recognizer.touch_begin(pointer(0, 10.0, 10.0), start);
+        assert_eq!(
+            recognizer.touch_move(pointer(0, 10.0, 30.0), start),
+            vec![GestureEvent::Pan {
+                start: Point::new(10.0, 10.0),
+                position: Point::new(10.0, 30.0),
+                delta: Point::new(0.0, 20.0)
+            }]
+        );
+        assert!(recognizer
+            .touch_end(pointer(0, 10.0, 30.0), start)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_pinch() {
+        let mut recognizer = GestureRecognizer::new();
+        let start = Instant::now();
+
+        recognizer.touch_begin(pointer(0, 0.0, 0.0), start);
+        recognizer.touch_begin(pointer(1, 10.0, 0.0), start);
+
+        assert_eq!(
+            recognizer.touch_move(pointer(1, 20.0, 0.0), start),
+            vec![GestureEvent::Pinch {
+                center: Point::new(10.0, 0.0),
+                scale: 2.0
+            }]
+        );
+
+        // multi touch does not produce a tap
+        assert!(recognizer
+            .touch_end(pointer(1, 20.0, 0.0), start)
+            .is_empty());
+        assert!(recognizer.touch_end(pointer(0, 0.0, 0.0), start).is_empty());
+    }
+}
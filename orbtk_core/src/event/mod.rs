@@ -6,36 +6,48 @@ use dces::entity::Entity;
 
 use crate::widget_base::StatesContext;
 
+pub use self::command::*;
+pub use self::context_menu::*;
+pub use self::drag_drop::*;
 pub use self::drop::*;
 pub use self::editable::*;
 pub use self::event_adapter::*;
 pub use self::event_handler::*;
 pub use self::event_queue::*;
 pub use self::focus::*;
+pub use self::gesture::*;
 pub use self::key::*;
 pub use self::mouse::*;
 pub use self::system::*;
 pub use self::text_input::*;
+pub use self::touch::*;
 pub use self::window::*;
 
+mod command;
+mod context_menu;
+mod drag_drop;
 mod drop;
 mod editable;
 mod event_adapter;
 mod event_handler;
 mod event_queue;
 mod focus;
+mod gesture;
 mod key;
 mod mouse;
 mod system;
 mod text_input;
+mod touch;
 mod window;
 
 /// Defines the strategy of an event how it moves through the tree.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum EventStrategy {
-    // /// From root to leaf.
-    // TopDown,
-    /// From leaf to root.
+    /// From root to leaf. Preview and regular handlers are called from the root to the target.
+    TopDown,
+
+    /// From leaf to root. Preview handlers are called from the root to the target (tunneling),
+    /// regular handlers from the target back to the root (bubbling).
     BottomUp,
 
     /// Occurs direct.
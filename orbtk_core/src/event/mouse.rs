@@ -1,4 +1,7 @@
-use std::rc::Rc;
+use std::{
+    rc::Rc,
+    time::{Duration, Instant},
+};
 
 use crate::{
     prelude::*,
@@ -7,7 +10,14 @@ use crate::{
     utils::*,
 };
 
-/// Checks if the given point is inside of a widget.
+/// Default maximum time in milliseconds between two clicks of a double click.
+pub const DEFAULT_DOUBLE_CLICK_TIME: u32 = 500;
+
+/// Default maximum distance in pixels between two clicks of a double click.
+pub const DEFAULT_DOUBLE_CLICK_DISTANCE: f64 = 4.0;
+
+/// Checks if the given point is inside of a widget. The scaling of a `Viewbox` the widget is
+/// placed in is respected.
 pub fn check_mouse_condition(mouse_position: Point, widget: &WidgetContainer<'_>) -> bool {
     let enabled = widget.get::<bool>("enabled");
 
@@ -15,6 +25,12 @@ pub fn check_mouse_condition(mouse_position: Point, widget: &WidgetContainer<'_>
         return false;
     }
 
+    let mouse_position = widget
+        .try_get::<ViewboxTransform>("viewbox_transform")
+        .map_or(mouse_position, |transform| {
+            transform.inverse(mouse_position)
+        });
+
     let bounds = widget.get::<Rectangle>("bounds");
     let position = widget.get::<Point>("position");
 
@@ -62,6 +78,9 @@ pub struct Mouse {
 
     /// Indicates position of the mouse on the window.
     pub position: Point,
+
+    /// Number of successive clicks, e.g. `2` on a double click.
+    pub click_count: u32,
 }
 
 /// `MouseUpEvent` occurs when a mouse button is released.
@@ -72,6 +91,9 @@ pub struct MouseUpEvent {
 
     /// Indicates position of the mouse on the window.
     pub position: Point,
+
+    /// Number of successive clicks, e.g. `2` on a double click.
+    pub click_count: u32,
 }
 
 /// `ClickEvent` occurs when a user clicked on an element.
@@ -79,6 +101,9 @@ pub struct MouseUpEvent {
 pub struct ClickEvent {
     /// Indicates the x and y position of the click event.
     pub position: Point,
+
+    /// Number of successive clicks, e.g. `2` on a double click.
+    pub click_count: u32,
 }
 
 /// `MouseDownEvent` occurs when a mouse button is pressed.
@@ -89,6 +114,9 @@ pub struct MouseDownEvent {
 
     /// Indicates position of the mouse on the window.
     pub position: Point,
+
+    /// Number of successive clicks, e.g. `2` on a double click.
+    pub click_count: u32,
 }
 
 /// `GlobalMouseUpEvent` occurs when a mouse button is released.
@@ -101,6 +129,66 @@ pub struct GlobalMouseUpEvent {
 
     /// Indicates position of the mouse on the window.
     pub position: Point,
+
+    /// Number of successive clicks, e.g. `2` on a double click.
+    pub click_count: u32,
+}
+
+/// `LostMouseCaptureEvent` is sent direct to a widget that has lost the mouse capture, e.g. if the capture
+/// is released, another widget captures the mouse or the window is deactivated.
+#[derive(Event)]
+pub struct LostMouseCaptureEvent;
+
+/// Counts successive clicks of a mouse button, e.g. to detect double clicks.
+///
+/// A press counts as successive click if it uses the same button as the last
+/// press, occurs inside of the given time and inside of the given distance.
+#[derive(Debug, Clone, Default)]
+pub struct ClickCounter {
+    last_button: Option<MouseButton>,
+    last_position: Point,
+    last_time: Option<Instant>,
+    count: u32,
+}
+
+impl ClickCounter {
+    /// Creates a new click counter.
+    pub fn new() -> Self {
+        ClickCounter::default()
+    }
+
+    /// Registers a press of the given button and returns the current click count.
+    pub fn press(
+        &mut self,
+        button: MouseButton,
+        position: Point,
+        time: Instant,
+        max_time: Duration,
+        max_distance: f64,
+    ) -> u32 {
+        let successive = self.last_button == Some(button)
+            && self.last_time.map_or(false, |last_time| {
+                time.duration_since(last_time) <= max_time
+            })
+            && self.last_position.distance(position) <= max_distance;
+
+        if successive {
+            self.count += 1;
+        } else {
+            self.count = 1;
+        }
+
+        self.last_button = Some(button);
+        self.last_position = position;
+        self.last_time = Some(time);
+
+        self.count
+    }
+
+    /// Returns the click count of the last press.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
 }
 
 /// Defines the mouse handler function.
@@ -137,6 +225,26 @@ impl EventHandler for ClickEventHandler {
     }
 }
 
+/// Used to handle double click events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct DoubleClickEventHandler {
+    handler: Rc<PositionHandlerFunction>,
+}
+
+impl EventHandler for DoubleClickEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<ClickEvent>()
+            .ok()
+            .filter(|event| event.click_count == 2)
+            .map_or(false, |event| (self.handler)(state_context, event.position))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<ClickEvent>()
+    }
+}
+
 /// Used to handle mouse down events. Could be attached to a widget.
 #[derive(IntoHandler)]
 pub struct MouseDownEventHandler {
@@ -154,6 +262,7 @@ impl EventHandler for MouseDownEventHandler {
                     Mouse {
                         button: event.button,
                         position: event.position,
+                        click_count: event.click_count,
                     },
                 )
             })
@@ -181,6 +290,7 @@ impl EventHandler for GlobalMouseUpEventHandler {
                     Mouse {
                         button: event.button,
                         position: event.position,
+                        click_count: event.click_count,
                     },
                 );
                 false
@@ -206,6 +316,7 @@ impl EventHandler for MouseUpEventHandler {
                 Mouse {
                     button: event.button,
                     position: event.position,
+                    click_count: event.click_count,
                 },
             );
         }
@@ -257,6 +368,26 @@ impl EventHandler for EnterEventHandler {
     }
 }
 
+/// Used to handle the lost mouse capture event on a widget.
+#[derive(IntoHandler)]
+pub struct LostMouseCaptureEventHandler {
+    handler: Rc<dyn Fn(&mut StatesContext) + 'static>,
+}
+
+impl EventHandler for LostMouseCaptureEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if event.is_type::<LostMouseCaptureEvent>() {
+            (self.handler)(state_context);
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<LostMouseCaptureEvent>()
+    }
+}
+
 /// Used to handle mouse leave event on a widget.
 #[derive(IntoHandler)]
 pub struct LeaveEventHandler {
@@ -304,6 +435,16 @@ pub trait MouseHandler: Sized + Widget {
         })
     }
 
+    /// Inserts a double click handler.
+    fn on_double_click<H: Fn(&mut StatesContext, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(DoubleClickEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
     /// Insert a mouse down handler.
     fn on_mouse_down<H: Fn(&mut StatesContext, Mouse) -> bool + 'static>(self, handler: H) -> Self {
         self.insert_handler(MouseDownEventHandler {
@@ -346,6 +487,13 @@ pub trait MouseHandler: Sized + Widget {
         })
     }
 
+    /// Insert a handler that is called if the widget loses the mouse capture.
+    fn on_lost_mouse_capture<H: Fn(&mut StatesContext) + 'static>(self, handler: H) -> Self {
+        self.insert_handler(LostMouseCaptureEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
     /// Insert a mouse up handler.
     fn on_scroll<H: Fn(&mut StatesContext, Point) -> bool + 'static>(self, handler: H) -> Self {
         self.insert_handler(ScrollEventHandler {
@@ -353,3 +501,76 @@ pub trait MouseHandler: Sized + Widget {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TIME: Duration = Duration::from_millis(500);
+
+    #[test]
+    fn test_click_counter_double_click() {
+        let mut counter = ClickCounter::new();
+        let start = Instant::now();
+
+        assert_eq!(
+            counter.press(MouseButton::Left, Point::new(10.0, 10.0), start, TIME, 4.0),
+            1
+        );
+        assert_eq!(
+            counter.press(
+                MouseButton::Left,
+                Point::new(11.0, 10.0),
+                start + Duration::from_millis(200),
+                TIME,
+                4.0
+            ),
+            2
+        );
+        assert_eq!(counter.count(), 2);
+    }
+
+    #[test]
+    fn test_click_counter_reset() {
+        let mut counter = ClickCounter::new();
+        let start = Instant::now();
+
+        counter.press(MouseButton::Left, Point::new(10.0, 10.0), start, TIME, 4.0);
+
+        // too late
+        assert_eq!(
+            counter.press(
+                MouseButton::Left,
+                Point::new(10.0, 10.0),
+                start + Duration::from_millis(600),
+                TIME,
+                4.0
+            ),
+            1
+        );
+
+        // too far away
+        assert_eq!(
+            counter.press(
+                MouseButton::Left,
+                Point::new(20.0, 10.0),
+                start + Duration::from_millis(700),
+                TIME,
+                4.0
+            ),
+            1
+        );
+
+        // other button
+        assert_eq!(
+            counter.press(
+                MouseButton::Right,
+                Point::new(20.0, 10.0),
+                start + Duration::from_millis(800),
+                TIME,
+                4.0
+            ),
+            1
+        );
+    }
+}
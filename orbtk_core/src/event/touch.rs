@@ -0,0 +1,135 @@
+use std::rc::Rc;
+
+use crate::{
+    prelude::*,
+    proc_macros::{Event, IntoHandler},
+    shell::PointerKind,
+    utils::*,
+};
+
+/// Represents the current state of a pointer (finger or pen) of a touch event.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Pointer {
+    /// The id of the pointer. Is unique as long as the pointer has contact.
+    pub id: u64,
+
+    /// Indicates the device kind of the pointer.
+    pub kind: PointerKind,
+
+    /// Indicates position of the pointer on the window.
+    pub position: Point,
+}
+
+/// `TouchBeginEvent` occurs when a pointer starts to touch the window.
+#[derive(Event)]
+pub struct TouchBeginEvent {
+    /// The pointer that touches the window.
+    pub pointer: Pointer,
+}
+
+/// `TouchMoveEvent` occurs when a touching pointer is moved.
+#[derive(Event)]
+pub struct TouchMoveEvent {
+    /// The pointer that is moved.
+    pub pointer: Pointer,
+}
+
+/// `TouchEndEvent` occurs when a pointer stops to touch the window.
+#[derive(Event)]
+pub struct TouchEndEvent {
+    /// The pointer that is lifted.
+    pub pointer: Pointer,
+}
+
+/// Defines the pointer handler function.
+pub type PointerHandlerFunction = dyn Fn(&mut StatesContext, Pointer) -> bool + 'static;
+
+/// Used to handle touch begin events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct TouchBeginEventHandler {
+    handler: Rc<PointerHandlerFunction>,
+}
+
+impl EventHandler for TouchBeginEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<TouchBeginEvent>()
+            .ok()
+            .map_or(false, |event| (self.handler)(state_context, event.pointer))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<TouchBeginEvent>()
+    }
+}
+
+/// Used to handle touch move events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct TouchMoveEventHandler {
+    handler: Rc<PointerHandlerFunction>,
+}
+
+impl EventHandler for TouchMoveEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<TouchMoveEvent>()
+            .ok()
+            .map_or(false, |event| (self.handler)(state_context, event.pointer))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<TouchMoveEvent>()
+    }
+}
+
+/// Used to handle touch end events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct TouchEndEventHandler {
+    handler: Rc<PointerHandlerFunction>,
+}
+
+impl EventHandler for TouchEndEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<TouchEndEvent>()
+            .ok()
+            .map_or(false, |event| (self.handler)(state_context, event.pointer))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<TouchEndEvent>()
+    }
+}
+
+/// Implement this trait if you want that your widget can handle touch events.
+pub trait TouchHandler: Sized + Widget {
+    /// Inserts a touch begin handler.
+    fn on_touch_begin<H: Fn(&mut StatesContext, Pointer) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(TouchBeginEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a touch move handler.
+    fn on_touch_move<H: Fn(&mut StatesContext, Pointer) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(TouchMoveEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a touch end handler.
+    fn on_touch_end<H: Fn(&mut StatesContext, Pointer) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(TouchEndEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
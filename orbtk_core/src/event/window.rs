@@ -5,10 +5,15 @@ use super::*;
 use crate::{proc_macros::*, widget_base::*};
 
 /// The enumeration of valid window events.
+///
+/// `CloseRequested` is sent top-down to all widgets of the window if the user requests to close
+/// it. If a handler marks the event as handled the window stays open and can be closed later by
+/// sending `WindowRequest::Close`.
 #[derive(Clone, Event)]
 pub enum WindowEvent {
     Resize { width: f64, height: f64 },
     ActiveChanged(bool),
+    CloseRequested,
     None,
 }
 
@@ -34,3 +39,38 @@ impl EventHandler for WindowEventHandler {
         event.is_type::<WindowEvent>()
     }
 }
+
+pub type CloseRequestedHandlerFn = dyn Fn(&mut StatesContext) -> bool + 'static;
+
+/// The structure handling close requests of a window.
+#[derive(IntoHandler)]
+pub struct CloseRequestedEventHandler {
+    /// A reference counted handler.
+    pub handler: Rc<CloseRequestedHandlerFn>,
+}
+
+impl EventHandler for CloseRequestedEventHandler {
+    fn handle_event(&self, states: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(WindowEvent::CloseRequested) = event.downcast_ref::<WindowEvent>() {
+            return (self.handler)(states);
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<WindowEvent>()
+    }
+}
+
+/// Implement this trait if you want that your widget can intercept close requests of its window.
+pub trait CloseRequestedHandler: Sized + Widget {
+    /// Inserts a handler that is called if the user requests to close the window.
+    /// Return `true` to veto the request. The window can be closed afterwards by
+    /// sending a `WindowRequest::Close`.
+    fn on_close_requested<H: Fn(&mut StatesContext) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(CloseRequestedEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
@@ -7,12 +7,13 @@ use crate::{
     widget_base::mark_as_dirty,
 };
 
-use super::{component, component_try_mut, Layout};
+use super::{component, component_try_mut, Layout, LayoutCache};
 
 /// Place widgets absolute on the screen.
 #[derive(Default, IntoLayout)]
 pub struct AbsoluteLayout {
     desired_size: RefCell<DirtySize>,
+    cache: LayoutCache,
 }
 
 impl AbsoluteLayout {
@@ -58,13 +59,16 @@ impl Layout for AbsoluteLayout {
             }
         }
 
+        // the desired size follows the window, therefore it is measured also if the cache is
+        // valid
+        self.cache.set_measured(*self.desired_size.borrow());
         *self.desired_size.borrow()
     }
 
     fn arrange(
         &self,
         render_context_2_d: &mut RenderContext2D,
-        _parent_size: (f64, f64),
+        parent_size: (f64, f64),
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree>,
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
@@ -100,6 +104,11 @@ impl Layout for AbsoluteLayout {
         }
 
         self.desired_size.borrow_mut().set_dirty(false);
+        self.cache.set_arranged(parent_size);
         self.desired_size.borrow().size()
     }
+
+    fn cache(&self) -> Option<&LayoutCache> {
+        Some(&self.cache)
+    }
 }
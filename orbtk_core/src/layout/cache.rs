@@ -0,0 +1,218 @@
+use std::cell::Cell;
+
+use crate::utils::DirtySize;
+
+/// Keys of the properties that change the desired size of a widget. A change invalidates the
+/// measure of the widget and of its ancestors until a desired size does not change.
+pub const MEASURE_PROPERTIES: &[&str] = &[
+    "anchors",
+    "column",
+    "column_gap",
+    "column_span",
+    "columns",
+    "constraint",
+    "direction",
+    "dock",
+    "flex_basis",
+    "font",
+    "font_size",
+    "gap",
+    "icon",
+    "icon_font",
+    "icon_size",
+    "image",
+    "item_spacing",
+    "line_spacing",
+    "localizable",
+    "localized_text",
+    "margin",
+    "orientation",
+    "padding",
+    "row",
+    "row_gap",
+    "row_span",
+    "rows",
+    "spacing",
+    "target",
+    "text",
+    "visibility",
+    "water_mark",
+    "wrap",
+];
+
+/// Keys of the properties that only change the arrangement of a widget inside of the size
+/// that is given by its parent.
+pub const ARRANGE_PROPERTIES: &[&str] = &[
+    "align_items",
+    "align_self",
+    "flex_grow",
+    "flex_shrink",
+    "flow_direction",
+    "h_align",
+    "justification",
+    "justify_content",
+    "last_child_fill",
+    "line_alignment",
+    "placement",
+    "position",
+    "v_align",
+];
+
+/// Keys of the properties that are inherited by the children of a widget. A change
+/// invalidates the layout of the whole subtree.
+pub const INHERITED_PROPERTIES: &[&str] = &["flow_direction"];
+
+/// Describes which layout pass has to be repeated for a widget after one of its properties
+/// has changed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LayoutInvalidation {
+    /// The layout is not affected, e.g. by a changed color.
+    None,
+
+    /// The widget has to be arranged again.
+    Arrange,
+
+    /// The widget has to be measured and arranged again.
+    Measure,
+}
+
+impl LayoutInvalidation {
+    /// Returns the invalidation that is caused by a change of the property with the given key.
+    pub fn of(key: &str) -> Self {
+        if MEASURE_PROPERTIES.contains(&key) {
+            LayoutInvalidation::Measure
+        } else if ARRANGE_PROPERTIES.contains(&key) {
+            LayoutInvalidation::Arrange
+        } else {
+            LayoutInvalidation::None
+        }
+    }
+
+    /// Returns `true` if the property with the given key is inherited by the children.
+    pub fn is_inherited(key: &str) -> bool {
+        INHERITED_PROPERTIES.contains(&key)
+    }
+}
+
+impl Default for LayoutInvalidation {
+    fn default() -> Self {
+        LayoutInvalidation::None
+    }
+}
+
+/// Caches the results of the last measure and arrangement of a layout. As long as the cache is
+/// valid, the layout returns its last desired size instead of measuring its children again and
+/// skips the arrangement for the same parent size.
+#[derive(Debug, Default)]
+pub struct LayoutCache {
+    measured: Cell<bool>,
+    arranged: Cell<bool>,
+    desired_size: Cell<(f64, f64)>,
+    parent_size: Cell<Option<(f64, f64)>>,
+}
+
+impl LayoutCache {
+    /// Creates a new invalid cache.
+    pub fn new() -> Self {
+        LayoutCache::default()
+    }
+
+    /// Returns `true` if the desired size of the last measure is still valid.
+    pub fn is_measured(&self) -> bool {
+        self.measured.get()
+    }
+
+    /// Stores the desired size of a measure.
+    pub fn set_measured(&self, desired_size: DirtySize) {
+        self.measured.set(true);
+        self.desired_size.set(desired_size.size());
+    }
+
+    /// Returns the desired size of the last measure.
+    pub fn desired_size(&self) -> (f64, f64) {
+        self.desired_size.get()
+    }
+
+    /// Returns `true` if the last arrangement is still valid for the given parent size.
+    pub fn is_arranged(&self, parent_size: (f64, f64)) -> bool {
+        self.arranged.get() && self.parent_size.get() == Some(parent_size)
+    }
+
+    /// Stores the parent size of an arrangement.
+    pub fn set_arranged(&self, parent_size: (f64, f64)) {
+        self.arranged.set(true);
+        self.parent_size.set(Some(parent_size));
+    }
+
+    /// Returns the parent size of the last arrangement or `None` if the layout is not
+    /// arranged yet.
+    pub fn parent_size(&self) -> Option<(f64, f64)> {
+        self.parent_size.get()
+    }
+
+    /// Invalidates the cache. A measure invalidation also invalidates the arrangement.
+    pub fn invalidate(&self, invalidation: LayoutInvalidation) {
+        match invalidation {
+            LayoutInvalidation::Measure => {
+                self.measured.set(false);
+                self.arranged.set(false);
+            }
+            LayoutInvalidation::Arrange => self.arranged.set(false),
+            LayoutInvalidation::None => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_of() {
+        assert_eq!(LayoutInvalidation::of("text"), LayoutInvalidation::Measure);
+        assert_eq!(
+            LayoutInvalidation::of("margin"),
+            LayoutInvalidation::Measure
+        );
+        assert_eq!(
+            LayoutInvalidation::of("h_align"),
+            LayoutInvalidation::Arrange
+        );
+        assert_eq!(
+            LayoutInvalidation::of("background"),
+            LayoutInvalidation::None
+        );
+        assert_eq!(LayoutInvalidation::of("bounds"), LayoutInvalidation::None);
+        assert!(LayoutInvalidation::is_inherited("flow_direction"));
+        assert!(!LayoutInvalidation::is_inherited("margin"));
+        assert_eq!(LayoutInvalidation::of("hover"), LayoutInvalidation::None);
+    }
+
+    #[test]
+    fn test_cache() {
+        let cache = LayoutCache::new();
+        assert!(!cache.is_measured());
+        assert!(!cache.is_arranged((10.0, 10.0)));
+        assert_eq!(cache.parent_size(), None);
+
+        let mut desired_size = DirtySize::new();
+        desired_size.set_size(4.0, 2.0);
+        cache.set_measured(desired_size);
+        cache.set_arranged((10.0, 10.0));
+
+        assert!(cache.is_measured());
+        assert_eq!(cache.desired_size(), (4.0, 2.0));
+        assert!(cache.is_arranged((10.0, 10.0)));
+        assert!(!cache.is_arranged((12.0, 10.0)));
+
+        cache.invalidate(LayoutInvalidation::Arrange);
+        assert!(cache.is_measured());
+        assert!(!cache.is_arranged((10.0, 10.0)));
+        assert_eq!(cache.parent_size(), Some((10.0, 10.0)));
+
+        cache.set_arranged((10.0, 10.0));
+        cache.invalidate(LayoutInvalidation::Measure);
+        assert!(!cache.is_measured());
+        assert!(!cache.is_arranged((10.0, 10.0)));
+    }
+}
@@ -0,0 +1,356 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+};
+
+use dces::prelude::*;
+
+use crate::{
+    prelude::*, proc_macros::IntoLayout, render::RenderContext2D, theming::*, tree::Tree,
+    utils::prelude::*,
+};
+
+use super::{
+    component, component_or_default, component_try_mut, try_component, Layout, LayoutCache,
+};
+
+/// Docks each child to an edge of the space that is left by the previous children. The edge
+/// is read from the attached `dock` property of the child (`Left` if not set). If the
+/// `last_child_fill` property of the widget is `true` (default), the last child fills the
+/// remaining space.
+#[derive(Default, IntoLayout)]
+pub struct DockLayout {
+    desired_size: RefCell<DirtySize>,
+    old_alignment: Cell<(Alignment, Alignment)>,
+    items: RefCell<Vec<(Entity, Dock, (f64, f64))>>,
+    cache: LayoutCache,
+}
+
+impl DockLayout {
+    /// Presets the defaults.
+    pub fn new() -> Self {
+        DockLayout::default()
+    }
+
+    pub fn set_dirty(&self, dirty: bool) {
+        self.desired_size.borrow_mut().set_dirty(dirty);
+    }
+}
+
+impl Layout for DockLayout {
+    fn measure(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> DirtySize {
+        if self.cache.is_measured() {
+            return *self.desired_size.borrow();
+        }
+
+        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
+            let mut desired = self.desired_size.borrow_mut();
+            desired.set_size(0.0, 0.0);
+            self.cache.set_measured(*desired);
+            return *desired;
+        }
+
+        let halign: Alignment = component(ecm, entity, "h_align");
+        let valign: Alignment = component(ecm, entity, "v_align");
+        let (old_valign, old_halign) = self.old_alignment.get();
+
+        if halign != old_halign || valign != old_valign {
+            self.set_dirty(true);
+        }
+
+        let constraint: Constraint = component(ecm, entity, "constraint");
+        let mut dirty = false;
+        let mut items = vec![];
+
+        let nchildren = ecm.entity_store().children[&entity].len();
+
+        for index in 0..nchildren {
+            let child = ecm.entity_store().children[&entity][index];
+
+            if let Some(child_layout) = layouts.get(&child) {
+                let child_desired_size =
+                    child_layout.measure(render_context_2_d, child, ecm, layouts, theme);
+
+                if child_desired_size.dirty() || self.desired_size.borrow().dirty() {
+                    dirty = true;
+                }
+
+                let child_margin = {
+                    if child_desired_size.width() > 0.0 && child_desired_size.height() > 0.0 {
+                        component(ecm, child, "margin")
+                    } else {
+                        Thickness::default()
+                    }
+                };
+
+                items.push((
+                    child,
+                    component_or_default(ecm, child, "dock"),
+                    (
+                        child_desired_size.width() + child_margin.left() + child_margin.right(),
+                        child_desired_size.height() + child_margin.top() + child_margin.bottom(),
+                    ),
+                ));
+            }
+        }
+
+        let docks: Vec<(Dock, (f64, f64))> =
+            items.iter().map(|(_, dock, size)| (*dock, *size)).collect();
+        *self.items.borrow_mut() = items;
+
+        let desired_size = constraint.perform(dock_size(&docks));
+
+        self.set_dirty(dirty);
+
+        let mut desired = self.desired_size.borrow_mut();
+        desired.set_size(desired_size.0, desired_size.1);
+        self.cache.set_measured(*desired);
+        *desired
+    }
+
+    fn arrange(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        parent_size: (f64, f64),
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> (f64, f64) {
+        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
+            self.desired_size.borrow_mut().set_size(0.0, 0.0);
+            return (0.0, 0.0);
+        }
+
+        if !self.desired_size.borrow().dirty() && self.cache.is_arranged(parent_size) {
+            return self.desired_size.borrow().size();
+        }
+
+        let halign: Alignment = component(ecm, entity, "h_align");
+        let valign: Alignment = component(ecm, entity, "v_align");
+        let margin: Thickness = component(ecm, entity, "margin");
+        let constraint: Constraint = component(ecm, entity, "constraint");
+        let last_child_fill = try_component(ecm, entity, "last_child_fill").unwrap_or(true);
+
+        let size = constraint.perform((
+            halign.align_measure(
+                parent_size.0,
+                self.desired_size.borrow().width(),
+                margin.left(),
+                margin.right(),
+            ),
+            valign.align_measure(
+                parent_size.1,
+                self.desired_size.borrow().height(),
+                margin.top(),
+                margin.bottom(),
+            ),
+        ));
+
+        let items = self.items.borrow().clone();
+        let docks: Vec<(Dock, (f64, f64))> =
+            items.iter().map(|(_, dock, size)| (*dock, *size)).collect();
+        let rects = dock_rects(&docks, size, last_child_fill);
+
+        for ((child, _, _), (x, y, width, height)) in items.iter().zip(rects) {
+            let child = *child;
+
+            let mut child_desired_size = (0.0, 0.0);
+            if let Some(child_layout) = layouts.get(&child) {
+                child_desired_size = child_layout.arrange(
+                    render_context_2_d,
+                    (width, height),
+                    child,
+                    ecm,
+                    layouts,
+                    theme,
+                );
+            }
+
+            let child_margin = {
+                if child_desired_size.0 > 0.0 && child_desired_size.1 > 0.0 {
+                    component(ecm, child, "margin")
+                } else {
+                    Thickness::default()
+                }
+            };
+
+            let child_halign: Alignment = component(ecm, child, "h_align");
+            let child_valign: Alignment = component(ecm, child, "v_align");
+
+            if let Some(child_bounds) = component_try_mut::<Rectangle>(ecm, child, "bounds") {
+                child_bounds.set_x(
+                    x + child_halign.align_position(
+                        width,
+                        child_bounds.width(),
+                        child_margin.left(),
+                        child_margin.right(),
+                    ),
+                );
+                child_bounds.set_y(
+                    y + child_valign.align_position(
+                        height,
+                        child_bounds.height(),
+                        child_margin.top(),
+                        child_margin.bottom(),
+                    ),
+                );
+            }
+
+            mark_as_dirty("bounds", child, ecm);
+        }
+
+        if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
+            bounds.set_width(size.0);
+            bounds.set_height(size.1);
+        }
+
+        mark_as_dirty("bounds", entity, ecm);
+
+        self.set_dirty(false);
+        self.cache.set_arranged(parent_size);
+        size
+    }
+
+    fn cache(&self) -> Option<&LayoutCache> {
+        Some(&self.cache)
+    }
+}
+
+/// Calculates the size that is needed to dock the children with the given desired sizes
+/// (including margins).
+fn dock_size(docks: &[(Dock, (f64, f64))]) -> (f64, f64) {
+    let mut size: (f64, f64) = (0.0, 0.0);
+    let mut accumulated: (f64, f64) = (0.0, 0.0);
+
+    for (dock, (width, height)) in docks {
+        match dock {
+            Dock::Left | Dock::Right => {
+                size.1 = size.1.max(accumulated.1 + height);
+                accumulated.0 += width;
+            }
+            Dock::Top | Dock::Bottom => {
+                size.0 = size.0.max(accumulated.0 + width);
+                accumulated.1 += height;
+            }
+            Dock::Fill => {
+                size.0 = size.0.max(accumulated.0 + width);
+                size.1 = size.1.max(accumulated.1 + height);
+            }
+        }
+    }
+
+    (size.0.max(accumulated.0), size.1.max(accumulated.1))
+}
+
+/// Calculates the (x, y, width, height) rectangles of the docked children inside of the
+/// given size.
+fn dock_rects(
+    docks: &[(Dock, (f64, f64))],
+    size: (f64, f64),
+    last_child_fill: bool,
+) -> Vec<(f64, f64, f64, f64)> {
+    let (mut x, mut y, mut width, mut height) = (0.0, 0.0, size.0, size.1);
+    let mut rects = vec![];
+
+    for (index, (dock, (child_width, child_height))) in docks.iter().enumerate() {
+        let dock = if last_child_fill && index == docks.len() - 1 {
+            Dock::Fill
+        } else {
+            *dock
+        };
+
+        let child_width = child_width.min(width);
+        let child_height = child_height.min(height);
+
+        match dock {
+            Dock::Left => {
+                rects.push((x, y, child_width, height));
+                x += child_width;
+                width -= child_width;
+            }
+            Dock::Right => {
+                rects.push((x + width - child_width, y, child_width, height));
+                width -= child_width;
+            }
+            Dock::Top => {
+                rects.push((x, y, width, child_height));
+                y += child_height;
+                height -= child_height;
+            }
+            Dock::Bottom => {
+                rects.push((x, y + height - child_height, width, child_height));
+                height -= child_height;
+            }
+            Dock::Fill => rects.push((x, y, width, height)),
+        }
+    }
+
+    rects
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCKS: [(Dock, (f64, f64)); 4] = [
+        (Dock::Top, (100.0, 20.0)),
+        (Dock::Bottom, (80.0, 10.0)),
+        (Dock::Left, (30.0, 50.0)),
+        (Dock::Fill, (60.0, 40.0)),
+    ];
+
+    #[test]
+    fn size() {
+        assert_eq!(dock_size(&DOCKS), (100.0, 80.0));
+        assert_eq!(
+            dock_size(&[(Dock::Left, (30.0, 50.0)), (Dock::Right, (20.0, 60.0))]),
+            (50.0, 60.0)
+        );
+        assert_eq!(dock_size(&[]), (0.0, 0.0));
+    }
+
+    #[test]
+    fn rects() {
+        assert_eq!(
+            dock_rects(&DOCKS, (200.0, 100.0), true),
+            vec![
+                (0.0, 0.0, 200.0, 20.0),
+                (0.0, 90.0, 200.0, 10.0),
+                (0.0, 20.0, 30.0, 70.0),
+                (30.0, 20.0, 170.0, 70.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn last_child_fill() {
+        let docks = [(Dock::Left, (30.0, 50.0)), (Dock::Right, (20.0, 50.0))];
+
+        assert_eq!(
+            dock_rects(&docks, (200.0, 100.0), true),
+            vec![(0.0, 0.0, 30.0, 100.0), (30.0, 0.0, 170.0, 100.0)]
+        );
+        assert_eq!(
+            dock_rects(&docks, (200.0, 100.0), false),
+            vec![(0.0, 0.0, 30.0, 100.0), (180.0, 0.0, 20.0, 100.0)]
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        let docks = [(Dock::Left, (150.0, 50.0)), (Dock::Left, (100.0, 50.0))];
+
+        assert_eq!(
+            dock_rects(&docks, (200.0, 100.0), false),
+            vec![(0.0, 0.0, 150.0, 100.0), (150.0, 0.0, 50.0, 100.0)]
+        );
+    }
+}
@@ -18,7 +18,7 @@ use crate::{
     widget_base::{mark_as_dirty, WidgetContainer},
 };
 
-use super::{component, component_try_mut, Layout};
+use super::{component, component_try_mut, Layout, LayoutCache};
 
 /// A fixed sized layout is defined by the fixed bounds.
 /// Examples are the size of an image or the size of a text.
@@ -26,6 +26,7 @@ use super::{component, component_try_mut, Layout};
 pub struct FixedSizeLayout {
     desired_size: RefCell<DirtySize>,
     old_alignment: Cell<(Alignment, Alignment)>,
+    cache: LayoutCache,
 }
 
 impl FixedSizeLayout {
@@ -44,8 +45,13 @@ impl Layout for FixedSizeLayout {
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
         theme: &Theme,
     ) -> DirtySize {
+        if self.cache.is_measured() {
+            return *self.desired_size.borrow();
+        }
+
         if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
             self.desired_size.borrow_mut().set_size(0.0, 0.0);
+            self.cache.set_measured(*self.desired_size.borrow());
             return *self.desired_size.borrow();
         }
 
@@ -136,13 +142,14 @@ impl Layout for FixedSizeLayout {
             }
         }
 
+        self.cache.set_measured(*self.desired_size.borrow());
         *self.desired_size.borrow()
     }
 
     fn arrange(
         &self,
         render_context_2_d: &mut RenderContext2D,
-        _parent_size: (f64, f64),
+        parent_size: (f64, f64),
         entity: Entity,
         ecm: &mut EntityComponentManager<Tree>,
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
@@ -178,8 +185,13 @@ impl Layout for FixedSizeLayout {
         }
 
         self.desired_size.borrow_mut().set_dirty(false);
+        self.cache.set_arranged(parent_size);
         self.desired_size.borrow().size()
     }
+
+    fn cache(&self) -> Option<&LayoutCache> {
+        Some(&self.cache)
+    }
 }
 
 fn text(widget: &WidgetContainer) -> Option<String> {
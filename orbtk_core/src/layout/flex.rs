@@ -0,0 +1,535 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+};
+
+use dces::prelude::*;
+
+use crate::{
+    prelude::*, proc_macros::IntoLayout, render::RenderContext2D, theming::*, tree::Tree,
+    utils::prelude::*,
+};
+
+use super::{
+    component, component_or_default, component_try_mut, try_component,
+    wrap::{lines_size, main_cross, wrap_limit, wrap_lines},
+    Layout, LayoutCache,
+};
+
+/// Implements the core of the CSS flexbox layout.
+///
+/// The layout reads the following properties of its widget:
+///
+/// * `direction`: main axis and direction of the children (`FlexDirection`)
+/// * `wrap`: wraps the children onto multiple lines (`FlexWrap`)
+/// * `justify_content`: distribution of the free space of a line on the main axis
+/// * `align_items`: default alignment of the children inside of a line on the cross axis
+/// * `gap`: space between two children and between two lines
+///
+/// and the following attached properties of its children:
+///
+/// * `flex_grow`: share of the free space the child grows by, default `0`
+/// * `flex_shrink`: share of the missing space the child shrinks by, default `1`
+/// * `flex_basis`: main size of the child before growing or shrinking, default is the
+/// desired size of the child
+/// * `align_self`: overwrites `align_items` for the child
+///
+/// The `h_align` and `v_align` of the children are not changed. A child with a `Stretch`
+/// alignment fills its slot, otherwise it keeps its desired size.
+#[derive(Default, IntoLayout)]
+pub struct FlexLayout {
+    desired_size: RefCell<DirtySize>,
+    old_alignment: Cell<(Alignment, Alignment)>,
+    available_size: Cell<f64>,
+    measured_limit: Cell<f64>,
+    items: RefCell<Vec<FlexItem>>,
+    cache: LayoutCache,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct FlexItem {
+    entity: Entity,
+    // (width, height) including the margin, the main size is the flex basis
+    size: (f64, f64),
+    min_size: (f64, f64),
+    margin: Thickness,
+    grow: f64,
+    shrink: f64,
+    align_self: Option<Alignment>,
+}
+
+impl FlexLayout {
+    /// Presets the defaults.
+    pub fn new() -> Self {
+        FlexLayout::default()
+    }
+
+    pub fn set_dirty(&self, dirty: bool) {
+        self.desired_size.borrow_mut().set_dirty(dirty);
+    }
+}
+
+impl Layout for FlexLayout {
+    fn measure(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> DirtySize {
+        if self.cache.is_measured() {
+            return *self.desired_size.borrow();
+        }
+
+        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
+            let mut desired = self.desired_size.borrow_mut();
+            desired.set_size(0.0, 0.0);
+            self.cache.set_measured(*desired);
+            return *desired;
+        }
+
+        let halign: Alignment = component(ecm, entity, "h_align");
+        let valign: Alignment = component(ecm, entity, "v_align");
+        let (old_valign, old_halign) = self.old_alignment.get();
+
+        if halign != old_halign || valign != old_valign {
+            self.set_dirty(true);
+        }
+
+        let direction: FlexDirection = component_or_default(ecm, entity, "direction");
+        let wrap: FlexWrap = component_or_default(ecm, entity, "wrap");
+        let constraint: Constraint = component(ecm, entity, "constraint");
+        let gap: f64 = component_or_default(ecm, entity, "gap");
+        let orientation = direction.orientation();
+        let mut dirty = false;
+        let mut items = vec![];
+
+        let nchildren = ecm.entity_store().children[&entity].len();
+
+        for index in 0..nchildren {
+            let child = ecm.entity_store().children[&entity][index];
+
+            if let Some(child_layout) = layouts.get(&child) {
+                let child_desired_size =
+                    child_layout.measure(render_context_2_d, child, ecm, layouts, theme);
+
+                if child_desired_size.dirty() || self.desired_size.borrow().dirty() {
+                    dirty = true;
+                }
+
+                if component::<Visibility>(ecm, child, "visibility") == Visibility::Collapsed {
+                    continue;
+                }
+
+                let margin: Thickness = component(ecm, child, "margin");
+                let child_constraint: Constraint = component(ecm, child, "constraint");
+                let margin_size = (
+                    margin.left() + margin.right(),
+                    margin.top() + margin.bottom(),
+                );
+
+                let mut size = (
+                    child_desired_size.width() + margin_size.0,
+                    child_desired_size.height() + margin_size.1,
+                );
+
+                if let Some(basis) = try_component::<f64>(ecm, child, "flex_basis") {
+                    match orientation {
+                        Orientation::Horizontal => size.0 = basis.max(0.0) + margin_size.0,
+                        Orientation::Vertical => size.1 = basis.max(0.0) + margin_size.1,
+                    }
+                }
+
+                items.push(FlexItem {
+                    entity: child,
+                    size,
+                    min_size: (
+                        child_constraint.min_width() + margin_size.0,
+                        child_constraint.min_height() + margin_size.1,
+                    ),
+                    margin,
+                    grow: component_or_default(ecm, child, "flex_grow"),
+                    shrink: try_component(ecm, child, "flex_shrink").unwrap_or(1.0),
+                    align_self: try_component(ecm, child, "align_self"),
+                });
+            }
+        }
+
+        let sizes: Vec<(f64, f64)> = items
+            .iter()
+            .map(|item| main_cross(item.size, orientation))
+            .collect();
+
+        let limit = if wrap == FlexWrap::NoWrap {
+            f64::MAX
+        } else {
+            wrap_limit(&constraint, orientation, self.available_size.get())
+        };
+        self.measured_limit.set(limit);
+
+        let lines = wrap_lines(&sizes, limit, gap);
+
+        *self.items.borrow_mut() = items;
+
+        let desired_size = main_cross(lines_size(&lines, gap), orientation);
+        let desired_size = constraint.perform(desired_size);
+
+        self.set_dirty(dirty);
+
+        let mut desired = self.desired_size.borrow_mut();
+        desired.set_size(desired_size.0, desired_size.1);
+        self.cache.set_measured(*desired);
+        *desired
+    }
+
+    fn arrange(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        parent_size: (f64, f64),
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> (f64, f64) {
+        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
+            self.desired_size.borrow_mut().set_size(0.0, 0.0);
+            return (0.0, 0.0);
+        }
+
+        if !self.desired_size.borrow().dirty() && self.cache.is_arranged(parent_size) {
+            return self.desired_size.borrow().size();
+        }
+
+        let halign: Alignment = component(ecm, entity, "h_align");
+        let valign: Alignment = component(ecm, entity, "v_align");
+        let margin: Thickness = component(ecm, entity, "margin");
+        let constraint: Constraint = component(ecm, entity, "constraint");
+        let direction: FlexDirection = component_or_default(ecm, entity, "direction");
+        let wrap: FlexWrap = component_or_default(ecm, entity, "wrap");
+        let justify_content: Justification = component_or_default(ecm, entity, "justify_content");
+        let align_items: Alignment = component_or_default(ecm, entity, "align_items");
+        let gap: f64 = component_or_default(ecm, entity, "gap");
+        let orientation = direction.orientation();
+
+        let size = constraint.perform((
+            halign.align_measure(
+                parent_size.0,
+                self.desired_size.borrow().width(),
+                margin.left(),
+                margin.right(),
+            ),
+            valign.align_measure(
+                parent_size.1,
+                self.desired_size.borrow().height(),
+                margin.top(),
+                margin.bottom(),
+            ),
+        ));
+
+        let (available_main, available_cross) = main_cross(size, orientation);
+        self.available_size.set(available_main);
+
+        // the lines are measured for another size, e.g. the available size is not known on the
+        // first measure. The widget is measured again, so its parent reserves the new lines.
+        if wrap != FlexWrap::NoWrap
+            && wrap_limit(&constraint, orientation, available_main) != self.measured_limit.get()
+        {
+            invalidate_layout(entity, LayoutInvalidation::Measure, ecm);
+        }
+
+        let items = self.items.borrow().clone();
+        let sizes: Vec<(f64, f64)> = items
+            .iter()
+            .map(|item| main_cross(item.size, orientation))
+            .collect();
+
+        let mut lines = if wrap == FlexWrap::NoWrap {
+            wrap_lines(&sizes, f64::MAX, gap)
+        } else {
+            wrap_lines(&sizes, available_main, gap)
+        };
+
+        // a single line container stretches its line over the cross size of the container
+        if wrap == FlexWrap::NoWrap {
+            if let Some(line) = lines.first_mut() {
+                line.cross = line.cross.max(available_cross);
+            }
+        }
+
+        let (_, lines_cross) = lines_size(&lines, gap);
+        let mut line_offset = 0.0;
+
+        for line in lines.iter() {
+            let line_items = &items[line.start..line.end];
+            let flex_items: Vec<(f64, f64, f64, f64)> = line_items
+                .iter()
+                .zip(&sizes[line.start..line.end])
+                .map(|(item, (basis, _))| {
+                    (
+                        *basis,
+                        item.grow,
+                        item.shrink,
+                        main_cross(item.min_size, orientation).0,
+                    )
+                })
+                .collect();
+
+            let (main_sizes, free) = resolve_flexible_lengths(&flex_items, available_main, gap);
+            let (leading, between) = justify_content.distribute(free, line_items.len());
+            let line_position = if wrap == FlexWrap::WrapReverse {
+                lines_cross - line_offset - line.cross
+            } else {
+                line_offset
+            };
+
+            let mut item_offset = leading;
+
+            for ((item, item_main), (_, item_cross)) in line_items
+                .iter()
+                .zip(main_sizes)
+                .zip(&sizes[line.start..line.end])
+            {
+                let child = item.entity;
+                let alignment = item.align_self.unwrap_or(align_items);
+                let ((main_start, _), (cross_start, cross_end)) =
+                    margin_main_cross(item.margin, orientation);
+
+                // the child is arranged inside of the slot of the flex algorithm. Its own
+                // alignment decides if it fills the slot, the line alignment only positions it.
+                let available_item_cross = if alignment == Alignment::Stretch {
+                    line.cross
+                } else {
+                    *item_cross
+                };
+
+                if let Some(child_layout) = layouts.get(&child) {
+                    child_layout.arrange(
+                        render_context_2_d,
+                        main_cross((item_main, available_item_cross), orientation),
+                        child,
+                        ecm,
+                        layouts,
+                        theme,
+                    );
+                }
+
+                let main_position = if direction.is_reverse() {
+                    available_main - item_offset - item_main + main_start
+                } else {
+                    item_offset + main_start
+                };
+
+                if let Some(child_bounds) = component_try_mut::<Rectangle>(ecm, child, "bounds") {
+                    let (_, child_cross) =
+                        main_cross((child_bounds.width(), child_bounds.height()), orientation);
+                    let cross_position = line_position
+                        + alignment.align_position(line.cross, child_cross, cross_start, cross_end);
+
+                    let (x, y) = main_cross((main_position, cross_position), orientation);
+                    child_bounds.set_x(x);
+                    child_bounds.set_y(y);
+                }
+
+                mark_as_dirty("bounds", child, ecm);
+
+                item_offset += item_main + gap + between;
+            }
+
+            line_offset += line.cross + gap;
+        }
+
+        self.set_dirty(false);
+
+        let size = main_cross((available_main, lines_cross), orientation);
+
+        if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
+            bounds.set_width(size.0);
+            bounds.set_height(size.1);
+        }
+
+        mark_as_dirty("bounds", entity, ecm);
+
+        self.cache.set_arranged(parent_size);
+        size
+    }
+
+    fn cache(&self) -> Option<&LayoutCache> {
+        Some(&self.cache)
+    }
+}
+
+// Returns the margin as ((main start, main end), (cross start, cross end)).
+fn margin_main_cross(margin: Thickness, orientation: Orientation) -> ((f64, f64), (f64, f64)) {
+    match orientation {
+        Orientation::Horizontal => (
+            (margin.left(), margin.right()),
+            (margin.top(), margin.bottom()),
+        ),
+        Orientation::Vertical => (
+            (margin.top(), margin.bottom()),
+            (margin.left(), margin.right()),
+        ),
+    }
+}
+
+/// Resolves the main sizes of the items of a line given as (basis, grow, shrink, min size).
+/// Positive free space is distributed by the grow factors, missing space is taken from the
+/// items by their shrink factors scaled by their basis. Items do not shrink below their min
+/// size, the missing space is then taken from the other items.
+///
+/// Returns the main sizes and the free space that is left for the justification.
+fn resolve_flexible_lengths(
+    items: &[(f64, f64, f64, f64)],
+    available: f64,
+    gap: f64,
+) -> (Vec<f64>, f64) {
+    let mut sizes: Vec<f64> = items.iter().map(|(basis, _, _, _)| *basis).collect();
+    let gaps = gap * items.len().saturating_sub(1) as f64;
+    let used = sizes.iter().sum::<f64>() + gaps;
+
+    if available >= f64::MAX || items.is_empty() {
+        return (sizes, 0.0);
+    }
+
+    let free = available - used;
+
+    if free > 0.0 {
+        let grow = items
+            .iter()
+            .map(|(_, grow, _, _)| grow.max(0.0))
+            .sum::<f64>();
+
+        if grow <= 0.0 {
+            return (sizes, free);
+        }
+
+        for (size, (_, item_grow, _, _)) in sizes.iter_mut().zip(items) {
+            *size += free * item_grow.max(0.0) / grow;
+        }
+
+        return (sizes, 0.0);
+    }
+
+    // shrink the items, items that reach their min size are frozen and the rest of the
+    // missing space is distributed to the others
+    let mut frozen = vec![false; items.len()];
+    let mut missing = -free;
+
+    while missing > 0.0 {
+        let shrink = items
+            .iter()
+            .zip(&frozen)
+            .filter(|(_, frozen)| !**frozen)
+            .map(|((basis, _, shrink, _), _)| shrink.max(0.0) * basis)
+            .sum::<f64>();
+
+        if shrink <= 0.0 {
+            break;
+        }
+
+        let mut clamped = 0.0;
+
+        for (index, (basis, _, item_shrink, min)) in items.iter().enumerate() {
+            if frozen[index] {
+                continue;
+            }
+
+            let size = sizes[index] - missing * item_shrink.max(0.0) * basis / shrink;
+
+            if size < *min {
+                clamped += *min - size;
+                sizes[index] = *min;
+                frozen[index] = true;
+            } else {
+                sizes[index] = size;
+            }
+        }
+
+        missing = clamped;
+    }
+
+    let used = sizes.iter().sum::<f64>() + gaps;
+
+    (sizes, (available - used).max(0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grow() {
+        let items = [
+            (20.0, 0.0, 1.0, 0.0),
+            (20.0, 1.0, 1.0, 0.0),
+            (20.0, 3.0, 1.0, 0.0),
+        ];
+
+        assert_eq!(
+            resolve_flexible_lengths(&items, 160.0, 10.0),
+            (vec![20.0, 40.0, 80.0], 0.0)
+        );
+    }
+
+    #[test]
+    fn no_grow() {
+        let items = [(20.0, 0.0, 1.0, 0.0), (30.0, 0.0, 1.0, 0.0)];
+
+        assert_eq!(
+            resolve_flexible_lengths(&items, 100.0, 10.0),
+            (vec![20.0, 30.0], 40.0)
+        );
+        assert_eq!(
+            resolve_flexible_lengths(&items, f64::MAX, 10.0),
+            (vec![20.0, 30.0], 0.0)
+        );
+    }
+
+    #[test]
+    fn shrink() {
+        // shrink is scaled by the basis
+        let items = [(100.0, 0.0, 1.0, 0.0), (50.0, 0.0, 1.0, 0.0)];
+        assert_eq!(
+            resolve_flexible_lengths(&items, 120.0, 0.0),
+            (vec![80.0, 40.0], 0.0)
+        );
+
+        // no shrink
+        let items = [(100.0, 0.0, 0.0, 0.0), (50.0, 0.0, 1.0, 0.0)];
+        assert_eq!(
+            resolve_flexible_lengths(&items, 120.0, 0.0),
+            (vec![100.0, 20.0], 0.0)
+        );
+    }
+
+    #[test]
+    fn shrink_min() {
+        let items = [(100.0, 0.0, 1.0, 0.0), (100.0, 0.0, 1.0, 90.0)];
+        assert_eq!(
+            resolve_flexible_lengths(&items, 150.0, 0.0),
+            (vec![60.0, 90.0], 0.0)
+        );
+
+        // the items could not shrink enough and overflow
+        let items = [(100.0, 0.0, 1.0, 80.0), (100.0, 0.0, 1.0, 90.0)];
+        assert_eq!(
+            resolve_flexible_lengths(&items, 150.0, 0.0),
+            (vec![80.0, 90.0], 0.0)
+        );
+    }
+
+    #[test]
+    fn margins() {
+        let margin = Thickness::new(1.0, 2.0, 3.0, 4.0);
+
+        assert_eq!(
+            margin_main_cross(margin, Orientation::Horizontal),
+            ((1.0, 3.0), (2.0, 4.0))
+        );
+        assert_eq!(
+            margin_main_cross(margin, Orientation::Vertical),
+            ((2.0, 4.0), (1.0, 3.0))
+        );
+    }
+}
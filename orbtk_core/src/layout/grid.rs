@@ -10,7 +10,7 @@ use crate::{
     utils::prelude::*,
 };
 
-use super::{component, component_try_mut, Layout};
+use super::{component, component_try_mut, mirror_children, Layout, LayoutCache};
 
 /// Orders its children in a grid layout with columns and rows. If no
 /// columns and rows are defined the grid layout can also be used as
@@ -20,6 +20,7 @@ pub struct GridLayout {
     desired_size: RefCell<DirtySize>,
     children_sizes: RefCell<BTreeMap<Entity, (f64, f64)>>,
     old_alignment: Cell<(Alignment, Alignment)>,
+    cache: LayoutCache,
 }
 
 impl GridLayout {
@@ -32,6 +33,7 @@ impl GridLayout {
     fn get_column_x_and_width(
         &self,
         columns_cache: &[(f64, f64)],
+        column_gap: f64,
         entity: Entity,
         store: &mut ComponentStore,
         grid_column: usize,
@@ -42,7 +44,16 @@ impl GridLayout {
         let x = if let Some((x, _)) = column { *x } else { 0.0 };
 
         if let Ok(column_span) = store.get::<usize>("column_span", entity) {
-            for column in columns_cache.iter().skip(grid_column).take(*column_span) {
+            for (i, column) in columns_cache
+                .iter()
+                .skip(grid_column)
+                .take(*column_span)
+                .enumerate()
+            {
+                // the gaps between the spanned columns belong to the cell
+                if i > 0 {
+                    width += column_gap;
+                }
                 width += column.1;
             }
         } else if let Some((_, column_width)) = column {
@@ -56,6 +67,7 @@ impl GridLayout {
     fn get_row_y_and_height(
         &self,
         rows_cache: &[(f64, f64)],
+        row_gap: f64,
         entity: Entity,
         store: &mut ComponentStore,
         grid_row: usize,
@@ -66,7 +78,11 @@ impl GridLayout {
         let y = if let Some((y, _)) = row { *y } else { 0.0 };
 
         if let Ok(row_span) = store.get::<usize>("row_span", entity) {
-            for row in rows_cache.iter().skip(grid_row).take(*row_span) {
+            for (i, row) in rows_cache.iter().skip(grid_row).take(*row_span).enumerate() {
+                // the gaps between the spanned rows belong to the cell
+                if i > 0 {
+                    height += row_gap;
+                }
                 height += row.1;
             }
         } else if let Some((_, row_height)) = row {
@@ -111,68 +127,87 @@ impl GridLayout {
             }
         };
     }
+}
 
-    fn calculate_blocks(
-        &self,
-        size: f64,
-        blocks_cache: &mut Vec<(f64, f64)>,
-        blocks: &mut Blocks,
-        block_sizes: &BTreeMap<usize, f64>,
-    ) {
-        if !blocks.is_empty() {
-            // sets auto blocks size to the size of the largest child
-            for (grid_block, size) in block_sizes {
-                if let Some(block) = blocks.get_mut(*grid_block) {
-                    block.set_current_size(*size);
-                }
-            }
+// Calculates the size of each block and returns the offset and size of the blocks. The gap
+// is placed between the blocks and is not available for them.
+fn calculate_blocks(
+    size: f64,
+    gap: f64,
+    blocks: &mut Blocks,
+    block_sizes: &BTreeMap<usize, f64>,
+) -> Vec<(f64, f64)> {
+    if blocks.is_empty() {
+        return vec![];
+    }
 
-            // sets the size of blocks with fixed size
-            for block in blocks.iter_mut() {
-                if let BlockSize::Size(size) = block.size {
-                    block.set_current_size(size);
-                }
-            }
+    let available = (size - gap * (blocks.len() - 1) as f64).max(0.0);
 
-            // calculates the size of the stretch blocks
-            let used_size: f64 = blocks
-                .iter()
-                .filter(|block| block.size != BlockSize::Stretch)
-                .map(|block| block.current_size())
-                .sum();
-
-            let stretch_size = ((size - used_size)
-                / blocks
-                    .iter()
-                    .filter(|block| block.size == BlockSize::Stretch)
-                    .count() as f64)
-                .trunc();
-
-            blocks
-                .iter_mut()
-                .filter(|block| block.size == BlockSize::Stretch)
-                .for_each(|block| block.set_current_size(stretch_size));
-
-            let mut block_sum = 0.0;
-
-            blocks_cache.reserve(blocks.len());
-            for block in blocks.iter() {
-                blocks_cache.push((block_sum, block.current_size()));
-                block_sum += block.current_size();
+    // sets the size of auto blocks to the size of the largest child, fixed and percentage blocks
+    for (index, block) in blocks.iter_mut().enumerate() {
+        match block.size {
+            BlockSize::Auto => {
+                block.set_current_size(block_sizes.get(&index).copied().unwrap_or(0.0))
             }
-
-            // fix rounding gab
-            if size - block_sum > 0.0 {
-                if let Some(last_block) = blocks
-                    .iter_mut()
-                    .rev()
-                    .find(|block| block.size == BlockSize::Stretch)
-                {
-                    last_block.set_current_size(last_block.current_size() + size - block_sum);
-                }
+            BlockSize::Size(size) => block.set_current_size(size),
+            BlockSize::Percent(percent) => {
+                block.set_current_size((available * percent / 100.0).trunc())
             }
+            BlockSize::Stretch | BlockSize::Star(_) => {}
         }
     }
+
+    // shares the rest of the available size between the star blocks by their weight
+    let used_size: f64 = blocks
+        .iter()
+        .filter(|block| !block.size.is_star())
+        .map(|block| block.current_size())
+        .sum();
+    let rest = (available - used_size).max(0.0);
+    let weights: f64 = blocks.iter().filter_map(|block| block.size.weight()).sum();
+
+    for block in blocks.iter_mut() {
+        if let Some(weight) = block.size.weight() {
+            let size = if weights > 0.0 {
+                (rest * weight / weights).trunc()
+            } else {
+                0.0
+            };
+
+            block.set_current_size(size);
+        }
+    }
+
+    // fix rounding gap
+    let block_sum: f64 = blocks.iter().map(|block| block.current_size()).sum();
+
+    if available - block_sum > 0.0 {
+        if let Some(last_block) = blocks
+            .iter_mut()
+            .rev()
+            .find(|block| block.size.weight().map_or(false, |weight| weight > 0.0))
+        {
+            last_block.set_current_size(last_block.current_size() + available - block_sum);
+        }
+    }
+
+    let mut offset = 0.0;
+
+    blocks
+        .iter()
+        .map(|block| {
+            let cache = (offset, block.current_size());
+            offset += block.current_size() + gap;
+            cache
+        })
+        .collect()
+}
+
+// Reads the gap of the given key, it is `0` if the grid doesn't define it.
+fn gap(ecm: &mut EntityComponentManager<Tree>, entity: Entity, key: &str) -> f64 {
+    ecm.component_store()
+        .get::<f64>(key, entity)
+        .map_or(0.0, |gap| gap.max(0.0))
 }
 
 impl Layout for GridLayout {
@@ -184,9 +219,14 @@ impl Layout for GridLayout {
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
         theme: &Theme,
     ) -> DirtySize {
+        if self.cache.is_measured() {
+            return *self.desired_size.borrow();
+        }
+
         // collapsed entities don't consume any size
         if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
             self.desired_size.borrow_mut().set_size(0.0, 0.0);
+            self.cache.set_measured(*self.desired_size.borrow());
             return *self.desired_size.borrow();
         }
 
@@ -251,9 +291,25 @@ impl Layout for GridLayout {
             }
         }
 
+        // the gaps between the columns and rows
+        let column_gaps = ecm
+            .component_store()
+            .get::<Blocks>("columns", entity)
+            .map_or(0, |columns| columns.len().saturating_sub(1)) as f64
+            * gap(ecm, entity, "column_gap");
+        let row_gaps = ecm
+            .component_store()
+            .get::<Blocks>("rows", entity)
+            .map_or(0, |rows| rows.len().saturating_sub(1)) as f64
+            * gap(ecm, entity, "row_gap");
+
         // update desired_size to hold the maximum grid width and height values
-        desired_size.0 = desired_size.0.max(sum_col.iter().map(|x| x.1).sum());
-        desired_size.1 = desired_size.1.max(sum_row.iter().map(|x| x.1).sum());
+        desired_size.0 = desired_size
+            .0
+            .max(sum_col.iter().map(|x| x.1).sum::<f64>() + column_gaps);
+        desired_size.1 = desired_size
+            .1
+            .max(sum_row.iter().map(|x| x.1).sum::<f64>() + row_gaps);
 
         self.desired_size
             .borrow_mut()
@@ -267,6 +323,7 @@ impl Layout for GridLayout {
             .perform(self.desired_size.borrow().size());
 
         self.desired_size.borrow_mut().set_size(size.0, size.1);
+        self.cache.set_measured(*self.desired_size.borrow());
 
         // result: return the calculated desired_size
         *self.desired_size.borrow()
@@ -287,8 +344,8 @@ impl Layout for GridLayout {
             return (0.0, 0.0);
         }
 
-        // Only trigger rendering, if entity is dirty
-        if !self.desired_size.borrow().dirty() {
+        // Only trigger rendering, if entity is dirty or the available size has changed
+        if !self.desired_size.borrow().dirty() && self.cache.is_arranged(parent_size) {
             return self.desired_size.borrow().size();
         }
 
@@ -316,6 +373,8 @@ impl Layout for GridLayout {
         let mut column_widths = BTreeMap::new();
         let mut rows_cache = Vec::new();
         let mut row_heights = BTreeMap::new();
+        let column_gap = gap(ecm, entity, "column_gap");
+        let row_gap = gap(ecm, entity, "row_gap");
 
         // calculates the auto column widths
         for index in 0..ecm.entity_store().children[&entity].len() {
@@ -360,12 +419,12 @@ impl Layout for GridLayout {
             .component_store_mut()
             .get_mut::<Blocks>("columns", entity)
         {
-            self.calculate_blocks(size.0, &mut columns_cache, columns, &column_widths);
+            columns_cache = calculate_blocks(size.0, column_gap, columns, &column_widths);
         }
 
         // take row_heights and calculate rows_cache
         if let Ok(rows) = ecm.component_store_mut().get_mut::<Blocks>("rows", entity) {
-            self.calculate_blocks(size.1, &mut rows_cache, rows, &row_heights);
+            rows_cache = calculate_blocks(size.1, row_gap, rows, &row_heights);
         }
 
         if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
@@ -402,6 +461,7 @@ impl Layout for GridLayout {
 
                 let (offset_x, available_width) = self.get_column_x_and_width(
                     &columns_cache,
+                    column_gap,
                     child,
                     ecm.component_store_mut(),
                     grid_column,
@@ -430,6 +490,7 @@ impl Layout for GridLayout {
 
                 let (offset_y, available_height) = self.get_row_y_and_height(
                     &rows_cache,
+                    row_gap,
                     child,
                     ecm.component_store_mut(),
                     grid_row,
@@ -492,6 +553,9 @@ impl Layout for GridLayout {
             mark_as_dirty("bounds", child, ecm);
         }
 
+        // right-to-left: the first column is placed on the right
+        mirror_children(ecm, entity, size.0);
+
         if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
             bounds.set_width(size.0);
             bounds.set_height(size.1);
@@ -501,6 +565,60 @@ impl Layout for GridLayout {
         mark_as_dirty("bounds", entity, ecm);
 
         self.desired_size.borrow_mut().set_dirty(false);
+        self.cache.set_arranged(parent_size);
         size
     }
+
+    fn cache(&self) -> Option<&LayoutCache> {
+        Some(&self.cache)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::calculate_blocks;
+    use crate::properties::Blocks;
+
+    #[test]
+    fn star_blocks() {
+        let mut blocks = Blocks::from("2*, auto, 120, 1*");
+        let mut block_sizes = BTreeMap::new();
+        block_sizes.insert(1, 30.0);
+
+        let cache = calculate_blocks(300.0, 0.0, &mut blocks, &block_sizes);
+
+        assert_eq!(
+            cache,
+            vec![(0.0, 100.0), (100.0, 30.0), (130.0, 120.0), (250.0, 50.0)]
+        );
+    }
+
+    #[test]
+    fn percent_blocks() {
+        let mut blocks = Blocks::from("25%, *, 10%");
+
+        let cache = calculate_blocks(200.0, 0.0, &mut blocks, &BTreeMap::new());
+
+        assert_eq!(cache, vec![(0.0, 50.0), (50.0, 130.0), (180.0, 20.0)]);
+    }
+
+    #[test]
+    fn gaps() {
+        let mut blocks = Blocks::from("*, *, *");
+
+        let cache = calculate_blocks(110.0, 10.0, &mut blocks, &BTreeMap::new());
+
+        assert_eq!(cache, vec![(0.0, 30.0), (40.0, 30.0), (80.0, 30.0)]);
+    }
+
+    #[test]
+    fn rounding_gap() {
+        let mut blocks = Blocks::from("1*, 1*, 1*");
+
+        let cache = calculate_blocks(100.0, 0.0, &mut blocks, &BTreeMap::new());
+
+        assert_eq!(cache, vec![(0.0, 33.0), (33.0, 33.0), (66.0, 34.0)]);
+    }
 }
@@ -1,23 +1,35 @@
-//! This module contains the layout types of an OrbTk application (Absolute, Fixed, Grid, Padding, Popup, Stack).
+//! This module contains the layout types of an OrbTk application (Absolute, Dock, Fixed, Flex, Grid, Padding, Popup, Relative, Stack, Viewbox, Wrap).
 use std::{any::Any, collections::BTreeMap};
 
 use dces::prelude::*;
 
-use crate::{render::RenderContext2D, theming::*, tree::Tree, utils::*};
+use crate::{properties::FlowDirection, render::RenderContext2D, theming::*, tree::Tree, utils::*};
 
 pub use self::absolute::*;
+pub use self::cache::*;
+pub use self::dock::*;
 pub use self::fixed_size::*;
+pub use self::flex::*;
 pub use self::grid::*;
 pub use self::padding::*;
 pub use self::popup::*;
+pub use self::relative::*;
 pub use self::stack::*;
+pub use self::viewbox::*;
+pub use self::wrap::*;
 
 mod absolute;
+mod cache;
+mod dock;
 mod fixed_size;
+mod flex;
 mod grid;
 mod padding;
 mod popup;
+mod relative;
 mod stack;
+mod viewbox;
+mod wrap;
 
 /// The layout process will order the children of a given widget in a dynamic iteration.
 /// It will respect constraint values between its elements. The following image illustrates
@@ -49,6 +61,14 @@ pub trait Layout: Any {
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
         theme: &Theme,
     ) -> (f64, f64);
+
+    /// Returns the cache of the last measure and arrangement. It is invalidated by the
+    /// `LayoutSystem` if a property of the widget has changed that affects the layout.
+    ///
+    /// Layouts without a cache are measured and arranged again on each layout update.
+    fn cache(&self) -> Option<&LayoutCache> {
+        None
+    }
 }
 
 fn component<C: Component + Clone>(
@@ -94,3 +114,62 @@ fn component_try_mut<'a, C: Component>(
         .get_mut::<C>(component, entity)
         .ok()
 }
+
+// Writes a property that is computed by a layout. If the value has changed, the `LayoutSystem`
+// marks the widget as dirty and notifies the observers of the property after the layout pass.
+fn set_component<C: Component + PartialEq>(
+    ecm: &mut EntityComponentManager<Tree>,
+    entity: Entity,
+    component: &str,
+    value: C,
+) {
+    match component_try_mut::<C>(ecm, entity, component) {
+        Some(current) if *current != value => *current = value,
+        _ => return,
+    }
+
+    let root = ecm.entity_store().root();
+
+    if let Some(layout_changes) =
+        component_try_mut::<Vec<(Entity, String)>>(ecm, root, "layout_changes")
+    {
+        layout_changes.push((entity, component.to_string()));
+    }
+}
+
+/// Returns the flow direction of the given widget. If the widget doesn't set its own
+/// `flow_direction`, it is inherited from its parent.
+pub fn flow_direction(ecm: &mut EntityComponentManager<Tree>, entity: Entity) -> FlowDirection {
+    let mut current = Some(entity);
+
+    while let Some(entity) = current {
+        if let Ok(flow_direction) = ecm
+            .component_store()
+            .get::<FlowDirection>("flow_direction", entity)
+        {
+            return *flow_direction;
+        }
+
+        current = ecm.entity_store().parent.get(&entity).copied().flatten();
+    }
+
+    FlowDirection::default()
+}
+
+// Mirrors the arranged children of the given widget inside of the given width, if the flow
+// direction of the widget is right-to-left.
+fn mirror_children(ecm: &mut EntityComponentManager<Tree>, entity: Entity, width: f64) {
+    let flow_direction = flow_direction(ecm, entity);
+
+    if !flow_direction.is_right_to_left() {
+        return;
+    }
+
+    for index in 0..ecm.entity_store().children[&entity].len() {
+        let child = ecm.entity_store().children[&entity][index];
+
+        if let Some(bounds) = component_try_mut::<Rectangle>(ecm, child, "bounds") {
+            bounds.set_x(flow_direction.mirror(bounds.x(), bounds.width(), width));
+        }
+    }
+}
@@ -10,14 +10,14 @@ use crate::{
     utils::prelude::*,
 };
 
-use super::{component, component_try_mut, Layout};
+use super::{component, component_try_mut, mirror_children, Layout, LayoutCache};
 
 /// Add padding to the widget.
 #[derive(Default, IntoLayout)]
 pub struct PaddingLayout {
     desired_size: RefCell<DirtySize>,
     old_alignment: Cell<(Alignment, Alignment)>,
-    old_parent_size: Cell<(f64, f64)>,
+    cache: LayoutCache,
 }
 
 impl PaddingLayout {
@@ -36,8 +36,13 @@ impl Layout for PaddingLayout {
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
         theme: &Theme,
     ) -> DirtySize {
+        if self.cache.is_measured() {
+            return *self.desired_size.borrow();
+        }
+
         if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
             self.desired_size.borrow_mut().set_size(0.0, 0.0);
+            self.cache.set_measured(*self.desired_size.borrow());
             return *self.desired_size.borrow();
         }
 
@@ -100,6 +105,7 @@ impl Layout for PaddingLayout {
             }
         }
         self.desired_size.borrow_mut().set_dirty(true);
+        self.cache.set_measured(*self.desired_size.borrow());
         *self.desired_size.borrow()
     }
 
@@ -117,7 +123,7 @@ impl Layout for PaddingLayout {
             return (0.0, 0.0);
         }
 
-        if !self.desired_size.borrow().dirty() && parent_size == self.old_parent_size.get() {
+        if !self.desired_size.borrow().dirty() && self.cache.is_arranged(parent_size) {
             return self.desired_size.borrow().size();
         }
 
@@ -203,8 +209,16 @@ impl Layout for PaddingLayout {
 
             mark_as_dirty("bounds", child, ecm);
         }
-        self.old_parent_size.set(parent_size);
+
+        // right-to-left: the left padding is applied to the right edge
+        mirror_children(ecm, entity, size.0);
+
+        self.cache.set_arranged(parent_size);
         self.desired_size.borrow_mut().set_dirty(false);
         size
     }
+
+    fn cache(&self) -> Option<&LayoutCache> {
+        Some(&self.cache)
+    }
 }
@@ -7,13 +7,16 @@ use crate::{
     utils::prelude::*,
 };
 
-use super::{component, component_or_default, component_try_mut, try_component, Layout};
+use super::{
+    component, component_or_default, component_try_mut, try_component, Layout, LayoutCache,
+};
 
 /// The `PopupLayout` handles measuring and arrangement of a `Popup` widget.
 #[derive(Default, IntoLayout)]
 pub struct PopupLayout {
     // TODO: Add padding to the widget.
     desired_size: RefCell<DirtySize>,
+    cache: LayoutCache,
 }
 
 /// Associated functions, that react on any given state change inside the `Popup` widget.
@@ -171,6 +174,8 @@ impl Layout for PopupLayout {
             println!("Target not found");
         }
 
+        // the popup follows its target, therefore it is measured also if the cache is valid
+        self.cache.set_measured(*self.desired_size.borrow());
         *self.desired_size.borrow()
     }
 
@@ -188,7 +193,7 @@ impl Layout for PopupLayout {
             return (0.0, 0.0);
         }
 
-        if !self.desired_size.borrow().dirty() {
+        if !self.desired_size.borrow().dirty() && self.cache.is_arranged(parent_size) {
             return self.desired_size.borrow().size();
         }
 
@@ -276,6 +281,11 @@ impl Layout for PopupLayout {
         }
 
         self.desired_size.borrow_mut().set_dirty(false);
+        self.cache.set_arranged(parent_size);
         size
     }
+
+    fn cache(&self) -> Option<&LayoutCache> {
+        Some(&self.cache)
+    }
 }
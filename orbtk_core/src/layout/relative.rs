@@ -0,0 +1,686 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+    fmt,
+};
+
+use dces::prelude::*;
+
+use crate::{
+    prelude::*, proc_macros::IntoLayout, render::RenderContext2D, theming::*, tree::Tree,
+    utils::prelude::*,
+};
+
+use super::{
+    component, component_or_default, component_try_mut, mirror_children, set_component,
+    try_component, Layout, LayoutCache,
+};
+
+/// Describes why the anchors of the children of a `RelativeLayout` could not be resolved. The
+/// affected anchors are ignored.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AnchorError {
+    /// The anchors of the children with the given ids depend on each other.
+    Cycle(Vec<String>),
+
+    /// A child is anchored to an id that is not the id of a sibling.
+    UnknownId(String),
+}
+
+impl fmt::Display for AnchorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnchorError::Cycle(ids) => write!(f, "anchor cycle: {}", ids.join(" -> ")),
+            AnchorError::UnknownId(id) => write!(f, "anchor to unknown id: {}", id),
+        }
+    }
+}
+
+/// The errors of the anchors of the children of a `RelativeLayout`.
+pub type AnchorErrors = Vec<AnchorError>;
+
+/// Positions each child relative to the panel or to its siblings. The anchors are read from the
+/// attached `anchors` property of the child, a sibling is referenced by its `id`. Children
+/// without anchors are placed at the top left corner. If the anchors of some children depend on
+/// each other, the anchors of these children to their siblings are ignored. Cycles and unknown
+/// ids are stored as `AnchorError` in the `anchor_errors` property of the widget.
+#[derive(Default, IntoLayout)]
+pub struct RelativeLayout {
+    desired_size: RefCell<DirtySize>,
+    old_alignment: Cell<(Alignment, Alignment)>,
+    items: RefCell<Vec<(Entity, AnchoredItem)>>,
+    cache: LayoutCache,
+}
+
+impl RelativeLayout {
+    /// Presets the defaults.
+    pub fn new() -> Self {
+        RelativeLayout::default()
+    }
+
+    pub fn set_dirty(&self, dirty: bool) {
+        self.desired_size.borrow_mut().set_dirty(dirty);
+    }
+}
+
+impl Layout for RelativeLayout {
+    fn measure(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> DirtySize {
+        if self.cache.is_measured() {
+            return *self.desired_size.borrow();
+        }
+
+        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
+            let mut desired = self.desired_size.borrow_mut();
+            desired.set_size(0.0, 0.0);
+            self.cache.set_measured(*desired);
+            return *desired;
+        }
+
+        let halign: Alignment = component(ecm, entity, "h_align");
+        let valign: Alignment = component(ecm, entity, "v_align");
+        let (old_valign, old_halign) = self.old_alignment.get();
+
+        if halign != old_halign || valign != old_valign {
+            self.set_dirty(true);
+        }
+
+        let constraint: Constraint = component(ecm, entity, "constraint");
+        let mut dirty = false;
+        let mut items = vec![];
+
+        let nchildren = ecm.entity_store().children[&entity].len();
+
+        for index in 0..nchildren {
+            let child = ecm.entity_store().children[&entity][index];
+
+            if let Some(child_layout) = layouts.get(&child) {
+                let child_desired_size =
+                    child_layout.measure(render_context_2_d, child, ecm, layouts, theme);
+
+                if child_desired_size.dirty() || self.desired_size.borrow().dirty() {
+                    dirty = true;
+                }
+
+                let child_margin = {
+                    if child_desired_size.width() > 0.0 && child_desired_size.height() > 0.0 {
+                        component(ecm, child, "margin")
+                    } else {
+                        Thickness::default()
+                    }
+                };
+
+                items.push((
+                    child,
+                    AnchoredItem {
+                        id: try_component(ecm, child, "id"),
+                        anchors: component_or_default(ecm, child, "anchors"),
+                        size: (
+                            child_desired_size.width() + child_margin.left() + child_margin.right(),
+                            child_desired_size.height()
+                                + child_margin.top()
+                                + child_margin.bottom(),
+                        ),
+                    },
+                ));
+            }
+        }
+
+        let anchored: Vec<AnchoredItem> = items.iter().map(|(_, item)| item.clone()).collect();
+        *self.items.borrow_mut() = items;
+
+        // the errors are reported by the arrangement
+        let (rects, _) = relative_rects(&anchored, None);
+        let desired_size = constraint.perform(relative_size(&rects));
+
+        self.set_dirty(dirty);
+
+        let mut desired = self.desired_size.borrow_mut();
+        desired.set_size(desired_size.0, desired_size.1);
+        self.cache.set_measured(*desired);
+        *desired
+    }
+
+    fn arrange(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        parent_size: (f64, f64),
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> (f64, f64) {
+        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
+            self.desired_size.borrow_mut().set_size(0.0, 0.0);
+            return (0.0, 0.0);
+        }
+
+        if !self.desired_size.borrow().dirty() && self.cache.is_arranged(parent_size) {
+            return self.desired_size.borrow().size();
+        }
+
+        let halign: Alignment = component(ecm, entity, "h_align");
+        let valign: Alignment = component(ecm, entity, "v_align");
+        let margin: Thickness = component(ecm, entity, "margin");
+        let constraint: Constraint = component(ecm, entity, "constraint");
+
+        let size = constraint.perform((
+            halign.align_measure(
+                parent_size.0,
+                self.desired_size.borrow().width(),
+                margin.left(),
+                margin.right(),
+            ),
+            valign.align_measure(
+                parent_size.1,
+                self.desired_size.borrow().height(),
+                margin.top(),
+                margin.bottom(),
+            ),
+        ));
+
+        let items = self.items.borrow().clone();
+        let anchored: Vec<AnchoredItem> = items.iter().map(|(_, item)| item.clone()).collect();
+        let (rects, errors) = relative_rects(&anchored, Some(size));
+
+        set_component::<AnchorErrors>(ecm, entity, "anchor_errors", errors);
+
+        for ((child, _), (x, y, width, height)) in items.iter().zip(rects) {
+            let child = *child;
+
+            let mut child_desired_size = (0.0, 0.0);
+            if let Some(child_layout) = layouts.get(&child) {
+                child_desired_size = child_layout.arrange(
+                    render_context_2_d,
+                    (width, height),
+                    child,
+                    ecm,
+                    layouts,
+                    theme,
+                );
+            }
+
+            let child_margin = {
+                if child_desired_size.0 > 0.0 && child_desired_size.1 > 0.0 {
+                    component(ecm, child, "margin")
+                } else {
+                    Thickness::default()
+                }
+            };
+
+            let child_halign: Alignment = component(ecm, child, "h_align");
+            let child_valign: Alignment = component(ecm, child, "v_align");
+
+            if let Some(child_bounds) = component_try_mut::<Rectangle>(ecm, child, "bounds") {
+                child_bounds.set_x(
+                    x + child_halign.align_position(
+                        width,
+                        child_bounds.width(),
+                        child_margin.left(),
+                        child_margin.right(),
+                    ),
+                );
+                child_bounds.set_y(
+                    y + child_valign.align_position(
+                        height,
+                        child_bounds.height(),
+                        child_margin.top(),
+                        child_margin.bottom(),
+                    ),
+                );
+            }
+
+            mark_as_dirty("bounds", child, ecm);
+        }
+
+        mirror_children(ecm, entity, size.0);
+
+        if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
+            bounds.set_width(size.0);
+            bounds.set_height(size.1);
+        }
+
+        mark_as_dirty("bounds", entity, ecm);
+
+        self.set_dirty(false);
+        self.cache.set_arranged(parent_size);
+        size
+    }
+
+    fn cache(&self) -> Option<&LayoutCache> {
+        Some(&self.cache)
+    }
+}
+
+/// A child of a `RelativeLayout` with its desired size (including margins).
+#[derive(Clone, Debug, Default, PartialEq)]
+struct AnchoredItem {
+    id: Option<String>,
+    anchors: Anchors,
+    size: (f64, f64),
+}
+
+// Edge of a child or of the referenced panel or sibling on one axis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Edge {
+    Start,
+    Center,
+    End,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Target {
+    Panel,
+    Sibling(usize),
+}
+
+// The resolved anchors of a child on one axis.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct AxisRule {
+    start: Option<(Target, Edge)>,
+    center: Option<(Target, Edge)>,
+    end: Option<(Target, Edge)>,
+}
+
+impl AxisRule {
+    fn set(&mut self, edge: Edge, anchor: (Target, Edge)) {
+        match edge {
+            Edge::Start => self.start = Some(anchor),
+            Edge::Center => self.center = Some(anchor),
+            Edge::End => self.end = Some(anchor),
+        }
+    }
+
+    fn siblings(&self) -> Vec<usize> {
+        [self.start, self.center, self.end]
+            .iter()
+            .filter_map(|anchor| match anchor {
+                Some((Target::Sibling(index), _)) => Some(*index),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn remove_siblings(&mut self) {
+        for anchor in [&mut self.start, &mut self.center, &mut self.end] {
+            if let Some((Target::Sibling(_), _)) = anchor {
+                *anchor = None;
+            }
+        }
+    }
+}
+
+// Maps an anchor to the edge of the child and the edge of the target on the given axis.
+fn axis_edges(anchor: &Anchor, orientation: Orientation) -> Option<(Edge, Edge)> {
+    match (orientation, anchor) {
+        (Orientation::Horizontal, Anchor::LeftOf(_)) => Some((Edge::End, Edge::Start)),
+        (Orientation::Horizontal, Anchor::RightOf(_)) => Some((Edge::Start, Edge::End)),
+        (Orientation::Horizontal, Anchor::AlignLeftWith(_))
+        | (Orientation::Horizontal, Anchor::AlignLeftWithPanel) => Some((Edge::Start, Edge::Start)),
+        (Orientation::Horizontal, Anchor::AlignRightWith(_))
+        | (Orientation::Horizontal, Anchor::AlignRightWithPanel) => Some((Edge::End, Edge::End)),
+        (Orientation::Horizontal, Anchor::AlignHorizontalCenterWith(_))
+        | (Orientation::Horizontal, Anchor::AlignHorizontalCenterWithPanel) => {
+            Some((Edge::Center, Edge::Center))
+        }
+        (Orientation::Vertical, Anchor::Above(_)) => Some((Edge::End, Edge::Start)),
+        (Orientation::Vertical, Anchor::Below(_)) => Some((Edge::Start, Edge::End)),
+        (Orientation::Vertical, Anchor::AlignTopWith(_))
+        | (Orientation::Vertical, Anchor::AlignTopWithPanel) => Some((Edge::Start, Edge::Start)),
+        (Orientation::Vertical, Anchor::AlignBottomWith(_))
+        | (Orientation::Vertical, Anchor::AlignBottomWithPanel) => Some((Edge::End, Edge::End)),
+        (Orientation::Vertical, Anchor::AlignVerticalCenterWith(_))
+        | (Orientation::Vertical, Anchor::AlignVerticalCenterWithPanel) => {
+            Some((Edge::Center, Edge::Center))
+        }
+        _ => None,
+    }
+}
+
+// Resolves the anchors of the items on the given axis, anchors to unknown ids are reported
+// and ignored.
+fn axis_rules(
+    items: &[AnchoredItem],
+    orientation: Orientation,
+    errors: &mut Vec<AnchorError>,
+) -> Vec<AxisRule> {
+    let mut rules = vec![AxisRule::default(); items.len()];
+
+    for (rule, item) in rules.iter_mut().zip(items) {
+        for anchor in item.anchors.iter() {
+            let (edge, target_edge) = match axis_edges(anchor, orientation) {
+                Some(edges) => edges,
+                None => continue,
+            };
+
+            let target = match anchor.target() {
+                Some(id) => match items
+                    .iter()
+                    .position(|sibling| sibling.id.as_deref() == Some(id))
+                {
+                    Some(index) => Target::Sibling(index),
+                    None => {
+                        errors.push(AnchorError::UnknownId(id.to_string()));
+                        continue;
+                    }
+                },
+                None => Target::Panel,
+            };
+
+            rule.set(edge, (target, target_edge));
+        }
+    }
+
+    rules
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Mark {
+    None,
+    Visiting,
+    Done,
+}
+
+// Visits the siblings the item depends on before the item. Returns the items of the cycle if
+// an item depends on itself.
+fn visit(
+    index: usize,
+    rules: &[AxisRule],
+    marks: &mut [Mark],
+    stack: &mut Vec<usize>,
+    order: &mut Vec<usize>,
+) -> Result<(), Vec<usize>> {
+    match marks[index] {
+        Mark::Done => return Ok(()),
+        Mark::Visiting => {
+            let start = stack.iter().position(|i| *i == index).unwrap_or(0);
+            return Err(stack[start..].to_vec());
+        }
+        Mark::None => {}
+    }
+
+    marks[index] = Mark::Visiting;
+    stack.push(index);
+
+    for sibling in rules[index].siblings() {
+        visit(sibling, rules, marks, stack, order)?;
+    }
+
+    stack.pop();
+    marks[index] = Mark::Done;
+    order.push(index);
+
+    Ok(())
+}
+
+// Sorts the items, so that each item comes after the siblings it is anchored to.
+fn solve_order(rules: &[AxisRule]) -> Result<Vec<usize>, Vec<usize>> {
+    let mut marks = vec![Mark::None; rules.len()];
+    let mut order = vec![];
+
+    for index in 0..rules.len() {
+        visit(index, rules, &mut marks, &mut vec![], &mut order)?;
+    }
+
+    Ok(order)
+}
+
+// Calculates the (position, size) of each item on one axis. Without an extent (measure) the
+// panel has no size and items that are anchored on both edges keep their size.
+fn solve_axis(
+    rules: &mut [AxisRule],
+    items: &[AnchoredItem],
+    sizes: &[f64],
+    extent: Option<f64>,
+    errors: &mut Vec<AnchorError>,
+) -> Vec<(f64, f64)> {
+    let order = loop {
+        match solve_order(rules) {
+            Ok(order) => break order,
+            Err(cycle) => {
+                errors.push(AnchorError::Cycle(
+                    cycle
+                        .iter()
+                        .map(|index| items[*index].id.clone().unwrap_or_default())
+                        .collect(),
+                ));
+
+                for index in cycle {
+                    rules[index].remove_siblings();
+                }
+            }
+        }
+    };
+
+    let mut placed = vec![(0.0, 0.0); rules.len()];
+
+    for index in order {
+        let size = sizes[index];
+        let rule = rules[index];
+
+        let resolve = |anchor: Option<(Target, Edge)>| {
+            anchor.map(|(target, edge)| {
+                let (position, size) = match target {
+                    Target::Panel => (0.0, extent.unwrap_or_default()),
+                    Target::Sibling(sibling) => placed[sibling],
+                };
+
+                match edge {
+                    Edge::Start => position,
+                    Edge::Center => position + size / 2.0,
+                    Edge::End => position + size,
+                }
+            })
+        };
+
+        let start = resolve(rule.start);
+        let center = resolve(rule.center);
+        let end = resolve(rule.end);
+
+        placed[index] = match (start, end, center) {
+            (Some(start), Some(end), _) if extent.is_some() => (start, (end - start).max(0.0)),
+            (Some(start), _, _) => (start, size),
+            (None, Some(end), _) => (end - size, size),
+            (None, None, Some(center)) => (center - size / 2.0, size),
+            _ => (0.0, size),
+        };
+    }
+
+    placed
+}
+
+/// Calculates the (x, y, width, height) rectangles of the anchored items inside of the given
+/// size. Without a size the rectangles are calculated for the measurement.
+fn relative_rects(
+    items: &[AnchoredItem],
+    size: Option<(f64, f64)>,
+) -> (Vec<(f64, f64, f64, f64)>, Vec<AnchorError>) {
+    let mut errors = vec![];
+
+    let mut horizontal = axis_rules(items, Orientation::Horizontal, &mut errors);
+    let mut vertical = axis_rules(items, Orientation::Vertical, &mut errors);
+
+    let widths: Vec<f64> = items.iter().map(|item| item.size.0).collect();
+    let heights: Vec<f64> = items.iter().map(|item| item.size.1).collect();
+
+    let xs = solve_axis(
+        &mut horizontal,
+        items,
+        &widths,
+        size.map(|size| size.0),
+        &mut errors,
+    );
+    let ys = solve_axis(
+        &mut vertical,
+        items,
+        &heights,
+        size.map(|size| size.1),
+        &mut errors,
+    );
+
+    let rects = xs
+        .iter()
+        .zip(ys)
+        .map(|((x, width), (y, height))| (*x, y, *width, height))
+        .collect();
+
+    (rects, errors)
+}
+
+/// Calculates the size that is needed to show all rectangles of a measurement, rectangles that
+/// are anchored to the end of the panel have negative positions.
+fn relative_size(rects: &[(f64, f64, f64, f64)]) -> (f64, f64) {
+    let (min_x, min_y, max_x, max_y) = rects.iter().fold(
+        (0.0_f64, 0.0_f64, 0.0_f64, 0.0_f64),
+        |(min_x, min_y, max_x, max_y), (x, y, width, height)| {
+            (
+                min_x.min(*x),
+                min_y.min(*y),
+                max_x.max(x + width),
+                max_y.max(y + height),
+            )
+        },
+    );
+
+    (max_x - min_x, max_y - min_y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(id: &str, anchors: &str, size: (f64, f64)) -> AnchoredItem {
+        AnchoredItem {
+            id: if id.is_empty() {
+                None
+            } else {
+                Some(id.to_string())
+            },
+            anchors: anchors.into(),
+            size,
+        }
+    }
+
+    #[test]
+    fn form() {
+        let items = [
+            item("name_label", "", (80.0, 20.0)),
+            item(
+                "name",
+                "right-of(name_label), align-right-with-panel, align-vertical-center-with(name_label)",
+                (150.0, 30.0),
+            ),
+            item("mail_label", "below(name), align-right-with(name_label)", (60.0, 20.0)),
+            item(
+                "mail",
+                "right-of(name_label), below(name), align-right-with-panel",
+                (150.0, 30.0),
+            ),
+        ];
+
+        let (rects, errors) = relative_rects(&items, Some((300.0, 100.0)));
+
+        assert!(errors.is_empty());
+        assert_eq!(
+            rects,
+            vec![
+                (0.0, 0.0, 80.0, 20.0),
+                (80.0, -5.0, 220.0, 30.0),
+                (20.0, 25.0, 60.0, 20.0),
+                (80.0, 25.0, 220.0, 30.0),
+            ]
+        );
+
+        let (rects, _) = relative_rects(&items, None);
+        assert_eq!(relative_size(&rects), (230.0, 60.0));
+    }
+
+    #[test]
+    fn panel() {
+        let items = [
+            item(
+                "",
+                "align-right-with-panel, align-bottom-with-panel",
+                (20.0, 10.0),
+            ),
+            item(
+                "",
+                "align-horizontal-center-with-panel, align-vertical-center-with-panel",
+                (40.0, 40.0),
+            ),
+            item("", "", (10.0, 10.0)),
+        ];
+
+        let (rects, _) = relative_rects(&items, Some((200.0, 100.0)));
+
+        assert_eq!(
+            rects,
+            vec![
+                (180.0, 90.0, 20.0, 10.0),
+                (80.0, 30.0, 40.0, 40.0),
+                (0.0, 0.0, 10.0, 10.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn left_of_and_above() {
+        let items = [
+            item(
+                "ok",
+                "align-right-with-panel, align-bottom-with-panel",
+                (50.0, 20.0),
+            ),
+            item("cancel", "left-of(ok), above(ok)", (60.0, 20.0)),
+        ];
+
+        let (rects, _) = relative_rects(&items, Some((200.0, 100.0)));
+
+        assert_eq!(rects[1], (90.0, 60.0, 60.0, 20.0));
+    }
+
+    #[test]
+    fn cycle() {
+        let items = [
+            item("a", "right-of(c)", (10.0, 10.0)),
+            item("b", "right-of(a)", (10.0, 10.0)),
+            item("c", "right-of(b), below(a)", (10.0, 10.0)),
+            item("d", "right-of(d)", (10.0, 10.0)),
+        ];
+
+        let (rects, errors) = relative_rects(&items, Some((100.0, 100.0)));
+
+        assert_eq!(
+            errors,
+            vec![
+                AnchorError::Cycle(vec!["a".to_string(), "c".to_string(), "b".to_string()]),
+                AnchorError::Cycle(vec!["d".to_string()]),
+            ]
+        );
+
+        // the cyclic anchors are ignored, the other ones are still resolved
+        assert_eq!(rects[0], (0.0, 0.0, 10.0, 10.0));
+        assert_eq!(rects[2], (0.0, 10.0, 10.0, 10.0));
+        assert_eq!(rects[3], (0.0, 0.0, 10.0, 10.0));
+    }
+
+    #[test]
+    fn unknown_id() {
+        let items = [item("a", "below(missing)", (10.0, 10.0))];
+
+        let (rects, errors) = relative_rects(&items, Some((100.0, 100.0)));
+
+        assert_eq!(errors, vec![AnchorError::UnknownId("missing".to_string())]);
+        assert_eq!(rects, vec![(0.0, 0.0, 10.0, 10.0)]);
+        assert_eq!(
+            errors[0].to_string(),
+            "anchor to unknown id: missing".to_string()
+        );
+    }
+}
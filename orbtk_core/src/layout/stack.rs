@@ -10,13 +10,16 @@ use crate::{
     utils::prelude::*,
 };
 
-use super::{component, component_or_default, component_try_mut, Layout};
+use super::{
+    component, component_or_default, component_try_mut, mirror_children, Layout, LayoutCache,
+};
 
 /// Stacks visual the children widgets vertical or horizontal.
 #[derive(Default, IntoLayout)]
 pub struct StackLayout {
     desired_size: RefCell<DirtySize>,
     old_alignment: Cell<(Alignment, Alignment)>,
+    cache: LayoutCache,
 }
 
 impl StackLayout {
@@ -39,9 +42,14 @@ impl Layout for StackLayout {
         layouts: &BTreeMap<Entity, Box<dyn Layout>>,
         theme: &Theme,
     ) -> DirtySize {
+        if self.cache.is_measured() {
+            return *self.desired_size.borrow();
+        }
+
         if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
             let mut desired = self.desired_size.borrow_mut();
             desired.set_size(0.0, 0.0);
+            self.cache.set_measured(*desired);
             return *desired;
         }
 
@@ -96,6 +104,7 @@ impl Layout for StackLayout {
 
         let mut desired = self.desired_size.borrow_mut();
         desired.set_size(desired_size.0, desired_size.1);
+        self.cache.set_measured(*desired);
         *desired
     }
 
@@ -113,7 +122,7 @@ impl Layout for StackLayout {
             return (0.0, 0.0);
         }
 
-        if !self.desired_size.borrow().dirty() {
+        if !self.desired_size.borrow().dirty() && self.cache.is_arranged(parent_size) {
             return self.desired_size.borrow().size();
         }
 
@@ -206,6 +215,9 @@ impl Layout for StackLayout {
             }
         }
 
+        // right-to-left: the first child of a horizontal stack is placed on the right
+        mirror_children(ecm, entity, size.0);
+
         if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
             bounds.set_width(size.0);
             bounds.set_height(size.1);
@@ -213,8 +225,13 @@ impl Layout for StackLayout {
 
         mark_as_dirty("bounds", entity, ecm);
 
+        self.cache.set_arranged(parent_size);
         size
     }
+
+    fn cache(&self) -> Option<&LayoutCache> {
+        Some(&self.cache)
+    }
 }
 
 fn apply_arrangement(
@@ -0,0 +1,171 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+};
+
+use dces::prelude::*;
+
+use crate::{
+    prelude::*, proc_macros::IntoLayout, render::RenderContext2D, theming::*, tree::Tree,
+    utils::prelude::*,
+};
+
+use super::{component, component_try_mut, Layout, LayoutCache};
+
+/// Arranges the children of a viewbox with their desired size. The scaling of the children
+/// to the size of the viewbox is done by the `ViewboxRenderObject`.
+#[derive(Default, IntoLayout)]
+pub struct ViewboxLayout {
+    desired_size: RefCell<DirtySize>,
+    old_alignment: Cell<(Alignment, Alignment)>,
+    content_size: Cell<(f64, f64)>,
+    cache: LayoutCache,
+}
+
+impl ViewboxLayout {
+    /// Presets the defaults.
+    pub fn new() -> Self {
+        ViewboxLayout::default()
+    }
+}
+
+impl Layout for ViewboxLayout {
+    fn measure(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> DirtySize {
+        if self.cache.is_measured() {
+            return *self.desired_size.borrow();
+        }
+
+        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
+            self.desired_size.borrow_mut().set_size(0.0, 0.0);
+            self.cache.set_measured(*self.desired_size.borrow());
+            return *self.desired_size.borrow();
+        }
+
+        let horizontal_alignment: Alignment = component(ecm, entity, "h_align");
+        let vertical_alignment: Alignment = component(ecm, entity, "v_align");
+
+        if horizontal_alignment != self.old_alignment.get().1
+            || vertical_alignment != self.old_alignment.get().0
+        {
+            self.desired_size.borrow_mut().set_dirty(true);
+        }
+
+        let mut content_size: (f64, f64) = (0.0, 0.0);
+
+        for index in 0..ecm.entity_store().children[&entity].len() {
+            let child = ecm.entity_store().children[&entity][index];
+
+            if let Some(child_layout) = layouts.get(&child) {
+                let child_desired_size =
+                    child_layout.measure(render_context_2_d, child, ecm, layouts, theme);
+
+                let dirty = child_desired_size.dirty() || self.desired_size.borrow().dirty();
+                self.desired_size.borrow_mut().set_dirty(dirty);
+
+                let child_margin: Thickness = component(ecm, child, "margin");
+
+                let child_width =
+                    child_desired_size.width() + child_margin.left() + child_margin.right();
+                let child_height =
+                    child_desired_size.height() + child_margin.top() + child_margin.bottom();
+
+                content_size = (
+                    content_size.0.max(child_width),
+                    content_size.1.max(child_height),
+                );
+            }
+        }
+
+        self.content_size.set(content_size);
+
+        let constraint: Constraint = component(ecm, entity, "constraint");
+        let desired_size = constraint.perform(content_size);
+
+        self.desired_size
+            .borrow_mut()
+            .set_size(desired_size.0, desired_size.1);
+        self.cache.set_measured(*self.desired_size.borrow());
+        *self.desired_size.borrow()
+    }
+
+    fn arrange(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        parent_size: (f64, f64),
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> (f64, f64) {
+        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
+            self.desired_size.borrow_mut().set_size(0.0, 0.0);
+            return (0.0, 0.0);
+        }
+
+        if !self.desired_size.borrow().dirty() && self.cache.is_arranged(parent_size) {
+            return self.desired_size.borrow().size();
+        }
+
+        let horizontal_alignment: Alignment = component(ecm, entity, "h_align");
+        let vertical_alignment: Alignment = component(ecm, entity, "v_align");
+        let margin: Thickness = component(ecm, entity, "margin");
+        let constraint: Constraint = component(ecm, entity, "constraint");
+
+        let size = constraint.perform((
+            horizontal_alignment.align_measure(
+                parent_size.0,
+                self.desired_size.borrow().width(),
+                margin.left(),
+                margin.right(),
+            ),
+            vertical_alignment.align_measure(
+                parent_size.1,
+                self.desired_size.borrow().height(),
+                margin.top(),
+                margin.bottom(),
+            ),
+        ));
+
+        if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
+            bounds.set_width(size.0);
+            bounds.set_height(size.1);
+        }
+
+        mark_as_dirty("bounds", entity, ecm);
+
+        // the children are arranged unscaled inside of the content size
+        let content_size = self.content_size.get();
+
+        for index in 0..ecm.entity_store().children[&entity].len() {
+            let child = ecm.entity_store().children[&entity][index];
+
+            if let Some(child_layout) = layouts.get(&child) {
+                child_layout.arrange(render_context_2_d, content_size, child, ecm, layouts, theme);
+            }
+
+            let child_margin: Thickness = component(ecm, child, "margin");
+
+            if let Some(child_bounds) = component_try_mut::<Rectangle>(ecm, child, "bounds") {
+                child_bounds.set_x(child_margin.left());
+                child_bounds.set_y(child_margin.top());
+            }
+
+            mark_as_dirty("bounds", child, ecm);
+        }
+
+        self.desired_size.borrow_mut().set_dirty(false);
+        self.cache.set_arranged(parent_size);
+        size
+    }
+
+    fn cache(&self) -> Option<&LayoutCache> {
+        Some(&self.cache)
+    }
+}
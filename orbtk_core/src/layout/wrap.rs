@@ -0,0 +1,438 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+};
+
+use dces::prelude::*;
+
+use crate::{
+    prelude::*, proc_macros::IntoLayout, render::RenderContext2D, theming::*, tree::Tree,
+    utils::prelude::*,
+};
+
+use super::{component, component_or_default, component_try_mut, Layout, LayoutCache};
+
+/// Places the children one after another in the direction of the `orientation` and
+/// continues on a new line (or column) if the available space is exhausted.
+///
+/// The layout reads the following properties of its widget:
+///
+/// * `orientation`: direction in which the children are placed, lines wrap on the other axis
+/// * `item_spacing`: space between two children of a line
+/// * `line_spacing`: space between two lines
+/// * `line_alignment`: alignment of the children inside of a line on the cross axis, `Stretch`
+/// uses the alignment of the child
+/// * `justification`: distribution of the free space of a line on the main axis
+#[derive(Default, IntoLayout)]
+pub struct WrapLayout {
+    desired_size: RefCell<DirtySize>,
+    old_alignment: Cell<(Alignment, Alignment)>,
+    available_size: Cell<f64>,
+    measured_limit: Cell<f64>,
+    items: RefCell<Vec<WrapItem>>,
+    cache: LayoutCache,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct WrapItem {
+    entity: Entity,
+    size: (f64, f64),
+    margin: Thickness,
+}
+
+/// Describes a line of children of a `WrapLayout`, sizes are given in (main, cross) order.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(super) struct WrapLine {
+    pub start: usize,
+    pub end: usize,
+    pub main: f64,
+    pub cross: f64,
+}
+
+impl WrapLayout {
+    /// Presets the defaults.
+    pub fn new() -> Self {
+        WrapLayout::default()
+    }
+
+    pub fn set_dirty(&self, dirty: bool) {
+        self.desired_size.borrow_mut().set_dirty(dirty);
+    }
+}
+
+impl Layout for WrapLayout {
+    fn measure(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> DirtySize {
+        if self.cache.is_measured() {
+            return *self.desired_size.borrow();
+        }
+
+        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
+            let mut desired = self.desired_size.borrow_mut();
+            desired.set_size(0.0, 0.0);
+            self.cache.set_measured(*desired);
+            return *desired;
+        }
+
+        let halign: Alignment = component(ecm, entity, "h_align");
+        let valign: Alignment = component(ecm, entity, "v_align");
+        let (old_valign, old_halign) = self.old_alignment.get();
+
+        if halign != old_halign || valign != old_valign {
+            self.set_dirty(true);
+        }
+
+        let orientation: Orientation = component_or_default(ecm, entity, "orientation");
+        let constraint: Constraint = component(ecm, entity, "constraint");
+        let item_spacing: f64 = component_or_default(ecm, entity, "item_spacing");
+        let line_spacing: f64 = component_or_default(ecm, entity, "line_spacing");
+        let mut dirty = false;
+        let mut items = vec![];
+
+        let nchildren = ecm.entity_store().children[&entity].len();
+
+        for index in 0..nchildren {
+            let child = ecm.entity_store().children[&entity][index];
+
+            if let Some(child_layout) = layouts.get(&child) {
+                let child_desired_size =
+                    child_layout.measure(render_context_2_d, child, ecm, layouts, theme);
+
+                if child_desired_size.dirty() || self.desired_size.borrow().dirty() {
+                    dirty = true;
+                }
+
+                // collapsed or empty children do not take part in a line
+                if child_desired_size.width() <= 0.0 || child_desired_size.height() <= 0.0 {
+                    continue;
+                }
+
+                let margin: Thickness = component(ecm, child, "margin");
+
+                items.push(WrapItem {
+                    entity: child,
+                    size: (
+                        child_desired_size.width() + margin.left() + margin.right(),
+                        child_desired_size.height() + margin.top() + margin.bottom(),
+                    ),
+                    margin,
+                });
+            }
+        }
+
+        let sizes: Vec<(f64, f64)> = items
+            .iter()
+            .map(|item| main_cross(item.size, orientation))
+            .collect();
+        let limit = wrap_limit(&constraint, orientation, self.available_size.get());
+        self.measured_limit.set(limit);
+        let lines = wrap_lines(&sizes, limit, item_spacing);
+
+        *self.items.borrow_mut() = items;
+
+        let desired_size = main_cross(lines_size(&lines, line_spacing), orientation);
+        let desired_size = constraint.perform(desired_size);
+
+        self.set_dirty(dirty);
+
+        let mut desired = self.desired_size.borrow_mut();
+        desired.set_size(desired_size.0, desired_size.1);
+        self.cache.set_measured(*desired);
+        *desired
+    }
+
+    fn arrange(
+        &self,
+        render_context_2_d: &mut RenderContext2D,
+        parent_size: (f64, f64),
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> (f64, f64) {
+        if component::<Visibility>(ecm, entity, "visibility") == Visibility::Collapsed {
+            self.desired_size.borrow_mut().set_size(0.0, 0.0);
+            return (0.0, 0.0);
+        }
+
+        if !self.desired_size.borrow().dirty() && self.cache.is_arranged(parent_size) {
+            return self.desired_size.borrow().size();
+        }
+
+        let halign: Alignment = component(ecm, entity, "h_align");
+        let valign: Alignment = component(ecm, entity, "v_align");
+        let margin: Thickness = component(ecm, entity, "margin");
+        let constraint: Constraint = component(ecm, entity, "constraint");
+        let orientation: Orientation = component_or_default(ecm, entity, "orientation");
+        let item_spacing: f64 = component_or_default(ecm, entity, "item_spacing");
+        let line_spacing: f64 = component_or_default(ecm, entity, "line_spacing");
+        let line_alignment: Alignment = component_or_default(ecm, entity, "line_alignment");
+        let justification: Justification = component_or_default(ecm, entity, "justification");
+
+        let size = constraint.perform((
+            halign.align_measure(
+                parent_size.0,
+                self.desired_size.borrow().width(),
+                margin.left(),
+                margin.right(),
+            ),
+            valign.align_measure(
+                parent_size.1,
+                self.desired_size.borrow().height(),
+                margin.top(),
+                margin.bottom(),
+            ),
+        ));
+
+        let (available_main, _) = main_cross(size, orientation);
+        self.available_size.set(available_main);
+
+        // the lines are measured for another size, e.g. the available size is not known on the
+        // first measure. The widget is measured again, so its parent reserves the new lines.
+        if wrap_limit(&constraint, orientation, available_main) != self.measured_limit.get() {
+            invalidate_layout(entity, LayoutInvalidation::Measure, ecm);
+        }
+
+        let items = self.items.borrow().clone();
+        let sizes: Vec<(f64, f64)> = items
+            .iter()
+            .map(|item| main_cross(item.size, orientation))
+            .collect();
+        let lines = wrap_lines(&sizes, available_main, item_spacing);
+
+        let mut line_offset = 0.0;
+
+        for line in lines.iter() {
+            let (leading, between) =
+                justification.distribute(available_main - line.main, line.end - line.start);
+            let mut item_offset = leading;
+
+            for (item, (item_main, item_cross)) in items[line.start..line.end]
+                .iter()
+                .zip(&sizes[line.start..line.end])
+            {
+                let child = item.entity;
+
+                let child_alignment: Alignment = match orientation {
+                    Orientation::Horizontal => component(ecm, child, "v_align"),
+                    Orientation::Vertical => component(ecm, child, "h_align"),
+                };
+
+                let cross_alignment = if line_alignment == Alignment::Stretch {
+                    child_alignment
+                } else {
+                    line_alignment
+                };
+
+                let available_cross = if cross_alignment == Alignment::Stretch {
+                    line.cross
+                } else {
+                    *item_cross
+                };
+
+                if let Some(child_layout) = layouts.get(&child) {
+                    child_layout.arrange(
+                        render_context_2_d,
+                        main_cross((*item_main, available_cross), orientation),
+                        child,
+                        ecm,
+                        layouts,
+                        theme,
+                    );
+                }
+
+                if let Some(child_bounds) = component_try_mut::<Rectangle>(ecm, child, "bounds") {
+                    match orientation {
+                        Orientation::Horizontal => {
+                            child_bounds.set_x(item_offset + item.margin.left());
+                            child_bounds.set_y(
+                                line_offset
+                                    + cross_alignment.align_position(
+                                        line.cross,
+                                        child_bounds.height(),
+                                        item.margin.top(),
+                                        item.margin.bottom(),
+                                    ),
+                            );
+                        }
+                        Orientation::Vertical => {
+                            child_bounds.set_x(
+                                line_offset
+                                    + cross_alignment.align_position(
+                                        line.cross,
+                                        child_bounds.width(),
+                                        item.margin.left(),
+                                        item.margin.right(),
+                                    ),
+                            );
+                            child_bounds.set_y(item_offset + item.margin.top());
+                        }
+                    }
+                }
+
+                mark_as_dirty("bounds", child, ecm);
+
+                item_offset += item_main + item_spacing + between;
+            }
+
+            line_offset += line.cross + line_spacing;
+        }
+
+        self.set_dirty(false);
+
+        let (_, lines_cross) = lines_size(&lines, line_spacing);
+        let size = main_cross((available_main, lines_cross), orientation);
+
+        if let Some(bounds) = component_try_mut::<Rectangle>(ecm, entity, "bounds") {
+            bounds.set_width(size.0);
+            bounds.set_height(size.1);
+        }
+
+        mark_as_dirty("bounds", entity, ecm);
+
+        self.cache.set_arranged(parent_size);
+        size
+    }
+
+    fn cache(&self) -> Option<&LayoutCache> {
+        Some(&self.cache)
+    }
+}
+
+// Converts a (width, height) size to (main, cross) order and vice versa.
+pub(super) fn main_cross(size: (f64, f64), orientation: Orientation) -> (f64, f64) {
+    match orientation {
+        Orientation::Horizontal => size,
+        Orientation::Vertical => (size.1, size.0),
+    }
+}
+
+// Returns the main size the lines are wrapped at during measure. The parent size is not
+// known at this point, so the constraint or the size of the last arrangement is used.
+pub(super) fn wrap_limit(constraint: &Constraint, orientation: Orientation, last_size: f64) -> f64 {
+    let (size, max_size) = match orientation {
+        Orientation::Horizontal => (constraint.width(), constraint.max_width()),
+        Orientation::Vertical => (constraint.height(), constraint.max_height()),
+    };
+
+    if size > 0.0 {
+        size
+    } else if max_size > 0.0 && max_size < f64::MAX {
+        max_size
+    } else if last_size > 0.0 {
+        last_size
+    } else {
+        f64::MAX
+    }
+}
+
+/// Breaks the given (main, cross) sizes into lines that do not exceed the `limit` on the
+/// main axis. A line contains at least one item, also if the item is larger than the limit.
+pub(super) fn wrap_lines(sizes: &[(f64, f64)], limit: f64, item_spacing: f64) -> Vec<WrapLine> {
+    let mut lines = vec![];
+    let mut line = WrapLine::default();
+
+    for (index, (main, cross)) in sizes.iter().enumerate() {
+        if line.end > line.start && line.main + item_spacing + main > limit {
+            lines.push(line);
+            line = WrapLine {
+                start: index,
+                end: index,
+                ..WrapLine::default()
+            };
+        }
+
+        if line.end > line.start {
+            line.main += item_spacing;
+        }
+
+        line.main += main;
+        line.cross = line.cross.max(*cross);
+        line.end = index + 1;
+    }
+
+    if line.end > line.start {
+        lines.push(line);
+    }
+
+    lines
+}
+
+// Returns the size of all lines in (main, cross) order.
+pub(super) fn lines_size(lines: &[WrapLine], line_spacing: f64) -> (f64, f64) {
+    let main = lines
+        .iter()
+        .fold(0.0, |main: f64, line| main.max(line.main));
+    let cross = lines.iter().map(|line| line.cross).sum::<f64>()
+        + line_spacing * lines.len().saturating_sub(1) as f64;
+
+    (main, cross)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIZES: [(f64, f64); 5] = [
+        (20.0, 10.0),
+        (30.0, 20.0),
+        (40.0, 10.0),
+        (10.0, 30.0),
+        (60.0, 10.0),
+    ];
+
+    #[test]
+    fn wrap() {
+        let lines = wrap_lines(&SIZES, 100.0, 5.0);
+
+        assert_eq!(
+            lines,
+            vec![
+                WrapLine {
+                    start: 0,
+                    end: 3,
+                    main: 100.0,
+                    cross: 20.0
+                },
+                WrapLine {
+                    start: 3,
+                    end: 5,
+                    main: 75.0,
+                    cross: 30.0
+                },
+            ]
+        );
+        assert_eq!(lines_size(&lines, 4.0), (100.0, 54.0));
+    }
+
+    #[test]
+    fn no_wrap() {
+        let lines = wrap_lines(&SIZES, f64::MAX, 0.0);
+
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines_size(&lines, 4.0), (160.0, 30.0));
+    }
+
+    #[test]
+    fn oversized_item() {
+        let lines = wrap_lines(&SIZES, 25.0, 0.0);
+
+        assert_eq!(lines.len(), 5);
+        assert!(lines.iter().all(|line| line.end - line.start == 1));
+        assert_eq!(lines[4].main, 60.0);
+    }
+
+    #[test]
+    fn empty() {
+        let lines = wrap_lines(&[], 100.0, 5.0);
+
+        assert!(lines.is_empty());
+        assert_eq!(lines_size(&lines, 4.0), (0.0, 0.0));
+    }
+}
@@ -83,6 +83,7 @@ macro_rules! widget {
 	#[derive(Default, WidgetCtx)]
 	#[allow(dead_code)]
 	pub struct $widget {
+	    aspect_ratio: Option<f64>,
 	    attached_properties: HashMap<String, ComponentBox>,
 	    #[property(Rectangle)]
 	    bounds: Rectangle,
@@ -104,6 +105,8 @@ macro_rules! widget {
 	    min_height: Option<f64>,
 	    min_width: Option<f64>,
 	    name: Option<String>,
+	    #[property(bool)]
+	    focusable: bool,
 	    #[property(Filter)]
 	    on_changed_filter: Filter,
 	    #[property(f32)]
@@ -112,6 +115,8 @@ macro_rules! widget {
 	    position: Point,
 	    #[property(Selector)]
 	    selector: Selector,
+	    #[property(i32)]
+	    tab_index: i32,
 	    shared_attached_properties: HashMap<(String, String), SharedComponentBox>,
 	    style: Option<String>,
 	    id: Option<String>,
@@ -134,6 +139,15 @@ macro_rules! widget {
 	}
 
 	impl $widget {
+	    /// Inserts a new aspect ratio (width / height).
+	    pub fn aspect_ratio(mut self, aspect_ratio: impl Into<f64>) -> Self {
+		if !self.aspect_ratio.is_none() {
+		    return self;
+		}
+		self.aspect_ratio = Some(aspect_ratio.into());
+		self
+	    }
+
 	    /// Sets or shares the clip property.
 	    pub fn clip(self, clip: impl IntoPropertySource<bool>) -> Self {
 		self.set_property("clip", clip)
@@ -149,6 +163,18 @@ macro_rules! widget {
 		self.set_property("enabled", enabled)
 	    }
 
+	    /// Sets or shares the flow direction property. If it is not set, the flow direction is inherited from the parent.
+	    pub fn flow_direction(self, flow_direction: impl IntoPropertySource<FlowDirection>) -> Self {
+		self.set_property("flow_direction", flow_direction)
+	    }
+
+	    /// Sets or shares the focusable property. Only focusable widgets are reached by keyboard focus traversal.
+	    ///
+	    /// Could only be set once, so widget templates do not override the value set by the user.
+	    pub fn focusable(self, focusable: impl IntoPropertySource<bool>) -> Self {
+		self.set_property_once("focusable", focusable)
+	    }
+
 	    /// Inserts a new height.
 	    pub fn height(mut self, height: impl Into<f64>) -> Self {
 		if !self.height.is_none() {
@@ -249,6 +275,13 @@ macro_rules! widget {
 		self
 	    }
 
+	    /// Inserts a preview handler for events of type `E`. Preview handlers are called in the tunneling phase
+	    /// from the root to the target, before the regular handlers. If the handler returns `true` the event
+	    /// is marked as handled and no other handler is called, e.g. a container could intercept the input of its children.
+	    pub fn on_preview<E: Event, H: Fn(&mut StatesContext, &E) -> bool + 'static>(self, handler: H) -> Self {
+		self.insert_handler(PreviewEventHandler::<E>::new(handler))
+	    }
+
 	    /// Sets or shares the opacity property.
 	    pub fn opacity(self, opacity: impl IntoPropertySource<f32>) -> Self {
 		self.set_property("opacity", opacity)
@@ -285,6 +318,14 @@ macro_rules! widget {
 		self
 	    }
 
+	    // internal helper that does not override a property that is already set.
+	    fn set_property_once<P: Component + Debug>(self, key: &str, property: impl IntoPropertySource<P>) -> Self {
+		if self.attached_properties.contains_key(key) || self.shared_attached_properties.keys().any(|k| k.0 == key) {
+		    return self;
+		}
+		self.set_property(key, property)
+	    }
+
 	    /// Inserts a new size.
 	    pub fn size(mut self, width: impl Into<f64>, height: impl Into<f64>) -> Self {
 		if self.width.is_none() {
@@ -306,6 +347,13 @@ macro_rules! widget {
 		self
 	    }
 
+	    /// Sets or shares the tab index property. Focusable widgets with a positive tab index are
+	    /// reached first in ascending order, followed by the widgets with tab index `0` in tree order.
+	    /// Widgets with a negative tab index are skipped by keyboard focus traversal.
+	    pub fn tab_index(self, tab_index: impl IntoPropertySource<i32>) -> Self {
+		self.set_property_once("tab_index", tab_index)
+	    }
+
 	    /// Sets or shares the vertical alignment property.
 	    #[inline(always)]
 	    #[deprecated = "Use v_align instead"]
@@ -417,11 +465,13 @@ macro_rules! widget {
 		ctx.register_property("clip", entity, this.clip);
 		ctx.register_property("dirty", entity, false);
 		ctx.register_property("enabled", entity, this.enabled);
+		ctx.register_property("focusable", entity, this.focusable);
 		ctx.register_property("h_align", entity, this.h_align);
 		ctx.register_property("margin", entity, this.margin);
 		ctx.register_property("on_changed_filter", entity, this.on_changed_filter);
 		ctx.register_property("opacity", entity, this.opacity);
 		ctx.register_property("position", entity, this.position);
+		ctx.register_property("tab_index", entity, this.tab_index);
 		ctx.register_property("type_id", entity, TypeId::of::<$widget>());
 		ctx.register_property("type_name", entity, std::any::type_name::<$widget>().to_string());
 		ctx.register_property("visibility", entity, this.visibility);
@@ -447,6 +497,9 @@ macro_rules! widget {
 		if let Some(max_height) = this.max_height {
 		    constraint.set_max_height(max_height);
 		}
+		if let Some(aspect_ratio) = this.aspect_ratio {
+		    constraint.set_aspect_ratio(aspect_ratio);
+		}
 		ctx.register_property("constraint", entity, constraint);
 
 		// register attached properties
@@ -490,9 +543,11 @@ macro_rules! widget {
 		    this.selector
 		};
 
-		// initial set disabled
-		if ctx.get_widget(entity).has::<bool>("enabled") && !*ctx.get_widget(entity).get::<bool>("enabled") {
-		    selector.push_state("disabled");
+		// initial set of the interaction states e.g. disabled
+		for state in InteractionState::ALL.iter() {
+		    if state.is_set_by(&ctx.get_widget(entity)) {
+			selector.push_state(state.name());
+		    }
 		}
 
 		ctx.register_property("selector", entity, selector);
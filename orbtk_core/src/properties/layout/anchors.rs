@@ -0,0 +1,282 @@
+/// `Anchor` describes how an edge or the center of a child of a `RelativeLayout` is aligned to
+/// the panel or to a sibling, that is referenced by its `id`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Anchor {
+    /// The right edge of the child is aligned to the left edge of the sibling.
+    LeftOf(String),
+
+    /// The left edge of the child is aligned to the right edge of the sibling.
+    RightOf(String),
+
+    /// The bottom edge of the child is aligned to the top edge of the sibling.
+    Above(String),
+
+    /// The top edge of the child is aligned to the bottom edge of the sibling.
+    Below(String),
+
+    /// The left edge of the child is aligned to the left edge of the sibling.
+    AlignLeftWith(String),
+
+    /// The right edge of the child is aligned to the right edge of the sibling.
+    AlignRightWith(String),
+
+    /// The top edge of the child is aligned to the top edge of the sibling.
+    AlignTopWith(String),
+
+    /// The bottom edge of the child is aligned to the bottom edge of the sibling.
+    AlignBottomWith(String),
+
+    /// The horizontal center of the child is aligned to the horizontal center of the sibling.
+    AlignHorizontalCenterWith(String),
+
+    /// The vertical center of the child is aligned to the vertical center of the sibling.
+    AlignVerticalCenterWith(String),
+
+    /// The left edge of the child is aligned to the left edge of the panel.
+    AlignLeftWithPanel,
+
+    /// The right edge of the child is aligned to the right edge of the panel.
+    AlignRightWithPanel,
+
+    /// The top edge of the child is aligned to the top edge of the panel.
+    AlignTopWithPanel,
+
+    /// The bottom edge of the child is aligned to the bottom edge of the panel.
+    AlignBottomWithPanel,
+
+    /// The child is centered horizontally in the panel.
+    AlignHorizontalCenterWithPanel,
+
+    /// The child is centered vertically in the panel.
+    AlignVerticalCenterWithPanel,
+}
+
+impl Anchor {
+    /// Returns the id of the sibling the child is anchored to.
+    pub fn target(&self) -> Option<&str> {
+        match self {
+            Anchor::LeftOf(id)
+            | Anchor::RightOf(id)
+            | Anchor::Above(id)
+            | Anchor::Below(id)
+            | Anchor::AlignLeftWith(id)
+            | Anchor::AlignRightWith(id)
+            | Anchor::AlignTopWith(id)
+            | Anchor::AlignBottomWith(id)
+            | Anchor::AlignHorizontalCenterWith(id)
+            | Anchor::AlignVerticalCenterWith(id) => Some(id),
+            _ => None,
+        }
+    }
+
+    // Parses a single anchor like `right-of(name_label)` or `align-left-with-panel`.
+    fn parse(s: &str) -> Option<Anchor> {
+        let s = s.trim();
+
+        let (name, target) = match s.find('(') {
+            Some(start) if s.ends_with(')') => {
+                (s[..start].trim(), Some(s[start + 1..s.len() - 1].trim()))
+            }
+            _ => (s, None),
+        };
+
+        match (name, target) {
+            ("left-of", Some(id)) => Some(Anchor::LeftOf(id.to_string())),
+            ("right-of", Some(id)) => Some(Anchor::RightOf(id.to_string())),
+            ("above", Some(id)) => Some(Anchor::Above(id.to_string())),
+            ("below", Some(id)) => Some(Anchor::Below(id.to_string())),
+            ("align-left-with", Some(id)) => Some(Anchor::AlignLeftWith(id.to_string())),
+            ("align-right-with", Some(id)) => Some(Anchor::AlignRightWith(id.to_string())),
+            ("align-top-with", Some(id)) => Some(Anchor::AlignTopWith(id.to_string())),
+            ("align-bottom-with", Some(id)) => Some(Anchor::AlignBottomWith(id.to_string())),
+            ("align-horizontal-center-with", Some(id)) => {
+                Some(Anchor::AlignHorizontalCenterWith(id.to_string()))
+            }
+            ("align-vertical-center-with", Some(id)) => {
+                Some(Anchor::AlignVerticalCenterWith(id.to_string()))
+            }
+            ("align-left-with-panel", None) => Some(Anchor::AlignLeftWithPanel),
+            ("align-right-with-panel", None) => Some(Anchor::AlignRightWithPanel),
+            ("align-top-with-panel", None) => Some(Anchor::AlignTopWithPanel),
+            ("align-bottom-with-panel", None) => Some(Anchor::AlignBottomWithPanel),
+            ("align-horizontal-center-with-panel", None) => {
+                Some(Anchor::AlignHorizontalCenterWithPanel)
+            }
+            ("align-vertical-center-with-panel", None) => {
+                Some(Anchor::AlignVerticalCenterWithPanel)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// `Anchors` is the list of anchors of a child of a `RelativeLayout`. If an edge is anchored
+/// more than once, the last anchor wins. If both edges of an axis are anchored, the child is
+/// stretched between them.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // builder
+/// let anchors = Anchors::new().right_of("name_label").align_right_with_panel();
+///
+/// // from string, anchors are separated by `,`
+/// let anchors = Anchors::from("right-of(name_label), align-right-with-panel");
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Anchors(pub Vec<Anchor>);
+
+impl Anchors {
+    /// Creates an empty list of anchors.
+    pub fn new() -> Self {
+        Anchors::default()
+    }
+
+    /// Adds the given anchor.
+    pub fn anchor(mut self, anchor: Anchor) -> Self {
+        self.0.push(anchor);
+        self
+    }
+
+    /// Aligns the right edge to the left edge of the sibling with the given id.
+    pub fn left_of(self, id: impl Into<String>) -> Self {
+        self.anchor(Anchor::LeftOf(id.into()))
+    }
+
+    /// Aligns the left edge to the right edge of the sibling with the given id.
+    pub fn right_of(self, id: impl Into<String>) -> Self {
+        self.anchor(Anchor::RightOf(id.into()))
+    }
+
+    /// Aligns the bottom edge to the top edge of the sibling with the given id.
+    pub fn above(self, id: impl Into<String>) -> Self {
+        self.anchor(Anchor::Above(id.into()))
+    }
+
+    /// Aligns the top edge to the bottom edge of the sibling with the given id.
+    pub fn below(self, id: impl Into<String>) -> Self {
+        self.anchor(Anchor::Below(id.into()))
+    }
+
+    /// Aligns the left edge to the left edge of the sibling with the given id.
+    pub fn align_left_with(self, id: impl Into<String>) -> Self {
+        self.anchor(Anchor::AlignLeftWith(id.into()))
+    }
+
+    /// Aligns the right edge to the right edge of the sibling with the given id.
+    pub fn align_right_with(self, id: impl Into<String>) -> Self {
+        self.anchor(Anchor::AlignRightWith(id.into()))
+    }
+
+    /// Aligns the top edge to the top edge of the sibling with the given id.
+    pub fn align_top_with(self, id: impl Into<String>) -> Self {
+        self.anchor(Anchor::AlignTopWith(id.into()))
+    }
+
+    /// Aligns the bottom edge to the bottom edge of the sibling with the given id.
+    pub fn align_bottom_with(self, id: impl Into<String>) -> Self {
+        self.anchor(Anchor::AlignBottomWith(id.into()))
+    }
+
+    /// Aligns the horizontal center to the horizontal center of the sibling with the given id.
+    pub fn align_horizontal_center_with(self, id: impl Into<String>) -> Self {
+        self.anchor(Anchor::AlignHorizontalCenterWith(id.into()))
+    }
+
+    /// Aligns the vertical center to the vertical center of the sibling with the given id.
+    pub fn align_vertical_center_with(self, id: impl Into<String>) -> Self {
+        self.anchor(Anchor::AlignVerticalCenterWith(id.into()))
+    }
+
+    /// Aligns the left edge to the left edge of the panel.
+    pub fn align_left_with_panel(self) -> Self {
+        self.anchor(Anchor::AlignLeftWithPanel)
+    }
+
+    /// Aligns the right edge to the right edge of the panel.
+    pub fn align_right_with_panel(self) -> Self {
+        self.anchor(Anchor::AlignRightWithPanel)
+    }
+
+    /// Aligns the top edge to the top edge of the panel.
+    pub fn align_top_with_panel(self) -> Self {
+        self.anchor(Anchor::AlignTopWithPanel)
+    }
+
+    /// Aligns the bottom edge to the bottom edge of the panel.
+    pub fn align_bottom_with_panel(self) -> Self {
+        self.anchor(Anchor::AlignBottomWithPanel)
+    }
+
+    /// Centers horizontally in the panel.
+    pub fn align_horizontal_center_with_panel(self) -> Self {
+        self.anchor(Anchor::AlignHorizontalCenterWithPanel)
+    }
+
+    /// Centers vertically in the panel.
+    pub fn align_vertical_center_with_panel(self) -> Self {
+        self.anchor(Anchor::AlignVerticalCenterWithPanel)
+    }
+
+    /// Returns an iterator over the anchors.
+    pub fn iter(&self) -> std::slice::Iter<Anchor> {
+        self.0.iter()
+    }
+
+    /// Returns `true` if there are no anchors.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<Anchor> for Anchors {
+    fn from(anchor: Anchor) -> Self {
+        Anchors(vec![anchor])
+    }
+}
+
+impl From<Vec<Anchor>> for Anchors {
+    fn from(anchors: Vec<Anchor>) -> Self {
+        Anchors(anchors)
+    }
+}
+
+impl From<&str> for Anchors {
+    fn from(s: &str) -> Anchors {
+        Anchors(s.split(',').filter_map(Anchor::parse).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let anchors: Anchors = "right-of(name_label), align-right-with-panel".into();
+        assert_eq!(
+            anchors,
+            Anchors(vec![
+                Anchor::RightOf("name_label".to_string()),
+                Anchor::AlignRightWithPanel
+            ])
+        );
+
+        let anchors: Anchors = " below ( title ) ,align-vertical-center-with(icon)".into();
+        assert_eq!(
+            anchors,
+            Anchors::new()
+                .below("title")
+                .align_vertical_center_with("icon")
+        );
+
+        let anchors: Anchors = "left-of, other(x), align-top-with-panel(x)".into();
+        assert!(anchors.is_empty());
+    }
+
+    #[test]
+    fn test_target() {
+        assert_eq!(Anchor::Above("title".to_string()).target(), Some("title"));
+        assert_eq!(Anchor::AlignTopWithPanel.target(), None);
+    }
+}
@@ -1,5 +1,7 @@
 use std::slice::{Iter, IterMut};
 
+use serde_derive::{Deserialize, Serialize};
+
 static BLOCK_SEPARATOR: &str = ",";
 
 /// Used to build a block (column | row), specifying additional details.
@@ -46,7 +48,7 @@ impl BlockBuilder {
 }
 
 /// Used to define a block inside a `Grid`.
-#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Block {
     pub size: BlockSize,
     pub min_size: f64,
@@ -87,13 +89,7 @@ impl Block {
 
 impl From<&str> for Block {
     fn from(t: &str) -> Self {
-        if let Ok(size) = t.parse::<f64>() {
-            return Block::create().size(BlockSize::Size(size)).build();
-        }
-        match t {
-            "Auto" | "auto" => Block::create().size(BlockSize::Auto).build(),
-            _ => Block::create().size(BlockSize::Stretch).build(),
-        }
+        Block::create().size(BlockSize::from(t)).build()
     }
 }
 
@@ -110,24 +106,78 @@ impl From<i32> for Block {
 }
 
 /// Used to define the size of a grid block.
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum BlockSize {
     /// Block is measured by the largest child.
     Auto,
 
-    /// Block expands to the rest available size.
+    /// Block expands to the rest available size. Equal to `Star(1.0)`.
     Stretch,
 
+    /// Block gets a share of the rest available size that is weighted by the given factor,
+    /// e.g. a `Star(2.0)` block gets twice the size of a `Star(1.0)` block.
+    Star(f64),
+
+    /// Block gets the given percentage of the available size.
+    Percent(f64),
+
     /// Defines a fixed size for the block.
     Size(f64),
 }
 
+impl BlockSize {
+    /// Returns the weight of a `Stretch` or `Star` block, otherwise `None`.
+    pub fn weight(&self) -> Option<f64> {
+        match self {
+            BlockSize::Stretch => Some(1.0),
+            BlockSize::Star(weight) => Some(weight.max(0.0)),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the block shares the rest available size.
+    pub fn is_star(&self) -> bool {
+        self.weight().is_some()
+    }
+}
+
 impl Default for BlockSize {
     fn default() -> Self {
         BlockSize::Stretch
     }
 }
 
+impl From<&str> for BlockSize {
+    fn from(t: &str) -> Self {
+        let t = t.trim();
+
+        if let Ok(size) = t.parse::<f64>() {
+            return BlockSize::Size(size);
+        }
+
+        if let Some(weight) = t.strip_suffix('*') {
+            if weight.is_empty() {
+                return BlockSize::Stretch;
+            }
+
+            if let Ok(weight) = weight.parse::<f64>() {
+                return BlockSize::Star(weight);
+            }
+        }
+
+        if let Some(percent) = t.strip_suffix('%') {
+            if let Ok(percent) = percent.parse::<f64>() {
+                return BlockSize::Percent(percent);
+            }
+        }
+
+        match t {
+            "Auto" | "auto" => BlockSize::Auto,
+            _ => BlockSize::Stretch,
+        }
+    }
+}
+
 /// Used to build `blocks`, that will group `block` structs.
 #[derive(Default)]
 pub struct BlocksBuilder {
@@ -183,7 +233,7 @@ impl From<BlocksBuilder> for Blocks {
 }
 
 /// Helper struct used to group block properties.
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct Blocks(pub Vec<Block>);
 
 /// Alias type for `Blocks`. Provided to keep old api calls working.
@@ -230,6 +280,66 @@ impl Blocks {
     pub fn len(&self) -> usize {
         self.0.len()
     }
+
+    /// Moves the border between the blocks `before` and `after` by `delta`, based on their
+    /// current sizes. The delta is limited by the `min_size` and `max_size` of both blocks. A
+    /// block that shares the rest available size keeps to do so (its weight is adjusted if both
+    /// blocks share it), any other block gets a fixed size. Returns the applied delta.
+    pub fn resize(&mut self, before: usize, after: usize, delta: f64) -> f64 {
+        let (first, second) = match (self.get(before), self.get(after)) {
+            (Some(first), Some(second)) if before != after => (*first, *second),
+            _ => return 0.0,
+        };
+
+        let mut min_delta = (first.min_size - first.current_size).max(-first.current_size);
+        let mut max_delta = (second.current_size - second.min_size).min(second.current_size);
+
+        if first.max_size > 0.0 {
+            max_delta = max_delta.min(first.max_size - first.current_size);
+        }
+
+        if second.max_size > 0.0 {
+            min_delta = min_delta.max(second.current_size - second.max_size);
+        }
+
+        if min_delta > max_delta {
+            return 0.0;
+        }
+
+        let delta = delta.max(min_delta).min(max_delta);
+
+        if delta == 0.0 {
+            return 0.0;
+        }
+
+        let first_size = first.current_size + delta;
+        let second_size = second.current_size - delta;
+
+        match (first.size.weight(), second.size.weight()) {
+            (Some(first_weight), Some(second_weight))
+                if first.current_size > 0.0 && second.current_size > 0.0 =>
+            {
+                self.0[before].size =
+                    BlockSize::Star(first_weight * first_size / first.current_size);
+                self.0[after].size =
+                    BlockSize::Star(second_weight * second_size / second.current_size);
+            }
+            (first_weight, second_weight) => {
+                if first_weight.is_none() {
+                    self.0[before].size = BlockSize::Size(first_size);
+                }
+
+                if second_weight.is_none() {
+                    self.0[after].size = BlockSize::Size(second_size);
+                }
+            }
+        }
+
+        self.0[before].current_size = first_size;
+        self.0[after].current_size = second_size;
+
+        delta
+    }
 }
 
 impl From<&str> for Blocks {
@@ -341,5 +451,112 @@ mod tests {
 
         let block: Block = 64.0.into();
         assert_eq!(block.size(), BlockSize::Size(64.0));
+
+        let block: Block = "2*".into();
+        assert_eq!(block.size(), BlockSize::Star(2.0));
+
+        let block: Block = "0.5*".into();
+        assert_eq!(block.size(), BlockSize::Star(0.5));
+
+        let block: Block = "25%".into();
+        assert_eq!(block.size(), BlockSize::Percent(25.0));
+
+        let block: Block = "x*".into();
+        assert_eq!(block.size(), BlockSize::Stretch);
+    }
+
+    #[test]
+    fn test_blocks_from_str() {
+        let blocks = Blocks::from("2*, auto, 120, 1*, 10%, *");
+        let sizes: Vec<BlockSize> = blocks.iter().map(|block| block.size()).collect();
+
+        assert_eq!(
+            sizes,
+            vec![
+                BlockSize::Star(2.0),
+                BlockSize::Auto,
+                BlockSize::Size(120.0),
+                BlockSize::Star(1.0),
+                BlockSize::Percent(10.0),
+                BlockSize::Stretch
+            ]
+        );
+    }
+
+    #[test]
+    fn test_weight() {
+        assert_eq!(BlockSize::Stretch.weight(), Some(1.0));
+        assert_eq!(BlockSize::Star(3.0).weight(), Some(3.0));
+        assert_eq!(BlockSize::Auto.weight(), None);
+        assert!(!BlockSize::Percent(50.0).is_star());
+    }
+
+    fn blocks(sizes: &[(&str, f64)]) -> Blocks {
+        let mut blocks = Blocks::from(
+            sizes
+                .iter()
+                .map(|(size, _)| *size)
+                .collect::<Vec<&str>>()
+                .join(","),
+        );
+
+        for (block, (_, current_size)) in blocks.iter_mut().zip(sizes) {
+            block.set_current_size(*current_size);
+        }
+
+        blocks
+    }
+
+    #[test]
+    fn test_resize() {
+        // a fixed block gets the new size, a stretched block keeps to stretch
+        let mut columns = blocks(&[("200", 200.0), ("4", 4.0), ("*", 396.0)]);
+
+        assert_eq!(columns.resize(0, 2, 50.0), 50.0);
+        assert_eq!(columns.get(0).unwrap().size(), BlockSize::Size(250.0));
+        assert_eq!(columns.get(2).unwrap().size(), BlockSize::Stretch);
+        assert_eq!(columns.get(2).unwrap().current_size(), 346.0);
+
+        // two star blocks keep their sum of weights
+        let mut columns = blocks(&[("*", 300.0), ("4", 4.0), ("*", 300.0)]);
+
+        assert_eq!(columns.resize(0, 2, -150.0), -150.0);
+        assert_eq!(columns.get(0).unwrap().size(), BlockSize::Star(0.5));
+        assert_eq!(columns.get(2).unwrap().size(), BlockSize::Star(1.5));
+
+        // auto blocks become fixed
+        let mut rows = blocks(&[("auto", 40.0), ("auto", 60.0)]);
+
+        assert_eq!(rows.resize(0, 1, 10.0), 10.0);
+        assert_eq!(rows.get(0).unwrap().size(), BlockSize::Size(50.0));
+        assert_eq!(rows.get(1).unwrap().size(), BlockSize::Size(50.0));
+
+        // invalid indices
+        assert_eq!(rows.resize(0, 0, 10.0), 0.0);
+        assert_eq!(rows.resize(0, 5, 10.0), 0.0);
+    }
+
+    #[test]
+    fn test_resize_min_max() {
+        let mut columns = blocks(&[("100", 100.0), ("*", 300.0)]);
+        columns.get_mut(0).unwrap().min_size = 50.0;
+        columns.get_mut(0).unwrap().max_size = 150.0;
+        columns.get_mut(1).unwrap().min_size = 280.0;
+
+        assert_eq!(columns.resize(0, 1, 100.0), 20.0);
+        assert_eq!(columns.get(0).unwrap().size(), BlockSize::Size(120.0));
+
+        assert_eq!(columns.resize(0, 1, -100.0), -70.0);
+        assert_eq!(columns.get(0).unwrap().size(), BlockSize::Size(50.0));
+
+        // a block without limits could not get a negative size
+        let mut columns = blocks(&[("100", 100.0), ("100", 100.0)]);
+        assert_eq!(columns.resize(0, 1, 150.0), 100.0);
+        assert_eq!(columns.get(1).unwrap().current_size(), 0.0);
+
+        // the max size of the second block limits the shrinking of the first one
+        let mut columns = blocks(&[("100", 100.0), ("100", 100.0)]);
+        columns.get_mut(1).unwrap().max_size = 120.0;
+        assert_eq!(columns.resize(0, 1, -50.0), -20.0);
     }
 }
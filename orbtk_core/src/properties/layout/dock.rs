@@ -0,0 +1,59 @@
+/// `Dock` describes the edge of a `DockLayout` a child is docked to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Dock {
+    /// The child is docked to the left edge of the remaining space.
+    Left,
+
+    /// The child is docked to the top edge of the remaining space.
+    Top,
+
+    /// The child is docked to the right edge of the remaining space.
+    Right,
+
+    /// The child is docked to the bottom edge of the remaining space.
+    Bottom,
+
+    /// The child fills the remaining space.
+    Fill,
+}
+
+impl Default for Dock {
+    fn default() -> Self {
+        Dock::Left
+    }
+}
+
+impl From<&str> for Dock {
+    fn from(s: &str) -> Dock {
+        match s {
+            "Top" | "top" => Dock::Top,
+            "Right" | "right" => Dock::Right,
+            "Bottom" | "bottom" => Dock::Bottom,
+            "Fill" | "fill" => Dock::Fill,
+            _ => Dock::Left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let dock: Dock = "top".into();
+        assert_eq!(dock, Dock::Top);
+
+        let dock: Dock = "Right".into();
+        assert_eq!(dock, Dock::Right);
+
+        let dock: Dock = "bottom".into();
+        assert_eq!(dock, Dock::Bottom);
+
+        let dock: Dock = "fill".into();
+        assert_eq!(dock, Dock::Fill);
+
+        let dock: Dock = "other".into();
+        assert_eq!(dock, Dock::Left);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::utils::Orientation;
+
+/// `FlexDirection` describes the main axis of a flex layout and the direction the children
+/// are placed on it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FlexDirection {
+    /// Children are placed from left to right.
+    Row,
+
+    /// Children are placed from right to left.
+    RowReverse,
+
+    /// Children are placed from top to bottom.
+    Column,
+
+    /// Children are placed from bottom to top.
+    ColumnReverse,
+}
+
+impl FlexDirection {
+    /// Returns the orientation of the main axis.
+    pub fn orientation(self) -> Orientation {
+        match self {
+            FlexDirection::Row | FlexDirection::RowReverse => Orientation::Horizontal,
+            _ => Orientation::Vertical,
+        }
+    }
+
+    /// Returns `true` if the children are placed from the end of the main axis.
+    pub fn is_reverse(self) -> bool {
+        self == FlexDirection::RowReverse || self == FlexDirection::ColumnReverse
+    }
+}
+
+impl Default for FlexDirection {
+    fn default() -> Self {
+        FlexDirection::Row
+    }
+}
+
+impl From<&str> for FlexDirection {
+    fn from(s: &str) -> FlexDirection {
+        match s {
+            "RowReverse" | "row-reverse" => FlexDirection::RowReverse,
+            "Column" | "column" => FlexDirection::Column,
+            "ColumnReverse" | "column-reverse" => FlexDirection::ColumnReverse,
+            _ => FlexDirection::Row,
+        }
+    }
+}
+
+/// `FlexWrap` describes if the children of a flex layout are forced onto one line or can wrap
+/// onto multiple lines.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FlexWrap {
+    /// All children are placed on one line and shrink if there is not enough space.
+    NoWrap,
+
+    /// Children wrap onto new lines that are placed from the start of the cross axis.
+    Wrap,
+
+    /// Children wrap onto new lines that are placed from the end of the cross axis.
+    WrapReverse,
+}
+
+impl Default for FlexWrap {
+    fn default() -> Self {
+        FlexWrap::NoWrap
+    }
+}
+
+impl From<&str> for FlexWrap {
+    fn from(s: &str) -> FlexWrap {
+        match s {
+            "Wrap" | "wrap" => FlexWrap::Wrap,
+            "WrapReverse" | "wrap-reverse" => FlexWrap::WrapReverse,
+            _ => FlexWrap::NoWrap,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction() {
+        let direction: FlexDirection = "column-reverse".into();
+        assert_eq!(direction, FlexDirection::ColumnReverse);
+        assert_eq!(direction.orientation(), Orientation::Vertical);
+        assert!(direction.is_reverse());
+
+        let direction: FlexDirection = "other".into();
+        assert_eq!(direction, FlexDirection::Row);
+        assert_eq!(direction.orientation(), Orientation::Horizontal);
+        assert!(!direction.is_reverse());
+    }
+
+    #[test]
+    fn test_wrap() {
+        let wrap: FlexWrap = "wrap".into();
+        assert_eq!(wrap, FlexWrap::Wrap);
+
+        let wrap: FlexWrap = "WrapReverse".into();
+        assert_eq!(wrap, FlexWrap::WrapReverse);
+
+        let wrap: FlexWrap = "nowrap".into();
+        assert_eq!(wrap, FlexWrap::NoWrap);
+    }
+}
@@ -0,0 +1,69 @@
+/// `FlowDirection` describes the direction in which the content of a widget flows. The flow
+/// direction is inherited by all children that don't set their own.
+///
+/// Right-to-left layouts are mirrored: `Alignment::Start` and the left side of a `Thickness`
+/// refer to the right edge, the first column of a grid and the first child of a horizontal
+/// stack are placed on the right.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FlowDirection {
+    /// The content flows from left to right.
+    LeftToRight,
+
+    /// The content flows from right to left, e.g. for Arabic and Hebrew.
+    RightToLeft,
+}
+
+impl FlowDirection {
+    /// Returns `true` if the content flows from right to left.
+    pub fn is_right_to_left(self) -> bool {
+        self == FlowDirection::RightToLeft
+    }
+
+    /// Mirrors the given x position of an element with the given width inside of the given
+    /// parent width, if the content flows from right to left.
+    pub fn mirror(self, x: f64, width: f64, parent_width: f64) -> f64 {
+        match self {
+            FlowDirection::LeftToRight => x,
+            FlowDirection::RightToLeft => parent_width - x - width,
+        }
+    }
+}
+
+impl Default for FlowDirection {
+    fn default() -> Self {
+        FlowDirection::LeftToRight
+    }
+}
+
+impl From<&str> for FlowDirection {
+    fn from(s: &str) -> FlowDirection {
+        match s {
+            "RightToLeft" | "right-to-left" | "rtl" => FlowDirection::RightToLeft,
+            _ => FlowDirection::LeftToRight,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let flow_direction: FlowDirection = "rtl".into();
+        assert_eq!(flow_direction, FlowDirection::RightToLeft);
+
+        let flow_direction: FlowDirection = "RightToLeft".into();
+        assert_eq!(flow_direction, FlowDirection::RightToLeft);
+
+        let flow_direction: FlowDirection = "ltr".into();
+        assert_eq!(flow_direction, FlowDirection::LeftToRight);
+    }
+
+    #[test]
+    fn test_mirror() {
+        assert_eq!(FlowDirection::LeftToRight.mirror(10.0, 20.0, 100.0), 10.0);
+        assert_eq!(FlowDirection::RightToLeft.mirror(10.0, 20.0, 100.0), 70.0);
+        assert_eq!(FlowDirection::RightToLeft.mirror(0.0, 100.0, 100.0), 0.0);
+    }
+}
@@ -0,0 +1,115 @@
+/// `Justification` describes how the free space on the main axis of a line is distributed
+/// between its children.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Justification {
+    /// Children are packed to the start of the line.
+    Start,
+
+    /// Children are packed to the center of the line.
+    Center,
+
+    /// Children are packed to the end of the line.
+    End,
+
+    /// The first child is placed at the start, the last child at the end of the line and the
+    /// free space is distributed evenly between the children.
+    SpaceBetween,
+
+    /// The free space is distributed evenly around each child, the space at the edges of the
+    /// line is half the space between two children.
+    SpaceAround,
+
+    /// The free space is distributed evenly between the children and the edges of the line.
+    SpaceEvenly,
+}
+
+impl Justification {
+    /// Distributes the `free` space of a line with `count` children. Returns the offset of the
+    /// first child and the additional space between two children.
+    pub fn distribute(self, free: f64, count: usize) -> (f64, f64) {
+        let free = free.max(0.0);
+
+        if count == 0 {
+            return (0.0, 0.0);
+        }
+
+        match self {
+            Justification::Start => (0.0, 0.0),
+            Justification::Center => (free / 2.0, 0.0),
+            Justification::End => (free, 0.0),
+            Justification::SpaceBetween => {
+                if count > 1 {
+                    (0.0, free / (count - 1) as f64)
+                } else {
+                    (0.0, 0.0)
+                }
+            }
+            Justification::SpaceAround => {
+                let space = free / count as f64;
+                (space / 2.0, space)
+            }
+            Justification::SpaceEvenly => {
+                let space = free / (count + 1) as f64;
+                (space, space)
+            }
+        }
+    }
+}
+
+impl Default for Justification {
+    fn default() -> Self {
+        Justification::Start
+    }
+}
+
+impl From<&str> for Justification {
+    fn from(s: &str) -> Justification {
+        match s {
+            "Center" | "center" => Justification::Center,
+            "End" | "end" => Justification::End,
+            "SpaceBetween" | "space-between" => Justification::SpaceBetween,
+            "SpaceAround" | "space-around" => Justification::SpaceAround,
+            "SpaceEvenly" | "space-evenly" => Justification::SpaceEvenly,
+            _ => Justification::Start,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let justification: Justification = "center".into();
+        assert_eq!(justification, Justification::Center);
+
+        let justification: Justification = "End".into();
+        assert_eq!(justification, Justification::End);
+
+        let justification: Justification = "space-between".into();
+        assert_eq!(justification, Justification::SpaceBetween);
+
+        let justification: Justification = "SpaceAround".into();
+        assert_eq!(justification, Justification::SpaceAround);
+
+        let justification: Justification = "space-evenly".into();
+        assert_eq!(justification, Justification::SpaceEvenly);
+
+        let justification: Justification = "other".into();
+        assert_eq!(justification, Justification::Start);
+    }
+
+    #[test]
+    fn test_distribute() {
+        assert_eq!(Justification::Start.distribute(60.0, 3), (0.0, 0.0));
+        assert_eq!(Justification::Center.distribute(60.0, 3), (30.0, 0.0));
+        assert_eq!(Justification::End.distribute(60.0, 3), (60.0, 0.0));
+        assert_eq!(Justification::SpaceBetween.distribute(60.0, 3), (0.0, 30.0));
+        assert_eq!(Justification::SpaceBetween.distribute(60.0, 1), (0.0, 0.0));
+        assert_eq!(Justification::SpaceAround.distribute(60.0, 3), (10.0, 20.0));
+        assert_eq!(Justification::SpaceEvenly.distribute(60.0, 3), (15.0, 15.0));
+        assert_eq!(Justification::End.distribute(-10.0, 3), (0.0, 0.0));
+        assert_eq!(Justification::Center.distribute(60.0, 0), (0.0, 0.0));
+    }
+}
@@ -1,7 +1,19 @@
 // Layout specific properties.
 
+pub use self::anchors::*;
 pub use self::block::*;
+pub use self::dock::*;
+pub use self::flex::*;
+pub use self::flow_direction::*;
+pub use self::justification::*;
 pub use self::scroll_viewer_mode::*;
+pub use self::stretch::*;
 
+mod anchors;
 mod block;
+mod dock;
+mod flex;
+mod flow_direction;
+mod justification;
 mod scroll_viewer_mode;
+mod stretch;
@@ -0,0 +1,92 @@
+/// `Stretch` describes how the content of a `Viewbox` is scaled to fill the available space.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Stretch {
+    /// The content keeps its original size.
+    None,
+
+    /// The content is scaled to fill the available space, the aspect ratio is not preserved.
+    Fill,
+
+    /// The content is scaled to fit into the available space while preserving its aspect
+    /// ratio.
+    Uniform,
+
+    /// The content is scaled to fill the available space while preserving its aspect ratio.
+    /// Parts of the content that overflow the available space are clipped.
+    UniformToFill,
+}
+
+impl Stretch {
+    /// Calculates the horizontal and vertical scale factors of the content with the given
+    /// size inside of the available size.
+    pub fn scale(self, content_size: (f64, f64), available_size: (f64, f64)) -> (f64, f64) {
+        if content_size.0 <= 0.0 || content_size.1 <= 0.0 {
+            return (1.0, 1.0);
+        }
+
+        let scale_x = available_size.0 / content_size.0;
+        let scale_y = available_size.1 / content_size.1;
+
+        match self {
+            Stretch::None => (1.0, 1.0),
+            Stretch::Fill => (scale_x, scale_y),
+            Stretch::Uniform => {
+                let scale = scale_x.min(scale_y);
+                (scale, scale)
+            }
+            Stretch::UniformToFill => {
+                let scale = scale_x.max(scale_y);
+                (scale, scale)
+            }
+        }
+    }
+}
+
+impl Default for Stretch {
+    fn default() -> Self {
+        Stretch::Uniform
+    }
+}
+
+impl From<&str> for Stretch {
+    fn from(s: &str) -> Stretch {
+        match s {
+            "None" | "none" => Stretch::None,
+            "Fill" | "fill" => Stretch::Fill,
+            "UniformToFill" | "uniform-to-fill" => Stretch::UniformToFill,
+            _ => Stretch::Uniform,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into() {
+        let stretch: Stretch = "none".into();
+        assert_eq!(stretch, Stretch::None);
+
+        let stretch: Stretch = "Fill".into();
+        assert_eq!(stretch, Stretch::Fill);
+
+        let stretch: Stretch = "uniform-to-fill".into();
+        assert_eq!(stretch, Stretch::UniformToFill);
+
+        let stretch: Stretch = "other".into();
+        assert_eq!(stretch, Stretch::Uniform);
+    }
+
+    #[test]
+    fn test_scale() {
+        let content = (100.0, 50.0);
+        let available = (200.0, 200.0);
+
+        assert_eq!(Stretch::None.scale(content, available), (1.0, 1.0));
+        assert_eq!(Stretch::Fill.scale(content, available), (2.0, 4.0));
+        assert_eq!(Stretch::Uniform.scale(content, available), (2.0, 2.0));
+        assert_eq!(Stretch::UniformToFill.scale(content, available), (4.0, 4.0));
+        assert_eq!(Stretch::Uniform.scale((0.0, 50.0), available), (1.0, 1.0));
+    }
+}
@@ -119,11 +119,19 @@ into_property_source!(theming::Theme);
 into_property_source!(render::Image: &str, String, (u32, u32, Vec<u32>));
 
 // Implementation of custom property types
+into_property_source!(Anchors: Anchor, Vec<Anchor>, &str);
+into_property_source!(crate::layout::AnchorErrors);
 into_property_source!(Blocks: BlocksBuilder, &str, String);
 into_property_source!(DefaultRenderPipeline);
+into_property_source!(Dock: &str);
+into_property_source!(FlexDirection: &str);
+into_property_source!(FlexWrap: &str);
+into_property_source!(FlowDirection: &str);
 into_property_source!(FocusState);
+into_property_source!(Justification: &str);
 into_property_source!(KeyboardState);
 into_property_source!(ScrollViewerMode: (&str, &str));
 into_property_source!(SelectedEntities: HashSet<Entity>);
 into_property_source!(SelectedIndices: HashSet<usize>);
+into_property_source!(Stretch: &str);
 into_property_source!(TextSelection: (usize, usize));
@@ -1,6 +1,39 @@
-use crate::{theming::Selector, widget_base::Context};
+use crate::{
+    properties::{AttachedProperty, IntoPropertySource, TextSelection},
+    theming::Selector,
+    tree::Tree,
+    utils::Visibility,
+    widget_base::{Context, InteractionState},
+};
 
-use dces::prelude::Entity;
+use dces::prelude::{Entity, EntityComponentManager};
+
+/// Provides attached properties to control the keyboard focus traversal.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Stack::new()
+///     .attach(Focus::group(true))
+///     .child(RadioButton::new().build(ctx))
+///     .child(RadioButton::new().build(ctx))
+///     .build(ctx)
+/// ```
+pub struct Focus;
+
+impl Focus {
+    /// Marks the widget as focus scope. If a focus scope is visible, keyboard focus traversal
+    /// is trapped inside of it. Used by popups and dialogs.
+    pub fn scope(scope: impl IntoPropertySource<bool>) -> AttachedProperty<bool> {
+        AttachedProperty::new("focus_scope", scope)
+    }
+
+    /// Marks the widget as focus group. Inside of a focus group the focus could be moved between
+    /// the focusable children with the arrow keys.
+    pub fn group(group: impl IntoPropertySource<bool>) -> AttachedProperty<bool> {
+        AttachedProperty::new("focus_group", group)
+    }
+}
 
 /// Contains the state information of the current focused element.
 ///
@@ -24,7 +57,9 @@ impl FocusState {
         if let Some(old_focused_element) = self.focused_entity {
             let mut old_focused_element = ctx.get_widget(old_focused_element);
 
-            old_focused_element.set("focused", false);
+            old_focused_element.set_interaction_state(InteractionState::Focused, false);
+
+            // removes also the derived states of the widget e.g. `not_empty_focused`
             old_focused_element
                 .get_mut::<Selector>("selector")
                 .remove_all_similar_states("focused");
@@ -34,13 +69,8 @@ impl FocusState {
         self.focused_entity = Some(entity);
 
         if ctx.get_widget(entity).has::<bool>("focused") {
-            let mut focused_element = ctx.get_widget(entity);
-
-            focused_element.set("focused", true);
-            focused_element
-                .get_mut::<Selector>("selector")
-                .push_state("focused");
-            focused_element.update(false);
+            ctx.get_widget(entity)
+                .set_interaction_state(InteractionState::Focused, true);
         }
     }
 
@@ -54,7 +84,9 @@ impl FocusState {
                 return;
             }
             let mut old_focused_element = ctx.get_widget(old_focused_element);
-            old_focused_element.set("focused", false);
+            old_focused_element.set_interaction_state(InteractionState::Focused, false);
+
+            // removes also the derived states of the widget e.g. `not_empty_focused`
             old_focused_element
                 .get_mut::<Selector>("selector")
                 .remove_all_similar_states("focused");
@@ -73,4 +105,236 @@ impl FocusState {
     pub fn focused_entity(&self) -> &Option<Entity> {
         &self.focused_entity
     }
+
+    /// Moves the focus to the next (or previous if `reverse` is `true`) focusable widget inside of
+    /// the active focus scope. Returns `true` if the focus was moved.
+    pub fn focus_next(&mut self, reverse: bool, ctx: &mut Context) -> bool {
+        let root = ctx.entity_of_window();
+        let scope = active_scope(root, ctx.ecm).unwrap_or(root);
+
+        let mut candidates = vec![];
+        collect_focusable(scope, ctx.ecm, &mut candidates);
+
+        self.move_focus(&candidates, reverse, ctx)
+    }
+
+    /// Moves the focus to the next (or previous if `reverse` is `true`) focusable widget inside of
+    /// the focus group of the focused widget. Returns `true` if the focus was moved.
+    ///
+    /// Widgets with a text selection handle the arrow keys by themselves, in that case the focus is not moved.
+    pub fn focus_in_group(&mut self, reverse: bool, ctx: &mut Context) -> bool {
+        let focused = match self.focused_entity {
+            Some(focused) => focused,
+            None => return false,
+        };
+
+        if ctx.get_widget(focused).has::<TextSelection>("selection") {
+            return false;
+        }
+
+        let mut current = focused;
+        let group = loop {
+            match ctx.ecm.entity_store().parent.get(&current).and_then(|p| *p) {
+                Some(parent) => {
+                    if is_set("focus_group", parent, ctx.ecm) {
+                        break parent;
+                    }
+                    current = parent;
+                }
+                None => return false,
+            }
+        };
+
+        let mut candidates = vec![];
+        collect_focusable(group, ctx.ecm, &mut candidates);
+
+        self.move_focus(&candidates, reverse, ctx)
+    }
+
+    fn move_focus(
+        &mut self,
+        candidates: &[(Entity, i32)],
+        reverse: bool,
+        ctx: &mut Context,
+    ) -> bool {
+        if let Some(next) = next_focus(candidates, self.focused_entity, reverse) {
+            if self.focused_entity != Some(next) {
+                self.request_focus(next, ctx);
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+// Returns `true` if the bool property with the given key is set to `true`.
+fn is_set(key: &str, entity: Entity, ecm: &EntityComponentManager<Tree>) -> bool {
+    ecm.component_store()
+        .get::<bool>(key, entity)
+        .map_or(false, |value| *value)
+}
+
+// Returns `true` if the widget and its children could be reached by keyboard focus traversal.
+fn is_reachable(entity: Entity, ecm: &EntityComponentManager<Tree>) -> bool {
+    let visible = ecm
+        .component_store()
+        .get::<Visibility>("visibility", entity)
+        .map_or(true, |visibility| *visibility == Visibility::Visible);
+
+    visible
+        && ecm
+            .component_store()
+            .get::<bool>("enabled", entity)
+            .map_or(true, |enabled| *enabled)
+}
+
+// Returns the last visible focus scope in tree order.
+fn active_scope(entity: Entity, ecm: &mut EntityComponentManager<Tree>) -> Option<Entity> {
+    if !is_reachable(entity, ecm) {
+        return None;
+    }
+
+    let mut scope = if is_set("focus_scope", entity, ecm) {
+        Some(entity)
+    } else {
+        None
+    };
+
+    let children = ecm.entity_store().children[&entity].clone();
+
+    for child in children {
+        if let Some(child_scope) = active_scope(child, ecm) {
+            scope = Some(child_scope);
+        }
+    }
+
+    scope
+}
+
+// Collects all reachable focusable widgets with their tab index in tree order.
+fn collect_focusable(
+    entity: Entity,
+    ecm: &mut EntityComponentManager<Tree>,
+    candidates: &mut Vec<(Entity, i32)>,
+) {
+    if !is_reachable(entity, ecm) {
+        return;
+    }
+
+    if is_set("focusable", entity, ecm) {
+        let tab_index = ecm
+            .component_store()
+            .get::<i32>("tab_index", entity)
+            .map_or(0, |tab_index| *tab_index);
+        candidates.push((entity, tab_index));
+    }
+
+    let children = ecm.entity_store().children[&entity].clone();
+
+    for child in children {
+        collect_focusable(child, ecm, candidates);
+    }
+}
+
+/// Calculates the next entity that should be focused from the given candidates in tree order.
+///
+/// Candidates with a positive tab index come first in ascending order, followed by the candidates
+/// with tab index `0` in tree order. Candidates with a negative tab index are skipped. If `current`
+/// is not part of the candidates, the first (or last if `reverse` is `true`) candidate is returned.
+pub fn next_focus(
+    candidates: &[(Entity, i32)],
+    current: Option<Entity>,
+    reverse: bool,
+) -> Option<Entity> {
+    let mut order: Vec<&(Entity, i32)> = candidates
+        .iter()
+        .filter(|(_, tab_index)| *tab_index >= 0)
+        .collect();
+
+    // stable sort keeps the tree order of equal tab indices
+    order.sort_by_key(|(_, tab_index)| {
+        if *tab_index > 0 {
+            (0, *tab_index)
+        } else {
+            (1, 0)
+        }
+    });
+
+    if order.is_empty() {
+        return None;
+    }
+
+    let len = order.len();
+    let next = match current.and_then(|current| order.iter().position(|(e, _)| *e == current)) {
+        Some(index) if reverse => (index + len - 1) % len,
+        Some(index) => (index + 1) % len,
+        None if reverse => len - 1,
+        None => 0,
+    };
+
+    Some(order[next].0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates() -> Vec<(Entity, i32)> {
+        vec![
+            (Entity(1), 0),
+            (Entity(2), 2),
+            (Entity(3), -1),
+            (Entity(4), 0),
+            (Entity(5), 1),
+        ]
+    }
+
+    #[test]
+    fn test_next_focus() {
+        let candidates = candidates();
+
+        assert_eq!(next_focus(&candidates, None, false), Some(Entity(5)));
+        assert_eq!(
+            next_focus(&candidates, Some(Entity(5)), false),
+            Some(Entity(2))
+        );
+        assert_eq!(
+            next_focus(&candidates, Some(Entity(2)), false),
+            Some(Entity(1))
+        );
+        assert_eq!(
+            next_focus(&candidates, Some(Entity(1)), false),
+            Some(Entity(4))
+        );
+        assert_eq!(
+            next_focus(&candidates, Some(Entity(4)), false),
+            Some(Entity(5))
+        );
+        assert_eq!(
+            next_focus(&candidates, Some(Entity(3)), false),
+            Some(Entity(5))
+        );
+    }
+
+    #[test]
+    fn test_next_focus_reverse() {
+        let candidates = candidates();
+
+        assert_eq!(next_focus(&candidates, None, true), Some(Entity(4)));
+        assert_eq!(
+            next_focus(&candidates, Some(Entity(4)), true),
+            Some(Entity(1))
+        );
+        assert_eq!(
+            next_focus(&candidates, Some(Entity(5)), true),
+            Some(Entity(4))
+        );
+    }
+
+    #[test]
+    fn test_next_focus_empty() {
+        assert_eq!(next_focus(&[], None, false), None);
+        assert_eq!(next_focus(&[(Entity(1), -1)], None, false), None);
+    }
 }
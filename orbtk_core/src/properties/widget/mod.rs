@@ -5,6 +5,7 @@ pub use self::render_pipeline::*;
 pub use self::selected_entities::*;
 pub use self::selected_indices::*;
 pub use self::text_selection::*;
+pub use self::tooltip::*;
 
 mod focus_state;
 mod keyboard_state;
@@ -12,3 +13,4 @@ mod render_pipeline;
 mod selected_entities;
 mod selected_indices;
 mod text_selection;
+mod tooltip;
@@ -0,0 +1,121 @@
+use std::{fmt, rc::Rc};
+
+use dces::prelude::Entity;
+
+use crate::{
+    properties::{AttachedProperty, IntoPropertySource, PropertySource},
+    widget_base::BuildContext,
+};
+
+/// The default time in milliseconds the mouse has to rest on a widget before its tooltip is shown.
+pub const DEFAULT_TOOLTIP_DELAY: u32 = 500;
+
+/// Describes the content of a tooltip.
+#[derive(Clone)]
+pub enum TooltipContent {
+    /// The tooltip displays the given text.
+    Text(String),
+
+    /// The content widget is build each time the tooltip is shown.
+    Builder(Rc<dyn Fn(&mut BuildContext) -> Entity>),
+}
+
+impl Default for TooltipContent {
+    fn default() -> Self {
+        TooltipContent::Text(String::new())
+    }
+}
+
+impl fmt::Debug for TooltipContent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TooltipContent::Text(text) => f.debug_tuple("Text").field(text).finish(),
+            TooltipContent::Builder(_) => f.write_str("Builder"),
+        }
+    }
+}
+
+impl PartialEq for TooltipContent {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (TooltipContent::Text(text), TooltipContent::Text(other)) => text == other,
+            (TooltipContent::Builder(builder), TooltipContent::Builder(other)) => {
+                Rc::ptr_eq(builder, other)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl From<&str> for TooltipContent {
+    fn from(text: &str) -> Self {
+        TooltipContent::Text(text.to_string())
+    }
+}
+
+impl From<String> for TooltipContent {
+    fn from(text: String) -> Self {
+        TooltipContent::Text(text)
+    }
+}
+
+into_property_source!(TooltipContent: &str, String);
+
+/// Provides attached properties to show a tooltip if the mouse rests on a widget.
+///
+/// The tooltip is displayed in the overlay after the hover delay of the widget (or
+/// the `tooltip_delay` of the window) and is hidden if the mouse leaves the widget, a mouse
+/// button is pressed or the mouse wheel is used.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Button::new()
+///     .text("Save")
+///     .attach(Tooltip::text("Saves the current document"))
+///     .attach(Tooltip::delay(1000))
+///     .build(ctx)
+/// ```
+pub struct Tooltip;
+
+impl Tooltip {
+    /// Attaches a tooltip that displays the given text.
+    pub fn text(text: impl IntoPropertySource<TooltipContent>) -> AttachedProperty<TooltipContent> {
+        AttachedProperty::new("tooltip", text)
+    }
+
+    /// Attaches a tooltip with a custom content. The builder is called each time the tooltip
+    /// is shown.
+    pub fn content<F: Fn(&mut BuildContext) -> Entity + 'static>(
+        builder: F,
+    ) -> AttachedProperty<TooltipContent> {
+        AttachedProperty::new("tooltip", TooltipContent::Builder(Rc::new(builder)))
+    }
+
+    /// Sets the time in milliseconds the mouse has to rest on the widget before its tooltip
+    /// is shown.
+    pub fn delay(delay: impl IntoPropertySource<u32>) -> AttachedProperty<u32> {
+        AttachedProperty::new("tooltip_delay", delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tooltip_content() {
+        assert_eq!(
+            TooltipContent::from("Save"),
+            TooltipContent::Text("Save".to_string())
+        );
+        assert_eq!(TooltipContent::default(), TooltipContent::from(""));
+
+        let builder: Rc<dyn Fn(&mut BuildContext) -> Entity> = Rc::new(|_| Entity(0));
+        let content = TooltipContent::Builder(builder.clone());
+
+        assert_eq!(content, TooltipContent::Builder(builder));
+        assert_ne!(content, TooltipContent::Builder(Rc::new(|_| Entity(0))));
+        assert_ne!(content, TooltipContent::from("Save"));
+    }
+}
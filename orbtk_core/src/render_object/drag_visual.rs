@@ -0,0 +1,12 @@
+use crate::{proc_macros::IntoRenderObject, render_object::*, utils::Point};
+
+/// Used to render the visual of a drag operation: a rectangle with the text of the dragged data.
+#[derive(Debug, IntoRenderObject)]
+pub struct DragVisualRenderObject;
+
+impl RenderObject for DragVisualRenderObject {
+    fn render_self(&self, ctx: &mut Context, global_position: &Point) {
+        RectangleRenderObject.render_self(ctx, global_position);
+        TextRenderObject.render_self(ctx, global_position);
+    }
+}
@@ -11,21 +11,25 @@ use crate::{application::ContextProvider, prelude::*, render::RenderContext2D, u
 
 pub use self::cursor::*;
 pub use self::default::*;
+pub use self::drag_visual::*;
 pub use self::font_icon::*;
 pub use self::image::*;
 pub use self::pipeline::*;
 pub use self::popup::*;
 pub use self::rectangle::*;
 pub use self::text::*;
+pub use self::viewbox::*;
 
 mod cursor;
 mod default;
+mod drag_visual;
 mod font_icon;
 mod image;
 mod pipeline;
 mod popup;
 mod rectangle;
 mod text;
+mod viewbox;
 
 /// The `RenderObject` trait defines rendering of 2D objects.
 ///
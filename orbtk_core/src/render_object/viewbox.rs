@@ -0,0 +1,137 @@
+use crate::{proc_macros::IntoRenderObject, render_object::*};
+
+/// The scaling of a `Viewbox` that is applied to the widgets inside of it. The render
+/// object stores it as `viewbox_transform` component of the widgets, so the mouse position
+/// can be mapped back to the unscaled bounds of the widgets, e.g. by `check_mouse_condition`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ViewboxTransform {
+    /// Horizontal and vertical scale factor.
+    pub scale: (f64, f64),
+
+    /// Offset that is added after the scaling.
+    pub offset: (f64, f64),
+}
+
+impl Default for ViewboxTransform {
+    fn default() -> Self {
+        ViewboxTransform {
+            scale: (1.0, 1.0),
+            offset: (0.0, 0.0),
+        }
+    }
+}
+
+impl ViewboxTransform {
+    /// Maps the given position on the window to the unscaled position inside of the viewbox.
+    pub fn inverse(&self, position: Point) -> Point {
+        if self.scale.0 == 0.0 || self.scale.1 == 0.0 {
+            return position;
+        }
+
+        Point::new(
+            (position.x() - self.offset.0) / self.scale.0,
+            (position.y() - self.offset.1) / self.scale.1,
+        )
+    }
+
+    // Applies the given outer transform after this transform, e.g. of nested viewboxes.
+    fn then(self, outer: ViewboxTransform) -> ViewboxTransform {
+        ViewboxTransform {
+            scale: (self.scale.0 * outer.scale.0, self.scale.1 * outer.scale.1),
+            offset: (
+                self.offset.0 * outer.scale.0 + outer.offset.0,
+                self.offset.1 * outer.scale.1 + outer.offset.1,
+            ),
+        }
+    }
+}
+
+/// The `ViewboxRenderObject` scales the children of a viewbox to its size with a render
+/// transform. The scaling is defined by the `stretch` property, the scaled content is
+/// centered inside of the viewbox.
+#[derive(Debug, IntoRenderObject)]
+pub struct ViewboxRenderObject;
+
+impl RenderObject for ViewboxRenderObject {
+    fn render_children(
+        &self,
+        render_context: &mut RenderContext2D,
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree>,
+        context_provider: &ContextProvider,
+        theme: &Rc<Theme>,
+        offsets: &mut BTreeMap<Entity, (f64, f64)>,
+        debug: bool,
+    ) {
+        let stretch = ecm
+            .component_store()
+            .get::<Stretch>("stretch", entity)
+            .map_or(Stretch::default(), |stretch| *stretch);
+        let size = ecm
+            .component_store()
+            .get::<Rectangle>("bounds", entity)
+            .map_or((0.0, 0.0), |bounds| (bounds.width(), bounds.height()));
+        let origin = offsets.get(&entity).copied().unwrap_or_default();
+        let outer_transform = ecm
+            .component_store()
+            .get::<ViewboxTransform>("viewbox_transform", entity)
+            .map_or(ViewboxTransform::default(), |transform| *transform);
+
+        for index in 0..ecm.entity_store().children[&entity].len() {
+            let child = ecm.entity_store().children[&entity][index];
+
+            let content_size = match (
+                ecm.component_store().get::<Rectangle>("bounds", child),
+                ecm.component_store().get::<Thickness>("margin", child),
+            ) {
+                (Ok(bounds), Ok(margin)) => (
+                    bounds.width() + margin.left() + margin.right(),
+                    bounds.height() + margin.top() + margin.bottom(),
+                ),
+                _ => continue,
+            };
+
+            let scale = stretch.scale(content_size, size);
+            let translation = (
+                origin.0 + (size.0 - content_size.0 * scale.0) / 2.0,
+                origin.1 + (size.1 - content_size.1 * scale.1) / 2.0,
+            );
+
+            // the children are arranged unscaled, their transform is needed for hit-testing
+            let transform = ViewboxTransform {
+                scale,
+                offset: (
+                    translation.0 - origin.0 * scale.0,
+                    translation.1 - origin.1 * scale.1,
+                ),
+            }
+            .then(outer_transform);
+
+            let nodes: Vec<Entity> = ecm.entity_store().start_node(child).into_iter().collect();
+
+            for node in nodes {
+                ecm.component_store_mut()
+                    .register("viewbox_transform", node, transform);
+            }
+
+            render_context.save();
+            render_context.translate(translation.0, translation.1);
+            render_context.scale(scale.0, scale.1);
+            render_context.translate(-origin.0, -origin.1);
+
+            if let Some(render_object) = context_provider.render_objects.borrow().get(&child) {
+                render_object.render(
+                    render_context,
+                    child,
+                    ecm,
+                    context_provider,
+                    theme,
+                    offsets,
+                    debug,
+                );
+            }
+
+            render_context.restore();
+        }
+    }
+}
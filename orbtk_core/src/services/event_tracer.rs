@@ -0,0 +1,345 @@
+use std::{collections::VecDeque, fmt};
+
+use dces::prelude::Entity;
+
+use crate::event::{EventBox, EventStrategy};
+
+/// The default number of traces the `EventTracer` keeps.
+pub const DEFAULT_MAX_TRACES: usize = 1000;
+
+/// Describes why a widget with a handler for the event is not part of its route.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipReason {
+    /// The widget or one of its parents is disabled.
+    Disabled,
+
+    /// The widget or one of its parents is not visible.
+    Hidden,
+
+    /// The position of the event is inside of the widget but outside of its clipping parent.
+    Clipped,
+
+    /// The position of the event is outside of the widget.
+    Missed,
+}
+
+/// Describes the phase in which the handlers of a widget are called.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventPhase {
+    /// Preview handlers from the root to the target.
+    Tunneling,
+
+    /// Regular handlers of a routed event.
+    Bubbling,
+
+    /// Handlers of an event that is sent direct to a widget.
+    Direct,
+}
+
+/// Describes the call of the handlers of a widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HandlerCall {
+    /// The widget whose handlers are called.
+    pub entity: Entity,
+
+    /// The phase of the call.
+    pub phase: EventPhase,
+
+    /// `true` if one of the handlers has marked the event as handled.
+    pub handled: bool,
+}
+
+/// Records how a single event is dispatched: its route through the tree, the widgets that
+/// are skipped and the handlers that have seen the event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventTrace {
+    /// The type name of the event.
+    pub event: &'static str,
+
+    /// The strategy of the event.
+    pub strategy: EventStrategy,
+
+    /// The widget the event is sent to.
+    pub source: Entity,
+
+    /// The widgets that match the event, ordered from the root to the target.
+    pub route: Vec<Entity>,
+
+    /// The widgets with a handler for the event that are not part of the route.
+    pub skipped: Vec<(Entity, SkipReason)>,
+
+    /// The handler calls in the order they are made.
+    pub calls: Vec<HandlerCall>,
+}
+
+impl EventTrace {
+    /// Creates a new empty trace of the given event.
+    pub fn new(event: &EventBox) -> Self {
+        EventTrace {
+            event: event.event_name(),
+            strategy: event.strategy.clone(),
+            source: event.source,
+            route: vec![],
+            skipped: vec![],
+            calls: vec![],
+        }
+    }
+
+    /// Returns `true` if the traced event is of the given type.
+    pub fn is<E>(&self) -> bool {
+        self.event == std::any::type_name::<E>()
+    }
+
+    /// Returns the call that has marked the event as handled.
+    pub fn handled_by(&self) -> Option<&HandlerCall> {
+        self.calls.iter().find(|call| call.handled)
+    }
+
+    /// Returns `true` if the event is marked as handled.
+    pub fn is_handled(&self) -> bool {
+        self.handled_by().is_some()
+    }
+
+    /// Returns the reason why the given widget is skipped.
+    pub fn skip_reason(&self, entity: Entity) -> Option<SkipReason> {
+        self.skipped
+            .iter()
+            .find(|(skipped, _)| *skipped == entity)
+            .map(|(_, reason)| *reason)
+    }
+}
+
+impl fmt::Display for EventTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.event.rsplit("::").next().unwrap_or(self.event);
+
+        writeln!(
+            f,
+            "{} ({:?}) source: {}",
+            name, self.strategy, self.source.0
+        )?;
+
+        if !self.route.is_empty() {
+            let route: Vec<String> = self.route.iter().map(|e| e.0.to_string()).collect();
+            writeln!(f, "  route: {}", route.join(" -> "))?;
+        }
+
+        for (entity, reason) in &self.skipped {
+            writeln!(f, "  skipped {}: {:?}", entity.0, reason)?;
+        }
+
+        for call in &self.calls {
+            writeln!(
+                f,
+                "  {:?} {}: {}",
+                call.phase,
+                call.entity.0,
+                if call.handled {
+                    "handled"
+                } else {
+                    "not handled"
+                }
+            )?;
+        }
+
+        if self.calls.is_empty() {
+            writeln!(f, "  no handler called")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The `EventTracer` records how the events of a window are dispatched. It is only available
+/// with the `debug` feature and registered as `event_tracer` service of the window.
+///
+/// The traces can be read e.g. from a test or printed to the console.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// fn update(&mut self, registry: &mut Registry, _: &mut Context) {
+///     let tracer = registry.get_mut::<EventTracer>("event_tracer");
+///
+///     for trace in tracer.traces().filter(|trace| trace.is::<ClickEvent>()) {
+///         println!("{}", trace);
+///     }
+///
+///     tracer.clear();
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct EventTracer {
+    traces: VecDeque<EventTrace>,
+    current: Option<EventTrace>,
+    max_traces: usize,
+    print: bool,
+}
+
+impl Default for EventTracer {
+    fn default() -> Self {
+        EventTracer {
+            traces: VecDeque::new(),
+            current: None,
+            max_traces: DEFAULT_MAX_TRACES,
+            print: false,
+        }
+    }
+}
+
+impl EventTracer {
+    /// Creates a new event tracer.
+    pub fn new() -> Self {
+        EventTracer::default()
+    }
+
+    /// If set to `true` each trace is printed to the console after the event is dispatched.
+    pub fn set_print(&mut self, print: bool) {
+        self.print = print;
+    }
+
+    /// Sets the number of traces that are kept, older traces are dropped.
+    pub fn set_max_traces(&mut self, max_traces: usize) {
+        self.max_traces = max_traces;
+
+        while self.traces.len() > self.max_traces {
+            self.traces.pop_front();
+        }
+    }
+
+    /// Returns an iterator over the recorded traces from the oldest to the newest.
+    pub fn traces(&self) -> impl Iterator<Item = &EventTrace> {
+        self.traces.iter()
+    }
+
+    /// Returns the newest trace.
+    pub fn last(&self) -> Option<&EventTrace> {
+        self.traces.back()
+    }
+
+    /// Removes all recorded traces.
+    pub fn clear(&mut self) {
+        self.traces.clear();
+    }
+
+    /// Starts the trace of the given event.
+    pub fn begin(&mut self, event: &EventBox) {
+        self.end();
+        self.current = Some(EventTrace::new(event));
+    }
+
+    /// Adds the widget to the route of the current event.
+    pub fn route(&mut self, entity: Entity) {
+        if let Some(trace) = &mut self.current {
+            trace.route.push(entity);
+        }
+    }
+
+    /// Records that the widget is skipped by the current event.
+    pub fn skip(&mut self, entity: Entity, reason: SkipReason) {
+        if let Some(trace) = &mut self.current {
+            trace.skipped.push((entity, reason));
+        }
+    }
+
+    /// Records a handler call of the current event.
+    pub fn call(&mut self, entity: Entity, phase: EventPhase, handled: bool) {
+        if let Some(trace) = &mut self.current {
+            trace.calls.push(HandlerCall {
+                entity,
+                phase,
+                handled,
+            });
+        }
+    }
+
+    /// Finishes the trace of the current event.
+    pub fn end(&mut self) {
+        if let Some(trace) = self.current.take() {
+            if self.print {
+                print!("{}", trace);
+            }
+
+            if self.max_traces == 0 {
+                return;
+            }
+
+            if self.traces.len() == self.max_traces {
+                self.traces.pop_front();
+            }
+
+            self.traces.push_back(trace);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{event::ClickEvent, utils::Point};
+
+    #[test]
+    fn test_trace() {
+        let mut tracer = EventTracer::new();
+        let event = EventBox::new(
+            ClickEvent {
+                position: Point::new(4.0, 4.0),
+                click_count: 1,
+            },
+            EventStrategy::BottomUp,
+            Entity(0),
+        );
+
+        tracer.begin(&event);
+        tracer.route(Entity(0));
+        tracer.route(Entity(2));
+        tracer.skip(Entity(3), SkipReason::Disabled);
+        tracer.call(Entity(0), EventPhase::Tunneling, false);
+        tracer.call(Entity(2), EventPhase::Bubbling, true);
+        tracer.end();
+
+        let trace = tracer.last().unwrap();
+        assert!(trace.is::<ClickEvent>());
+        assert_eq!(trace.route, vec![Entity(0), Entity(2)]);
+        assert_eq!(trace.skip_reason(Entity(3)), Some(SkipReason::Disabled));
+        assert_eq!(trace.skip_reason(Entity(2)), None);
+        assert_eq!(
+            trace.handled_by(),
+            Some(&HandlerCall {
+                entity: Entity(2),
+                phase: EventPhase::Bubbling,
+                handled: true
+            })
+        );
+        assert_eq!(
+            trace.to_string(),
+            "ClickEvent (BottomUp) source: 0\n  route: 0 -> 2\n  skipped 3: Disabled\n  \
+             Tunneling 0: not handled\n  Bubbling 2: handled\n"
+        );
+    }
+
+    #[test]
+    fn test_max_traces() {
+        let mut tracer = EventTracer::new();
+        tracer.set_max_traces(2);
+
+        for source in 0..3 {
+            tracer.begin(&EventBox::new(
+                ClickEvent {
+                    position: Point::default(),
+                    click_count: 1,
+                },
+                EventStrategy::BottomUp,
+                Entity(source),
+            ));
+        }
+        tracer.end();
+
+        let sources: Vec<Entity> = tracer.traces().map(|trace| trace.source).collect();
+        assert_eq!(sources, vec![Entity(1), Entity(2)]);
+        assert!(!tracer.last().unwrap().is_handled());
+
+        tracer.clear();
+        assert!(tracer.last().is_none());
+    }
+}
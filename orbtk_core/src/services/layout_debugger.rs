@@ -0,0 +1,641 @@
+use std::collections::BTreeMap;
+
+use dces::prelude::*;
+
+use crate::{prelude::*, render::RenderContext2D, shell::Key, tree::Tree, utils::*};
+
+// Colors of the overlay.
+const BOUNDS_COLOR: &str = "#0033cc";
+const MARGIN_COLOR: &str = "#f5a623";
+const PADDING_COLOR: &str = "#2eb82e";
+const BASELINE_COLOR: &str = "#e600e6";
+const GRID_LINE_COLOR: &str = "#00b4d8";
+const HIGHLIGHT_COLOR: &str = "#ff3333";
+const INFO_BACKGROUND: &str = "#202020";
+const INFO_FOREGROUND: &str = "#ffffff";
+
+// Font of the info label.
+const INFO_FONT: &str = "Roboto-Regular";
+const INFO_FONT_SIZE: f64 = 10.0;
+const INFO_LINE_HEIGHT: f64 = 16.0;
+const INFO_PADDING: f64 = 6.0;
+
+// Distance in pixels between the pointer and the info label.
+const INFO_OFFSET: f64 = 16.0;
+
+/// The `LayoutDebugger` draws an overlay on top of the window that makes the layout of the
+/// widgets visible: their bounds, margins, padding, text baselines and the lines of grids.
+/// The widget under the pointer is highlighted and its type, id, style and constraint are
+/// shown next to the pointer.
+///
+/// The debugger is registered as `layout_debugger` service of each window and is disabled by
+/// default. It could be toggled at runtime over its api or with its toggle key, that is `F12`
+/// if the `debug` feature is enabled.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// fn update(&mut self, registry: &mut Registry, _: &mut Context) {
+///     let debugger = registry.get_mut::<LayoutDebugger>("layout_debugger");
+///
+///     debugger.set_show_baselines(false);
+///     debugger.toggle();
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct LayoutDebugger {
+    enabled: bool,
+    show_bounds: bool,
+    show_margins: bool,
+    show_padding: bool,
+    show_baselines: bool,
+    show_grid_lines: bool,
+    show_info: bool,
+    toggle_key: Option<Key>,
+    dirty: bool,
+    pointer: Option<Point>,
+}
+
+impl Default for LayoutDebugger {
+    fn default() -> Self {
+        LayoutDebugger {
+            enabled: false,
+            show_bounds: true,
+            show_margins: true,
+            show_padding: true,
+            show_baselines: true,
+            show_grid_lines: true,
+            show_info: true,
+            toggle_key: if cfg!(feature = "debug") {
+                Some(Key::F12)
+            } else {
+                None
+            },
+            dirty: false,
+            pointer: None,
+        }
+    }
+}
+
+impl LayoutDebugger {
+    /// Creates a new disabled layout debugger.
+    pub fn new() -> Self {
+        LayoutDebugger::default()
+    }
+
+    /// Returns `true` if the overlay is drawn.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Enables or disables the overlay.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        if self.enabled != enabled {
+            self.enabled = enabled;
+            self.dirty = true;
+        }
+    }
+
+    /// Enables the overlay if it is disabled and disables it otherwise.
+    pub fn toggle(&mut self) {
+        self.set_enabled(!self.enabled);
+    }
+
+    /// Returns the key that toggles the overlay.
+    pub fn toggle_key(&self) -> Option<Key> {
+        self.toggle_key
+    }
+
+    /// Sets the key that toggles the overlay. With `None` the overlay could only be toggled
+    /// over the api.
+    pub fn set_toggle_key(&mut self, toggle_key: impl Into<Option<Key>>) {
+        self.toggle_key = toggle_key.into();
+    }
+
+    /// If set to `true` the bounds of each widget are drawn.
+    pub fn set_show_bounds(&mut self, show_bounds: bool) {
+        self.show_bounds = show_bounds;
+        self.dirty = true;
+    }
+
+    /// If set to `true` the margins of each widget are drawn outside of its bounds.
+    pub fn set_show_margins(&mut self, show_margins: bool) {
+        self.show_margins = show_margins;
+        self.dirty = true;
+    }
+
+    /// If set to `true` the padding of each widget is drawn inside of its bounds.
+    pub fn set_show_padding(&mut self, show_padding: bool) {
+        self.show_padding = show_padding;
+        self.dirty = true;
+    }
+
+    /// If set to `true` the baselines of each text line are drawn.
+    pub fn set_show_baselines(&mut self, show_baselines: bool) {
+        self.show_baselines = show_baselines;
+        self.dirty = true;
+    }
+
+    /// If set to `true` the lines between the columns and rows of each grid are drawn.
+    pub fn set_show_grid_lines(&mut self, show_grid_lines: bool) {
+        self.show_grid_lines = show_grid_lines;
+        self.dirty = true;
+    }
+
+    /// If set to `true` the widget under the pointer is highlighted and described by a label.
+    pub fn set_show_info(&mut self, show_info: bool) {
+        self.show_info = show_info;
+        self.dirty = true;
+    }
+
+    // Toggles the overlay if the given key is the toggle key. Returns `true` if the overlay
+    // was toggled.
+    pub(crate) fn handle_key(&mut self, key: Key) -> bool {
+        if self.toggle_key == Some(key) {
+            self.toggle();
+            return true;
+        }
+
+        false
+    }
+
+    // Returns `true` if the window has to be rendered again to update the overlay, e.g. if
+    // the debugger was toggled or the pointer has moved while it is enabled.
+    pub(crate) fn needs_render(&self, pointer: Point) -> bool {
+        self.dirty || (self.enabled && self.show_info && self.pointer != Some(pointer))
+    }
+
+    // Draws the overlay on top of the rendered widgets. The offsets are the global positions
+    // of the widgets, that are collected by the render objects.
+    pub(crate) fn render(
+        &mut self,
+        render_context: &mut RenderContext2D,
+        ecm: &mut EntityComponentManager<Tree>,
+        offsets: &BTreeMap<Entity, (f64, f64)>,
+        pointer: Point,
+    ) {
+        self.dirty = false;
+        self.pointer = Some(pointer);
+
+        if !self.enabled {
+            return;
+        }
+
+        let tree = ecm.entity_store();
+        let root = tree.root();
+        let overlay = tree.overlay;
+
+        // the tree is iterated in render order, so the last widget under the pointer is the top most
+        let entities: Vec<Entity> = tree
+            .into_iter()
+            .filter(|entity| offsets.contains_key(entity))
+            .collect();
+
+        let mut hovered = None;
+
+        render_context.save();
+        render_context.set_alpha(1.0);
+        render_context.set_line_width(1.0);
+
+        for entity in entities {
+            let store = ecm.component_store();
+            let bounds = match store.get::<Rectangle>("bounds", entity) {
+                Ok(bounds) => *bounds,
+                Err(_) => continue,
+            };
+            let (x, y) = offsets[&entity];
+            let global_bounds = Rectangle::new((x, y), (bounds.width(), bounds.height()));
+
+            if entity != root
+                && Some(entity) != overlay
+                && bounds.width() > 0.0
+                && bounds.height() > 0.0
+                && global_bounds.contains(pointer)
+            {
+                hovered = Some((entity, global_bounds));
+            }
+
+            if self.show_margins {
+                if let Ok(margin) = store.get::<Thickness>("margin", entity) {
+                    if *margin != Thickness::default() {
+                        stroke_rect(
+                            render_context,
+                            MARGIN_COLOR,
+                            x - margin.left(),
+                            y - margin.top(),
+                            bounds.width() + margin.left() + margin.right(),
+                            bounds.height() + margin.top() + margin.bottom(),
+                        );
+                    }
+                }
+            }
+
+            if self.show_padding {
+                if let Ok(padding) = store.get::<Thickness>("padding", entity) {
+                    if *padding != Thickness::default() {
+                        stroke_rect(
+                            render_context,
+                            PADDING_COLOR,
+                            x + padding.left(),
+                            y + padding.top(),
+                            bounds.width() - padding.left() - padding.right(),
+                            bounds.height() - padding.top() - padding.bottom(),
+                        );
+                    }
+                }
+            }
+
+            if self.show_bounds {
+                stroke_rect(
+                    render_context,
+                    BOUNDS_COLOR,
+                    x,
+                    y,
+                    bounds.width(),
+                    bounds.height(),
+                );
+            }
+
+            if self.show_grid_lines {
+                self.render_grid_lines(render_context, ecm, entity, &global_bounds);
+            }
+
+            if self.show_baselines {
+                self.render_baselines(render_context, ecm, entity, &global_bounds);
+            }
+        }
+
+        if self.show_info {
+            if let Some((entity, bounds)) = hovered {
+                render_context.set_line_width(2.0);
+                stroke_rect(
+                    render_context,
+                    HIGHLIGHT_COLOR,
+                    bounds.x(),
+                    bounds.y(),
+                    bounds.width(),
+                    bounds.height(),
+                );
+                render_context.set_line_width(1.0);
+
+                let window_size = ecm
+                    .component_store()
+                    .get::<Rectangle>("bounds", root)
+                    .map(|bounds| (bounds.width(), bounds.height()))
+                    .unwrap_or_default();
+
+                let lines = info_lines(ecm.component_store(), entity);
+                render_info(render_context, &lines, pointer, window_size);
+            }
+        }
+
+        render_context.restore();
+    }
+
+    fn render_grid_lines(
+        &self,
+        render_context: &mut RenderContext2D,
+        ecm: &EntityComponentManager<Tree>,
+        entity: Entity,
+        bounds: &Rectangle,
+    ) {
+        let store = ecm.component_store();
+
+        if let Ok(columns) = store.get::<Blocks>("columns", entity) {
+            let gap = store
+                .get::<f64>("column_gap", entity)
+                .copied()
+                .unwrap_or_default();
+            let sizes: Vec<f64> = columns.iter().map(|c| c.current_size()).collect();
+
+            for offset in grid_line_offsets(&sizes, gap) {
+                line(
+                    render_context,
+                    GRID_LINE_COLOR,
+                    (bounds.x() + offset, bounds.y()),
+                    (bounds.x() + offset, bounds.y() + bounds.height()),
+                );
+            }
+        }
+
+        if let Ok(rows) = store.get::<Blocks>("rows", entity) {
+            let gap = store
+                .get::<f64>("row_gap", entity)
+                .copied()
+                .unwrap_or_default();
+            let sizes: Vec<f64> = rows.iter().map(|r| r.current_size()).collect();
+
+            for offset in grid_line_offsets(&sizes, gap) {
+                line(
+                    render_context,
+                    GRID_LINE_COLOR,
+                    (bounds.x(), bounds.y() + offset),
+                    (bounds.x() + bounds.width(), bounds.y() + offset),
+                );
+            }
+        }
+    }
+
+    fn render_baselines(
+        &self,
+        render_context: &mut RenderContext2D,
+        ecm: &mut EntityComponentManager<Tree>,
+        entity: Entity,
+        bounds: &Rectangle,
+    ) {
+        // only the widgets that draw the text by themselves, e.g. `TextBlock`
+        if ecm
+            .entity_store()
+            .children
+            .get(&entity)
+            .map_or(false, |children| !children.is_empty())
+        {
+            return;
+        }
+
+        let store = ecm.component_store();
+
+        let (text, font, font_size) = match (
+            store.get::<String>("text", entity),
+            store.get::<String>("font", entity),
+            store.get::<f64>("font_size", entity),
+        ) {
+            (Ok(text), Ok(font), Ok(font_size)) if !text.is_empty() && *font_size > 0.0 => {
+                (text.clone(), font.clone(), *font_size)
+            }
+            _ => return,
+        };
+
+        let offset = store
+            .get::<f64>("offset", entity)
+            .copied()
+            .unwrap_or_default();
+
+        // the same line height as used by the `TextRenderObject`
+        let mut y = bounds.y();
+        for text_line in text.split('\n') {
+            let ascent = render_context.measure(text_line, font_size, &font).ascent;
+
+            if ascent > 0.0 {
+                let width = render_context.measure_text(text_line).width;
+                line(
+                    render_context,
+                    BASELINE_COLOR,
+                    (bounds.x() + offset, y + ascent),
+                    (bounds.x() + offset + width, y + ascent),
+                );
+            }
+
+            y += font_size * 1.15;
+        }
+    }
+}
+
+fn stroke_rect(
+    render_context: &mut RenderContext2D,
+    color: &str,
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+) {
+    if width <= 0.0 || height <= 0.0 {
+        return;
+    }
+
+    render_context.begin_path();
+    render_context.set_stroke_style(Brush::from(color));
+    render_context.stroke_rect(x, y, width, height);
+    render_context.close_path();
+}
+
+fn line(render_context: &mut RenderContext2D, color: &str, from: (f64, f64), to: (f64, f64)) {
+    render_context.begin_path();
+    render_context.set_stroke_style(Brush::from(color));
+    render_context.move_to(from.0, from.1);
+    render_context.line_to(to.0, to.1);
+    render_context.stroke();
+    render_context.close_path();
+}
+
+fn render_info(
+    render_context: &mut RenderContext2D,
+    lines: &[String],
+    pointer: Point,
+    window_size: (f64, f64),
+) {
+    let width = lines
+        .iter()
+        .map(|line| {
+            render_context
+                .measure(line, INFO_FONT_SIZE, INFO_FONT)
+                .width
+        })
+        .fold(0.0, f64::max)
+        + 2.0 * INFO_PADDING;
+    let height = lines.len() as f64 * INFO_LINE_HEIGHT + 2.0 * INFO_PADDING;
+
+    let (x, y) = info_position(pointer, (width, height), window_size);
+
+    render_context.begin_path();
+    render_context.set_fill_style(Brush::from(INFO_BACKGROUND));
+    render_context.fill_rect(x, y, width, height);
+    render_context.close_path();
+
+    render_context.begin_path();
+    render_context.set_font_family(INFO_FONT);
+    render_context.set_font_size(INFO_FONT_SIZE);
+    render_context.set_fill_style(Brush::from(INFO_FOREGROUND));
+
+    for (index, line) in lines.iter().enumerate() {
+        render_context.fill_text(
+            line,
+            x + INFO_PADDING,
+            y + INFO_PADDING + index as f64 * INFO_LINE_HEIGHT,
+        );
+    }
+
+    render_context.close_path();
+}
+
+// Describes the given widget by its type, id, style, bounds and constraint.
+fn info_lines(store: &ComponentStore, entity: Entity) -> Vec<String> {
+    let mut lines = vec![];
+
+    let type_name = store
+        .get::<String>("type_name", entity)
+        .map(|type_name| short_type_name(type_name).to_string())
+        .unwrap_or_else(|_| "unknown".to_string());
+    lines.push(format!("{} ({})", type_name, entity.0));
+
+    if let Ok(id) = store.get::<String>("id", entity) {
+        lines.push(format!("id: {}", id));
+    }
+
+    if let Ok(selector) = store.get::<Selector>("selector", entity) {
+        if let Some(style) = &selector.style {
+            let mut style = style.clone();
+
+            for state in selector.states() {
+                style.push(':');
+                style.push_str(state);
+            }
+
+            lines.push(format!("style: {}", style));
+        }
+    }
+
+    if let Ok(bounds) = store.get::<Rectangle>("bounds", entity) {
+        lines.push(format!(
+            "bounds: {} {} {} x {}",
+            bounds.x(),
+            bounds.y(),
+            bounds.width(),
+            bounds.height()
+        ));
+    }
+
+    if let Ok(constraint) = store.get::<Constraint>("constraint", entity) {
+        lines.push(format_constraint(constraint));
+    }
+
+    lines
+}
+
+// Returns the name of the type without its module path.
+fn short_type_name(type_name: &str) -> &str {
+    type_name.rsplit("::").next().unwrap_or(type_name)
+}
+
+fn format_size(size: f64) -> String {
+    if size == f64::MAX {
+        "max".to_string()
+    } else {
+        format!("{}", size)
+    }
+}
+
+fn format_constraint(constraint: &Constraint) -> String {
+    let mut text = format!(
+        "constraint: {} x {} min {} x {} max {} x {}",
+        format_size(constraint.width()),
+        format_size(constraint.height()),
+        format_size(constraint.min_width()),
+        format_size(constraint.min_height()),
+        format_size(constraint.max_width()),
+        format_size(constraint.max_height()),
+    );
+
+    if constraint.aspect_ratio() > 0.0 {
+        text.push_str(&format!(" ratio {}", constraint.aspect_ratio()));
+    }
+
+    text
+}
+
+// Calculates the offsets of the lines between the blocks of a grid, a line is drawn in the
+// middle of each gap.
+fn grid_line_offsets(sizes: &[f64], gap: f64) -> Vec<f64> {
+    let mut offsets = vec![];
+    let mut offset = 0.0;
+
+    for (index, size) in sizes.iter().enumerate() {
+        offset += size;
+
+        if index < sizes.len() - 1 {
+            offsets.push(offset + gap / 2.0);
+            offset += gap;
+        }
+    }
+
+    offsets
+}
+
+// Places the info label below right of the pointer and moves it inside of the window.
+fn info_position(pointer: Point, size: (f64, f64), window_size: (f64, f64)) -> (f64, f64) {
+    let mut x = pointer.x() + INFO_OFFSET;
+    let mut y = pointer.y() + INFO_OFFSET;
+
+    if x + size.0 > window_size.0 {
+        x = pointer.x() - INFO_OFFSET - size.0;
+    }
+
+    if y + size.1 > window_size.1 {
+        y = pointer.y() - INFO_OFFSET - size.1;
+    }
+
+    (x.max(0.0), y.max(0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toggle() {
+        let mut debugger = LayoutDebugger::new();
+        assert!(!debugger.is_enabled());
+        assert!(!debugger.needs_render(Point::new(1.0, 1.0)));
+
+        debugger.toggle();
+        assert!(debugger.is_enabled());
+        assert!(debugger.needs_render(Point::new(1.0, 1.0)));
+
+        debugger.set_toggle_key(Key::F11);
+        assert!(!debugger.handle_key(Key::F12));
+        assert!(debugger.handle_key(Key::F11));
+        assert!(!debugger.is_enabled());
+
+        debugger.set_toggle_key(None);
+        assert!(!debugger.handle_key(Key::F11));
+    }
+
+    #[test]
+    fn grid_lines() {
+        assert_eq!(grid_line_offsets(&[], 4.0), Vec::<f64>::new());
+        assert_eq!(grid_line_offsets(&[50.0], 4.0), Vec::<f64>::new());
+        assert_eq!(
+            grid_line_offsets(&[50.0, 20.0, 30.0], 4.0),
+            vec![52.0, 76.0]
+        );
+        assert_eq!(grid_line_offsets(&[50.0, 20.0], 0.0), vec![50.0]);
+    }
+
+    #[test]
+    fn info_label_inside_window() {
+        assert_eq!(
+            info_position(Point::new(10.0, 10.0), (100.0, 40.0), (400.0, 300.0)),
+            (26.0, 26.0)
+        );
+        assert_eq!(
+            info_position(Point::new(350.0, 280.0), (100.0, 40.0), (400.0, 300.0)),
+            (234.0, 224.0)
+        );
+        assert_eq!(
+            info_position(Point::new(20.0, 20.0), (500.0, 40.0), (400.0, 300.0)),
+            (0.0, 36.0)
+        );
+    }
+
+    #[test]
+    fn constraint_text() {
+        assert_eq!(
+            format_constraint(&Constraint::default()),
+            "constraint: 0 x 0 min 0 x 0 max max x max"
+        );
+        assert_eq!(
+            format_constraint(
+                &Constraint::create()
+                    .width(120.0)
+                    .min_height(20.0)
+                    .aspect_ratio(2.0)
+                    .build()
+            ),
+            "constraint: 120 x 0 min 0 x 20 max max x max ratio 2"
+        );
+        assert_eq!(
+            short_type_name("orbtk_widgets::text_block::TextBlock"),
+            "TextBlock"
+        );
+    }
+}
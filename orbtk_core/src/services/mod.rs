@@ -1,7 +1,15 @@
 //! This module contains global services.
 //!
 pub use self::clipboard::*;
+#[cfg(feature = "debug")]
+pub use self::event_tracer::*;
+pub use self::layout_debugger::*;
 pub use self::settings::*;
+pub use self::shortcuts::*;
 
 mod clipboard;
+#[cfg(feature = "debug")]
+mod event_tracer;
+mod layout_debugger;
 mod settings;
+mod shortcuts;
@@ -0,0 +1,572 @@
+use std::{fmt, str::FromStr};
+
+use crate::{properties::KeyboardState, shell::Key};
+
+// Names of the keys that could be used in shortcuts beside letters and digits.
+static KEY_NAMES: &[(&str, Key)] = &[
+    ("Enter", Key::Enter),
+    ("Escape", Key::Escape),
+    ("Esc", Key::Escape),
+    ("Tab", Key::Tab),
+    ("Space", Key::Space),
+    ("Backspace", Key::Backspace),
+    ("Delete", Key::Delete),
+    ("Del", Key::Delete),
+    ("Up", Key::Up),
+    ("Down", Key::Down),
+    ("Left", Key::Left),
+    ("Right", Key::Right),
+    ("Home", Key::Home),
+    ("Menu", Key::Menu),
+    ("F1", Key::F1),
+    ("F2", Key::F2),
+    ("F3", Key::F3),
+    ("F4", Key::F4),
+    ("F5", Key::F5),
+    ("F6", Key::F6),
+    ("F7", Key::F7),
+    ("F8", Key::F8),
+    ("F9", Key::F9),
+    ("F10", Key::F10),
+    ("F11", Key::F11),
+    ("F12", Key::F12),
+    (".", Key::Dot),
+    ("/", Key::Slash),
+    ("\\", Key::Backslash),
+    ("Num+", Key::NumpadAdd),
+    ("Num-", Key::NumpadSubtract),
+    ("Num*", Key::NumpadMultiply),
+    ("Num/", Key::NumpadDivide),
+];
+
+static DIGITS: [Key; 10] = [
+    Key::Zero,
+    Key::One,
+    Key::Two,
+    Key::Three,
+    Key::Four,
+    Key::Five,
+    Key::Six,
+    Key::Seven,
+    Key::Eight,
+    Key::Nine,
+];
+
+/// Describes an error of the shortcut registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShortcutError {
+    /// The given shortcut string could not be parsed.
+    Parse(String),
+
+    /// The shortcut conflicts with the shortcut of the given command.
+    Conflict(String),
+}
+
+impl fmt::Display for ShortcutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShortcutError::Parse(shortcut) => write!(f, "Could not parse shortcut: {}", shortcut),
+            ShortcutError::Conflict(command) => {
+                write!(f, "Shortcut conflicts with command: {}", command)
+            }
+        }
+    }
+}
+
+/// Describes a single key stroke of a shortcut e.g. `Ctrl+S`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyStroke {
+    key: Key,
+    ctrl: bool,
+    alt: bool,
+    shift: bool,
+}
+
+impl KeyStroke {
+    /// Creates a new key stroke without modifiers. Letters are case insensitive, use `shift` instead.
+    pub fn new(key: Key) -> Self {
+        KeyStroke {
+            key: normalize(key),
+            ctrl: false,
+            alt: false,
+            shift: false,
+        }
+    }
+
+    /// Creates a key stroke from a pressed key and the modifiers of the keyboard state.
+    /// Returns `None` if the key is a modifier key.
+    pub fn from_key(key: Key, keyboard_state: &KeyboardState) -> Option<Self> {
+        if is_modifier(key) || key == Key::Unknown {
+            return None;
+        }
+
+        Some(KeyStroke {
+            key: normalize(key),
+            ctrl: keyboard_state.is_ctrl_down(),
+            alt: keyboard_state.is_alt_down(),
+            shift: keyboard_state.is_shift_down(),
+        })
+    }
+
+    /// Builder method that adds the control modifier.
+    pub fn ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+
+    /// Builder method that adds the alt modifier.
+    pub fn alt(mut self) -> Self {
+        self.alt = true;
+        self
+    }
+
+    /// Builder method that adds the shift modifier.
+    pub fn shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    /// Gets the key of the stroke.
+    pub fn key(&self) -> Key {
+        self.key
+    }
+}
+
+impl FromStr for KeyStroke {
+    type Err = ShortcutError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ShortcutError::Parse(s.to_string());
+        let parts: Vec<&str> = s.trim().split('+').map(|p| p.trim()).collect();
+
+        // a trailing `+` is the plus key of the numpad e.g. `Ctrl+Num+`
+        let (modifiers, key) = match parts.as_slice() {
+            [modifiers @ .., key, ""] if key.eq_ignore_ascii_case("num") => {
+                (modifiers, "Num+".to_string())
+            }
+            [modifiers @ .., key] => (modifiers, key.to_string()),
+            [] => return Err(error()),
+        };
+
+        let mut stroke = KeyStroke::new(parse_key(&key).ok_or_else(error)?);
+
+        for modifier in modifiers {
+            match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" => stroke.ctrl = true,
+                "alt" => stroke.alt = true,
+                "shift" => stroke.shift = true,
+                _ => return Err(error()),
+            }
+        }
+
+        Ok(stroke)
+    }
+}
+
+impl fmt::Display for KeyStroke {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.ctrl {
+            write!(f, "Ctrl+")?;
+        }
+        if self.alt {
+            write!(f, "Alt+")?;
+        }
+        if self.shift {
+            write!(f, "Shift+")?;
+        }
+
+        write!(f, "{}", key_name(self.key))
+    }
+}
+
+/// Describes the key strokes of a shortcut. Multi stroke shortcuts like `Ctrl+K Ctrl+C`
+/// are written with white spaces between the strokes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    strokes: Vec<KeyStroke>,
+}
+
+impl KeyChord {
+    /// Creates a new key chord from the given strokes.
+    pub fn new(strokes: Vec<KeyStroke>) -> Self {
+        KeyChord { strokes }
+    }
+
+    /// Gets the strokes of the chord.
+    pub fn strokes(&self) -> &[KeyStroke] {
+        &self.strokes
+    }
+
+    /// Returns `true` if the chord starts with the given strokes.
+    pub fn starts_with(&self, strokes: &[KeyStroke]) -> bool {
+        self.strokes.starts_with(strokes)
+    }
+
+    /// Returns `true` if one of the chords is a prefix of the other one. In that case it
+    /// could not be decided which of them is meant.
+    pub fn conflicts_with(&self, other: &KeyChord) -> bool {
+        self.starts_with(&other.strokes) || other.starts_with(&self.strokes)
+    }
+}
+
+impl From<KeyStroke> for KeyChord {
+    fn from(stroke: KeyStroke) -> Self {
+        KeyChord::new(vec![stroke])
+    }
+}
+
+impl FromStr for KeyChord {
+    type Err = ShortcutError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let strokes = s
+            .split_whitespace()
+            .map(KeyStroke::from_str)
+            .collect::<Result<Vec<KeyStroke>, ShortcutError>>()?;
+
+        if strokes.is_empty() {
+            return Err(ShortcutError::Parse(s.to_string()));
+        }
+
+        Ok(KeyChord::new(strokes))
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, stroke) in self.strokes.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", stroke)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Describes the result of a key stroke that is processed by the shortcut registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShortcutMatch {
+    /// The stroke is not part of a shortcut.
+    None,
+
+    /// The stroke is the start of a multi stroke shortcut, the registry waits for the next stroke.
+    Pending,
+
+    /// The shortcut of the given command is completed.
+    Command(String),
+}
+
+#[derive(Debug, Clone)]
+struct Shortcut {
+    chord: KeyChord,
+    command: String,
+    enabled: bool,
+}
+
+/// The `Shortcuts` service is a registry of keyboard shortcuts of a window. Each shortcut
+/// binds a key chord to a command. If the chord is pressed, a `CommandEvent` is sent to the
+/// focused widget and bubbles up to the window until it is handled.
+///
+/// # Examples
+/// ```rust,ignore
+/// impl State for MyState {
+///     fn init(&mut self, registry: &mut Registry, _: &mut Context) {
+///         let shortcuts = registry.get_mut::<Shortcuts>("shortcuts");
+///         shortcuts.register("Ctrl+S", "save").unwrap();
+///         shortcuts.register("Ctrl+K Ctrl+C", "comment").unwrap();
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Shortcuts {
+    shortcuts: Vec<Shortcut>,
+    pending: Vec<KeyStroke>,
+}
+
+impl Shortcuts {
+    /// Creates a new empty shortcut registry.
+    pub fn new() -> Self {
+        Shortcuts::default()
+    }
+
+    /// Parses the given shortcut and binds it to the command. Returns an error if the shortcut
+    /// could not be parsed or conflicts with an existing shortcut.
+    pub fn register(
+        &mut self,
+        shortcut: &str,
+        command: impl Into<String>,
+    ) -> Result<(), ShortcutError> {
+        self.register_chord(shortcut.parse()?, command)
+    }
+
+    /// Binds the given key chord to the command. Returns an error if it conflicts with an existing shortcut.
+    pub fn register_chord(
+        &mut self,
+        chord: impl Into<KeyChord>,
+        command: impl Into<String>,
+    ) -> Result<(), ShortcutError> {
+        let chord = chord.into();
+
+        if let Some(command) = self.conflict(&chord) {
+            return Err(ShortcutError::Conflict(command.to_string()));
+        }
+
+        self.shortcuts.push(Shortcut {
+            chord,
+            command: command.into(),
+            enabled: true,
+        });
+
+        Ok(())
+    }
+
+    /// Returns the command of the shortcut the given chord conflicts with.
+    pub fn conflict(&self, chord: &KeyChord) -> Option<&str> {
+        self.shortcuts
+            .iter()
+            .find(|s| s.chord.conflicts_with(chord))
+            .map(|s| s.command.as_str())
+    }
+
+    /// Removes all shortcuts of the given command. Returns `true` if a shortcut was removed.
+    pub fn unregister(&mut self, command: &str) -> bool {
+        let len = self.shortcuts.len();
+        self.shortcuts.retain(|s| s.command != command);
+        self.pending.clear();
+        len != self.shortcuts.len()
+    }
+
+    /// Enables or disables the shortcuts of the given command. Disabled shortcuts are ignored.
+    pub fn set_enabled(&mut self, command: &str, enabled: bool) {
+        for shortcut in self.shortcuts.iter_mut().filter(|s| s.command == command) {
+            shortcut.enabled = enabled;
+        }
+    }
+
+    /// Returns `true` if the command has an enabled shortcut.
+    pub fn is_enabled(&self, command: &str) -> bool {
+        self.shortcuts
+            .iter()
+            .any(|s| s.command == command && s.enabled)
+    }
+
+    /// Gets the key chord of the given command.
+    pub fn chord(&self, command: &str) -> Option<&KeyChord> {
+        self.shortcuts
+            .iter()
+            .find(|s| s.command == command)
+            .map(|s| &s.chord)
+    }
+
+    /// Gets the display string of the shortcut of the given command e.g. `Ctrl+Shift+P`.
+    pub fn display(&self, command: &str) -> Option<String> {
+        self.chord(command).map(|chord| chord.to_string())
+    }
+
+    /// Returns `true` if the registry waits for the next stroke of a multi stroke shortcut.
+    pub fn is_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Discards the pending strokes of a multi stroke shortcut.
+    pub fn reset(&mut self) {
+        self.pending.clear();
+    }
+
+    /// Processes the given key stroke and returns the command if a shortcut is completed.
+    pub fn process(&mut self, stroke: KeyStroke) -> ShortcutMatch {
+        self.pending.push(stroke);
+
+        let mut pending = false;
+
+        for shortcut in self.shortcuts.iter().filter(|s| s.enabled) {
+            if shortcut.chord.strokes() == self.pending.as_slice() {
+                self.pending.clear();
+                return ShortcutMatch::Command(shortcut.command.clone());
+            }
+
+            if shortcut.chord.starts_with(&self.pending) {
+                pending = true;
+            }
+        }
+
+        if pending {
+            return ShortcutMatch::Pending;
+        }
+
+        // the stroke could be the start of another shortcut
+        let retry = self.pending.len() > 1;
+        self.pending.clear();
+
+        if retry {
+            return self.process(stroke);
+        }
+
+        ShortcutMatch::None
+    }
+}
+
+// Letters are stored as lower case, the case is described by the shift modifier.
+fn normalize(key: Key) -> Key {
+    match char_of(key) {
+        Some(c) if c.is_ascii_alphabetic() => Key::from(c.to_ascii_lowercase()),
+        _ => key,
+    }
+}
+
+fn char_of(key: Key) -> Option<char> {
+    let s: &str = key.into();
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+fn is_modifier(key: Key) -> bool {
+    matches!(
+        key,
+        Key::Control | Key::Alt | Key::ShiftL | Key::ShiftR | Key::CapsLock
+    )
+}
+
+fn parse_key(name: &str) -> Option<Key> {
+    if let Some((_, key)) = KEY_NAMES.iter().find(|(n, _)| n.eq_ignore_ascii_case(name)) {
+        return Some(*key);
+    }
+
+    let mut chars = name.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii_alphabetic() => Some(normalize(Key::from(c))),
+        (Some(c), None) if c.is_ascii_digit() => c.to_digit(10).map(|d| DIGITS[d as usize]),
+        _ => None,
+    }
+}
+
+fn key_name(key: Key) -> String {
+    if let Some((name, _)) = KEY_NAMES.iter().find(|(_, k)| *k == key) {
+        return name.to_string();
+    }
+
+    if let Some(digit) = DIGITS.iter().position(|k| *k == key) {
+        return digit.to_string();
+    }
+
+    match char_of(key) {
+        Some(c) => c.to_ascii_uppercase().to_string(),
+        None => format!("{:?}", key),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        let stroke: KeyStroke = "ctrl+shift+p".parse().unwrap();
+        assert_eq!(stroke, KeyStroke::new(Key::P(false)).ctrl().shift());
+        assert_eq!(stroke.to_string(), "Ctrl+Shift+P");
+
+        let chord: KeyChord = "Ctrl+K  Ctrl+C".parse().unwrap();
+        assert_eq!(chord.strokes().len(), 2);
+        assert_eq!(chord.to_string(), "Ctrl+K Ctrl+C");
+
+        assert_eq!(
+            "Alt+Enter".parse::<KeyStroke>().unwrap().to_string(),
+            "Alt+Enter"
+        );
+        assert_eq!("Ctrl+1".parse::<KeyStroke>().unwrap().key(), Key::One);
+        assert_eq!(
+            "shift+f10".parse::<KeyStroke>().unwrap(),
+            KeyStroke::new(Key::F10).shift()
+        );
+        assert!("Ctrl+Foo".parse::<KeyStroke>().is_err());
+        assert!("Hyper+S".parse::<KeyStroke>().is_err());
+        assert!("".parse::<KeyChord>().is_err());
+    }
+
+    #[test]
+    fn test_conflicts() {
+        let mut shortcuts = Shortcuts::new();
+        assert!(shortcuts.register("Ctrl+S", "save").is_ok());
+        assert!(shortcuts.register("Ctrl+K Ctrl+C", "comment").is_ok());
+
+        assert_eq!(
+            shortcuts.register("ctrl+s", "store"),
+            Err(ShortcutError::Conflict("save".to_string()))
+        );
+        assert_eq!(
+            shortcuts.register("Ctrl+K", "kill"),
+            Err(ShortcutError::Conflict("comment".to_string()))
+        );
+        assert!(shortcuts.register("Ctrl+K Ctrl+U", "uncomment").is_ok());
+        assert_eq!(
+            shortcuts.display("uncomment"),
+            Some("Ctrl+K Ctrl+U".to_string())
+        );
+    }
+
+    #[test]
+    fn test_process() {
+        let mut shortcuts = Shortcuts::new();
+        shortcuts.register("Ctrl+S", "save").unwrap();
+        shortcuts.register("Ctrl+K Ctrl+C", "comment").unwrap();
+
+        let ctrl = |key| KeyStroke::new(key).ctrl();
+
+        assert_eq!(
+            shortcuts.process(ctrl(Key::S(false))),
+            ShortcutMatch::Command("save".to_string())
+        );
+        assert_eq!(
+            shortcuts.process(KeyStroke::new(Key::S(false))),
+            ShortcutMatch::None
+        );
+
+        assert_eq!(
+            shortcuts.process(ctrl(Key::K(false))),
+            ShortcutMatch::Pending
+        );
+        assert!(shortcuts.is_pending());
+        assert_eq!(
+            shortcuts.process(ctrl(Key::C(false))),
+            ShortcutMatch::Command("comment".to_string())
+        );
+
+        // a wrong second stroke is processed as first stroke
+        assert_eq!(
+            shortcuts.process(ctrl(Key::K(false))),
+            ShortcutMatch::Pending
+        );
+        assert_eq!(
+            shortcuts.process(ctrl(Key::S(false))),
+            ShortcutMatch::Command("save".to_string())
+        );
+        assert!(!shortcuts.is_pending());
+    }
+
+    #[test]
+    fn test_enabled() {
+        let mut shortcuts = Shortcuts::new();
+        shortcuts.register("Ctrl+S", "save").unwrap();
+        shortcuts.set_enabled("save", false);
+
+        assert!(!shortcuts.is_enabled("save"));
+        assert_eq!(
+            shortcuts.process(KeyStroke::new(Key::S(true)).ctrl()),
+            ShortcutMatch::None
+        );
+
+        shortcuts.set_enabled("save", true);
+        assert_eq!(
+            shortcuts.process(KeyStroke::new(Key::S(true)).ctrl()),
+            ShortcutMatch::Command("save".to_string())
+        );
+
+        assert!(shortcuts.unregister("save"));
+        assert!(!shortcuts.unregister("save"));
+    }
+}
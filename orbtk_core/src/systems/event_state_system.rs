@@ -1,8 +1,41 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    time::{Duration, Instant},
+};
 
 use dces::prelude::*;
 
-use crate::{prelude::*, render::RenderContext2D, theming::Theme, tree::Tree, utils::*};
+use crate::{
+    prelude::*,
+    render::RenderContext2D,
+    shell::{Key, MouseButton, WindowRequest},
+    theming::Theme,
+    tree::Tree,
+    utils::*,
+};
+
+// Distance in pixels between the mouse cursor and the drag visual.
+const DRAG_VISUAL_OFFSET: f64 = 12.0;
+
+/// Stores the state of the current drag and drop operation of a window.
+#[derive(Debug, Default)]
+pub struct DragDropState {
+    start: Option<Point>,
+    data: Option<DragData>,
+    target: Option<Entity>,
+    accepted: bool,
+}
+
+/// Stores the state of the tooltip of a window.
+#[derive(Debug, Default)]
+pub struct TooltipState {
+    target: Option<Entity>,
+    position: Point,
+    hover_start: Option<Instant>,
+    open: bool,
+    content: Option<Entity>,
+}
 
 /// The `EventStateSystem` pops events from the event queue and
 /// delegates the events to the corresponding event handlers of the
@@ -12,6 +45,9 @@ pub struct EventStateSystem {
     context_provider: ContextProvider,
     registry: Rc<RefCell<Registry>>,
     hovered_widgets: RefCell<Vec<Entity>>,
+    pressed_widgets: RefCell<Vec<Entity>>,
+    drag_drop: RefCell<DragDropState>,
+    tooltip: RefCell<TooltipState>,
 }
 
 impl EventStateSystem {
@@ -47,31 +83,115 @@ impl EventStateSystem {
         false
     }
 
-    fn remove_hover(&self, entity: Entity, ecm: &mut EntityComponentManager<Tree>, theme: &Theme) {
-        if self.hovered_widgets.borrow().is_empty()
-            || !self.hovered_widgets.borrow().contains(&entity)
-        {
+    // Releases the pressed widgets if the left mouse button is released or the window is deactivated.
+    fn process_pressed(&self, event: &EventBox, ecm: &mut EntityComponentManager<Tree>) {
+        let released = match event.downcast_ref::<GlobalMouseUpEvent>() {
+            Ok(event) => event.button == MouseButton::Left,
+            Err(_) => matches!(
+                event.downcast_ref::<WindowEvent>(),
+                Ok(WindowEvent::ActiveChanged(false))
+            ),
+        };
+
+        if !released || self.pressed_widgets.borrow().is_empty() {
             return;
         }
 
-        let pos = self
+        let root = ecm.entity_store().root();
+        let theme = ecm
+            .component_store()
+            .get::<Rc<Theme>>("theme", root)
+            .unwrap()
+            .clone();
+
+        self.release(ecm, &theme);
+    }
+
+    // Sets the interaction state of the given widget if it is still part of the tree.
+    fn set_interaction_state(
+        &self,
+        entity: Entity,
+        state: InteractionState,
+        value: bool,
+        ecm: &mut EntityComponentManager<Tree>,
+        theme: &Theme,
+    ) {
+        if !ecm.entity_store().parent.contains_key(&entity) {
+            return;
+        }
+
+        WidgetContainer::new(
+            entity,
+            ecm,
+            theme,
+            Some(&self.context_provider.event_adapter),
+        )
+        .set_interaction_state(state, value);
+    }
+
+    // Hovers the given target and its parents. Widgets that are not longer hovered receive
+    // a `LeaveEvent`, new hovered widgets an `EnterEvent`.
+    fn update_hover(
+        &self,
+        target: Option<Entity>,
+        position: Point,
+        ecm: &mut EntityComponentManager<Tree>,
+        theme: &Theme,
+    ) {
+        let hovered = target.map_or(vec![], |target| with_parents(target, ecm));
+
+        let left: Vec<Entity> = self
             .hovered_widgets
             .borrow()
             .iter()
-            .position(|x| *x == entity);
+            .filter(|entity| !hovered.contains(entity))
+            .copied()
+            .collect();
+
+        for entity in left {
+            self.hovered_widgets.borrow_mut().retain(|e| *e != entity);
+            self.set_interaction_state(entity, InteractionState::Hover, false, ecm, theme);
+
+            if self.handles_enter_leave(entity) {
+                self.context_provider
+                    .event_adapter
+                    .push_event_direct(entity, LeaveEvent { position });
+            }
+        }
 
-        if let Some(pos) = pos {
-            let removed = self.hovered_widgets.borrow_mut().remove(pos);
+        // enters from the root to the target
+        for entity in hovered.into_iter().rev() {
+            if self.hovered_widgets.borrow().contains(&entity) {
+                continue;
+            }
 
-            remove_flag(
-                "hover",
-                &mut WidgetContainer::new(
-                    removed,
-                    ecm,
-                    theme,
-                    Some(&self.context_provider.event_adapter),
-                ),
-            );
+            self.hovered_widgets.borrow_mut().push(entity);
+            self.set_interaction_state(entity, InteractionState::Hover, true, ecm, theme);
+
+            if self.handles_enter_event(entity) {
+                self.context_provider
+                    .event_adapter
+                    .push_event_direct(entity, EnterEvent { position });
+            }
+        }
+    }
+
+    // Presses the given target and its parents until the left mouse button is released.
+    fn press(&self, target: Entity, ecm: &mut EntityComponentManager<Tree>, theme: &Theme) {
+        self.release(ecm, theme);
+
+        for entity in with_parents(target, ecm).into_iter().rev() {
+            self.pressed_widgets.borrow_mut().push(entity);
+            self.set_interaction_state(entity, InteractionState::Pressed, true, ecm, theme);
+        }
+    }
+
+    // Removes the pressed state from all pressed widgets.
+    fn release(&self, ecm: &mut EntityComponentManager<Tree>, theme: &Theme) {
+        let pressed: Vec<Entity> = self.pressed_widgets.borrow_mut().drain(..).collect();
+
+        for entity in pressed {
+            self.set_interaction_state(entity, InteractionState::Pressed, false, ecm, theme);
         }
     }
 
@@ -89,42 +209,1077 @@ impl EventStateSystem {
             let mut ctx =
                 Context::new((entity, ecm), theme, &self.context_provider, render_context);
 
-            if let Some(state) = self.context_provider.states.borrow_mut().get_mut(&entity) {
-                state.cleanup(registry, &mut ctx);
-            }
+            if let Some(state) = self.context_provider.states.borrow_mut().get_mut(&entity) {
+                state.cleanup(registry, &mut ctx);
+            }
+
+            drop(ctx);
+        }
+        self.context_provider.states.borrow_mut().remove(&entity);
+
+        if self.context_provider.mouse_capture.get() == Some(entity) {
+            self.context_provider.mouse_capture.set(None);
+        }
+
+        self.hovered_widgets.borrow_mut().retain(|e| *e != entity);
+        self.pressed_widgets.borrow_mut().retain(|e| *e != entity);
+
+        ecm.remove_entity(entity);
+        self.context_provider.layouts.borrow_mut().remove(&entity);
+        self.context_provider
+            .render_objects
+            .borrow_mut()
+            .remove(&entity);
+        self.context_provider
+            .handler_map
+            .borrow_mut()
+            .remove(&entity);
+    }
+
+    // Removes the widget and all of its children.
+    fn remove_widget_tree(
+        &self,
+        entity: Entity,
+        theme: &Rc<Theme>,
+        ecm: &mut EntityComponentManager<Tree>,
+        render_context: &mut RenderContext2D,
+    ) {
+        let mut children = vec![];
+        get_all_children(&mut children, entity, ecm.entity_store());
+
+        // remove children of target widget.
+        for child in children.iter().rev() {
+            self.remove_widget(*child, theme, ecm, render_context);
+        }
+
+        // remove target widget
+        self.remove_widget(entity, theme, ecm, render_context);
+    }
+
+    // Sends the event direct to the handlers of the given entity. Returns `true` if the event is handled.
+    fn dispatch_to(
+        &self,
+        entity: Entity,
+        event: &EventBox,
+        ecm: &mut EntityComponentManager<Tree>,
+    ) -> bool {
+        let message_adapter = self.context_provider.message_adapter.clone();
+
+        if let Some(handlers) = self.context_provider.handler_map.borrow().get(&entity) {
+            // direct events have no tunneling phase
+            let handled = handlers
+                .iter()
+                .filter(|handler| !handler.is_preview())
+                .any(|handler| {
+                    handler.handle_event(
+                        &mut StatesContext::new(
+                            &mut *self.context_provider.states.borrow_mut(),
+                            ecm,
+                            &message_adapter,
+                        ),
+                        event,
+                    )
+                });
+
+            #[cfg(feature = "debug")]
+            self.trace(|tracer| tracer.call(entity, EventPhase::Direct, handled));
+
+            return handled;
+        }
+
+        false
+    }
+
+    // Returns the top most widget under the given position that handles the event.
+    fn hit_test(
+        &self,
+        position: Point,
+        event: &EventBox,
+        theme: &Theme,
+        ecm: &mut EntityComponentManager<Tree>,
+    ) -> Option<Entity> {
+        let root = ecm.entity_store().root();
+        let nodes: Vec<Entity> = ecm.entity_store().start_node(root).into_iter().collect();
+        let mut hit = None;
+
+        for node in nodes {
+            let has_handler = self
+                .context_provider
+                .handler_map
+                .borrow()
+                .get(&node)
+                .map_or(false, |handlers| {
+                    handlers.iter().any(|handler| handler.handles_event(event))
+                });
+
+            if has_handler && self.is_hit(node, position, theme, ecm) {
+                hit = Some(node);
+            }
+        }
+
+        hit
+    }
+
+    // Returns the top most widget under the given position.
+    fn widget_at(
+        &self,
+        position: Point,
+        theme: &Theme,
+        ecm: &mut EntityComponentManager<Tree>,
+    ) -> Option<Entity> {
+        let root = ecm.entity_store().root();
+        let nodes: Vec<Entity> = ecm.entity_store().start_node(root).into_iter().collect();
+
+        nodes
+            .into_iter()
+            .filter(|node| self.is_hit(*node, position, theme, ecm))
+            .last()
+    }
+
+    // Checks if the position is inside of the widget and the widget and its parents are enabled, visible and not clipped.
+    fn is_hit(
+        &self,
+        entity: Entity,
+        position: Point,
+        theme: &Theme,
+        ecm: &mut EntityComponentManager<Tree>,
+    ) -> bool {
+        let mut current = Some(entity);
+
+        while let Some(node) = current {
+            if let Ok(enabled) = ecm.component_store().get::<bool>("enabled", node) {
+                if !enabled {
+                    return false;
+                }
+            }
+
+            if let Ok(visibility) = ecm.component_store().get::<Visibility>("visibility", node) {
+                if *visibility != Visibility::Visible {
+                    return false;
+                }
+            }
+
+            let clip = node == entity
+                || *ecm
+                    .component_store()
+                    .get::<bool>("clip", node)
+                    .unwrap_or(&false);
+
+            if clip
+                && ecm
+                    .component_store()
+                    .get::<Rectangle>("bounds", node)
+                    .is_ok()
+                && !check_mouse_condition(
+                    position,
+                    &WidgetContainer::new(
+                        node,
+                        ecm,
+                        theme,
+                        Some(&self.context_provider.event_adapter),
+                    ),
+                )
+            {
+                return false;
+            }
+
+            current = ecm.entity_store().parent.get(&node).cloned().flatten();
+        }
+
+        true
+    }
+
+    // Returns the drag visual of the overlay.
+    fn drag_visual(&self, ecm: &mut EntityComponentManager<Tree>) -> Option<Entity> {
+        let overlay = ecm.entity_store().overlay?;
+
+        ecm.component_store()
+            .get::<u32>("drag_visual", overlay)
+            .ok()
+            .map(|drag_visual| Entity(*drag_visual))
+    }
+
+    // Moves the drag visual to the given position and updates its text and accepted state. `None` hides the visual.
+    fn update_drag_visual(
+        &self,
+        position: Point,
+        data: Option<(&DragData, bool)>,
+        theme: &Theme,
+        ecm: &mut EntityComponentManager<Tree>,
+    ) {
+        let drag_visual = match self.drag_visual(ecm) {
+            Some(drag_visual) => drag_visual,
+            None => return,
+        };
+
+        let mut widget = WidgetContainer::new(
+            drag_visual,
+            ecm,
+            theme,
+            Some(&self.context_provider.event_adapter),
+        );
+
+        if let Some((data, accepted)) = data {
+            widget.set("text", data.text().to_string());
+            widget.set("visibility", Visibility::Visible);
+
+            let mut bounds = *widget.get::<Rectangle>("bounds");
+            bounds.set_x(position.x() + DRAG_VISUAL_OFFSET);
+            bounds.set_y(position.y() + DRAG_VISUAL_OFFSET);
+            widget.set("bounds", bounds);
+
+            if accepted != *widget.get::<bool>("accepted") {
+                if accepted {
+                    set_flag("accepted", &mut widget);
+                } else {
+                    remove_flag("accepted", &mut widget);
+                }
+            }
+        } else {
+            widget.set("visibility", Visibility::Collapsed);
+            remove_flag("accepted", &mut widget);
+        }
+    }
+
+    // Tracks drag and drop operations. Returns `true` if the event is consumed by a running operation.
+    fn process_drag_drop(&self, event: &EventBox, ecm: &mut EntityComponentManager<Tree>) -> bool {
+        if let Ok(event) = event.downcast_ref::<MouseDownEvent>() {
+            if event.button == MouseButton::Left {
+                self.drag_drop.borrow_mut().start = Some(event.position);
+            }
+            return false;
+        }
+
+        if let Ok(event) = event.downcast_ref::<MouseMoveEvent>() {
+            return self.drag_move(event.position, ecm);
+        }
+
+        if let Ok(event) = event.downcast_ref::<MouseUpEvent>() {
+            if event.button == MouseButton::Left {
+                self.drop_data(event.position, ecm);
+            }
+            return false;
+        }
+
+        // the button could be released outside of the widgets or the window
+        if let Ok(event) = event.downcast_ref::<GlobalMouseUpEvent>() {
+            if event.button == MouseButton::Left {
+                self.drag_drop.borrow_mut().start = None;
+            }
+            return false;
+        }
+
+        // cancels a running operation, the data is not dropped on the current target
+        if let Ok(WindowEvent::ActiveChanged(false)) = event.downcast_ref::<WindowEvent>() {
+            self.drag_drop.borrow_mut().accepted = false;
+            self.drop_data(self.context_provider.mouse_position.get(), ecm);
+        }
+
+        false
+    }
+
+    // Starts a drag operation if the mouse is moved far enough with pressed left button and moves the dragged data.
+    fn drag_move(&self, position: Point, ecm: &mut EntityComponentManager<Tree>) -> bool {
+        let root = ecm.entity_store().root();
+        let theme = ecm
+            .component_store()
+            .get::<Rc<Theme>>("theme", root)
+            .unwrap()
+            .clone();
+
+        if self.drag_drop.borrow().data.is_none() {
+            let start = match self.drag_drop.borrow().start {
+                Some(start) => start,
+                None => return false,
+            };
+
+            if start.distance(position) < DRAG_THRESHOLD {
+                return false;
+            }
+
+            // only one drag operation per mouse down
+            self.drag_drop.borrow_mut().start = None;
+
+            let source = match self.hit_test(
+                start,
+                &EventBox::new(DragStartEvent::new(start), EventStrategy::Direct, root),
+                &theme,
+                ecm,
+            ) {
+                Some(source) => source,
+                None => return false,
+            };
+
+            let event = EventBox::new(DragStartEvent::new(start), EventStrategy::Direct, source);
+
+            if !self.dispatch_to(source, &event, ecm) {
+                return false;
+            }
+
+            let data = match event
+                .downcast_ref::<DragStartEvent>()
+                .ok()
+                .and_then(|event| event.take_data())
+            {
+                Some(data) => data,
+                None => return false,
+            };
+
+            self.drag_drop.borrow_mut().data = Some(data);
+        }
+
+        let data = match self.drag_drop.borrow().data.clone() {
+            Some(data) => data,
+            None => return false,
+        };
+
+        let target = self.hit_test(
+            position,
+            &EventBox::new(
+                DragOverEvent {
+                    data: data.clone(),
+                    position,
+                },
+                EventStrategy::Direct,
+                root,
+            ),
+            &theme,
+            ecm,
+        );
+
+        let old_target = self.drag_drop.borrow().target;
+
+        if old_target != target {
+            if let Some(old_target) = old_target {
+                self.dispatch_to(
+                    old_target,
+                    &EventBox::new(
+                        DragLeaveEvent {
+                            data: data.clone(),
+                            position,
+                        },
+                        EventStrategy::Direct,
+                        old_target,
+                    ),
+                    ecm,
+                );
+            }
+
+            if let Some(target) = target {
+                self.dispatch_to(
+                    target,
+                    &EventBox::new(
+                        DragEnterEvent {
+                            data: data.clone(),
+                            position,
+                        },
+                        EventStrategy::Direct,
+                        target,
+                    ),
+                    ecm,
+                );
+            }
+        }
+
+        let accepted = target.map_or(false, |target| {
+            self.dispatch_to(
+                target,
+                &EventBox::new(
+                    DragOverEvent {
+                        data: data.clone(),
+                        position,
+                    },
+                    EventStrategy::Direct,
+                    target,
+                ),
+                ecm,
+            )
+        });
+
+        {
+            let mut drag_drop = self.drag_drop.borrow_mut();
+            drag_drop.target = target;
+            drag_drop.accepted = accepted;
+        }
+
+        self.update_drag_visual(position, Some((&data, accepted)), &theme, ecm);
+
+        true
+    }
+
+    // Drops the dragged data on the current target and finishes the drag operation.
+    fn drop_data(&self, position: Point, ecm: &mut EntityComponentManager<Tree>) {
+        let drag_drop = self.drag_drop.replace(DragDropState::default());
+
+        let data = match drag_drop.data {
+            Some(data) => data,
+            None => return,
+        };
+
+        let mut accepted = false;
+
+        if let Some(target) = drag_drop.target {
+            if drag_drop.accepted {
+                accepted = self.dispatch_to(
+                    target,
+                    &EventBox::new(
+                        DragDropEvent {
+                            data: data.clone(),
+                            position,
+                        },
+                        EventStrategy::Direct,
+                        target,
+                    ),
+                    ecm,
+                );
+            } else {
+                self.dispatch_to(
+                    target,
+                    &EventBox::new(
+                        DragLeaveEvent {
+                            data: data.clone(),
+                            position,
+                        },
+                        EventStrategy::Direct,
+                        target,
+                    ),
+                    ecm,
+                );
+            }
+        }
+
+        let source = data.source();
+        self.dispatch_to(
+            source,
+            &EventBox::new(
+                DragEndEvent { data, accepted },
+                EventStrategy::Direct,
+                source,
+            ),
+            ecm,
+        );
+
+        let root = ecm.entity_store().root();
+        let theme = ecm
+            .component_store()
+            .get::<Rc<Theme>>("theme", root)
+            .unwrap()
+            .clone();
+        self.update_drag_visual(position, None, &theme, ecm);
+    }
+
+    // Returns the tooltip visual of the overlay.
+    fn tooltip_visual(&self, ecm: &mut EntityComponentManager<Tree>) -> Option<Entity> {
+        let overlay = ecm.entity_store().overlay?;
+
+        ecm.component_store()
+            .get::<u32>("tooltip_visual", overlay)
+            .ok()
+            .map(|tooltip_visual| Entity(*tooltip_visual))
+    }
+
+    // Returns the hovered widget or its nearest parent with a tooltip.
+    fn tooltip_target(&self, ecm: &mut EntityComponentManager<Tree>) -> Option<Entity> {
+        let hovered = *self.hovered_widgets.borrow().last()?;
+
+        with_parents(hovered, ecm).into_iter().find(|node| {
+            ecm.component_store()
+                .get::<TooltipContent>("tooltip", *node)
+                .is_ok()
+        })
+    }
+
+    // Tracks the position of the mouse for the tooltip. The tooltip is hidden if a mouse button
+    // or key is pressed or the mouse wheel is used.
+    fn process_tooltip(
+        &self,
+        event: &EventBox,
+        ecm: &mut EntityComponentManager<Tree>,
+        render_context: &mut RenderContext2D,
+    ) {
+        if let Ok(event) = event.downcast_ref::<MouseMoveEvent>() {
+            let mut tooltip = self.tooltip.borrow_mut();
+
+            if !tooltip.open {
+                tooltip.position = event.position;
+            }
+
+            return;
+        }
+
+        if event.is_type::<MouseDownEvent>()
+            || event.is_type::<ScrollEvent>()
+            || event.is_type::<KeyDownEvent>()
+            || matches!(
+                event.downcast_ref::<WindowEvent>(),
+                Ok(WindowEvent::ActiveChanged(false))
+            )
+        {
+            // the tooltip is shown again after the mouse has left the widget
+            self.tooltip.borrow_mut().hover_start = None;
+            self.hide_tooltip(ecm, render_context);
+        }
+    }
+
+    // Shows the tooltip of the widget under the mouse if the mouse rests long enough on it.
+    // The tooltip is hidden if the mouse leaves the widget.
+    fn update_tooltip(
+        &self,
+        ecm: &mut EntityComponentManager<Tree>,
+        render_context: &mut RenderContext2D,
+    ) {
+        let target = self.tooltip_target(ecm);
+
+        if target != self.tooltip.borrow().target {
+            self.hide_tooltip(ecm, render_context);
+
+            let mut tooltip = self.tooltip.borrow_mut();
+            tooltip.target = target;
+            tooltip.hover_start = target.map(|_| self.context_provider.now());
+        }
+
+        let (target, position, hover_start) = {
+            let tooltip = self.tooltip.borrow();
+
+            match (tooltip.target, tooltip.hover_start) {
+                (Some(target), Some(hover_start)) if !tooltip.open => {
+                    (target, tooltip.position, hover_start)
+                }
+                _ => return,
+            }
+        };
+
+        if !ecm.entity_store().parent.contains_key(&target) {
+            self.tooltip.replace(TooltipState::default());
+            return;
+        }
+
+        let root = ecm.entity_store().root();
+
+        let delay = ecm
+            .component_store()
+            .get::<u32>("tooltip_delay", target)
+            .or_else(|_| ecm.component_store().get::<u32>("tooltip_delay", root))
+            .map_or(DEFAULT_TOOLTIP_DELAY, |delay| *delay);
+
+        if self.context_provider.now().duration_since(hover_start)
+            < Duration::from_millis(delay as u64)
+        {
+            // keeps the window updating until the delay is elapsed
+            let _ = self
+                .context_provider
+                .window_sender
+                .send(WindowRequest::Redraw);
+            return;
+        }
+
+        let tooltip_visual = match self.tooltip_visual(ecm) {
+            Some(tooltip_visual) => tooltip_visual,
+            None => return,
+        };
+
+        let theme = ecm
+            .component_store()
+            .get::<Rc<Theme>>("theme", root)
+            .unwrap()
+            .clone();
+
+        let content = ecm
+            .component_store()
+            .get::<TooltipContent>("tooltip", target)
+            .cloned()
+            .unwrap_or_default();
+
+        let mut custom_content = None;
+
+        let text = match content {
+            TooltipContent::Text(text) => text,
+            TooltipContent::Builder(builder) => {
+                let mut ctx = Context::new(
+                    (tooltip_visual, ecm),
+                    &theme,
+                    &self.context_provider,
+                    render_context,
+                );
+
+                {
+                    let build_context = &mut ctx.build_context();
+                    let child = builder(build_context);
+                    build_context.append_child(tooltip_visual, child);
+                    custom_content = Some(child);
+                }
+
+                let keys = ctx.new_states_keys();
+                drop(ctx);
+
+                for key in keys {
+                    let mut ctx =
+                        Context::new((key, ecm), &theme, &self.context_provider, render_context);
+
+                    if let Some(state) = self.context_provider.states.borrow_mut().get_mut(&key) {
+                        state.init(&mut self.registry.borrow_mut(), &mut ctx);
+                    }
+                }
+
+                String::new()
+            }
+        };
+
+        // the first child of the tooltip visual displays the text
+        let text_block = ecm.entity_store().children[&tooltip_visual][0];
+        let text_visibility = if text.is_empty() {
+            Visibility::Collapsed
+        } else {
+            Visibility::Visible
+        };
+
+        WidgetContainer::new(
+            text_block,
+            ecm,
+            &theme,
+            Some(&self.context_provider.event_adapter),
+        )
+        .set("visibility", text_visibility);
+
+        // places the tooltip above the mouse in the lower half of the window
+        let window_height = ecm
+            .component_store()
+            .get::<Rectangle>("bounds", root)
+            .map_or(0.0, |bounds| bounds.height());
+        let placement = if position.y() > window_height / 2.0 {
+            Placement::Top
+        } else {
+            Placement::Bottom
+        };
+
+        let mut widget = WidgetContainer::new(
+            tooltip_visual,
+            ecm,
+            &theme,
+            Some(&self.context_provider.event_adapter),
+        );
+
+        widget.set("text", text);
+        widget.set("placement", placement);
+        widget.set("target", PopupTarget::Point(position));
+        widget.set("visibility", Visibility::Visible);
+
+        let mut tooltip = self.tooltip.borrow_mut();
+        tooltip.open = true;
+        tooltip.content = custom_content;
+    }
+
+    // Hides the tooltip and removes its custom content.
+    fn hide_tooltip(
+        &self,
+        ecm: &mut EntityComponentManager<Tree>,
+        render_context: &mut RenderContext2D,
+    ) {
+        let content = {
+            let mut tooltip = self.tooltip.borrow_mut();
+
+            if !tooltip.open {
+                return;
+            }
+
+            tooltip.open = false;
+            tooltip.content.take()
+        };
+
+        let tooltip_visual = match self.tooltip_visual(ecm) {
+            Some(tooltip_visual) => tooltip_visual,
+            None => return,
+        };
+
+        let root = ecm.entity_store().root();
+        let theme = ecm
+            .component_store()
+            .get::<Rc<Theme>>("theme", root)
+            .unwrap()
+            .clone();
+
+        if let Some(content) = content {
+            if let Some(children) = ecm.entity_store_mut().children.get_mut(&tooltip_visual) {
+                children.retain(|child| *child != content);
+            }
+
+            invalidate_layout(tooltip_visual, LayoutInvalidation::Measure, ecm);
+
+            self.remove_widget_tree(content, &theme, ecm, render_context);
+        }
+
+        WidgetContainer::new(
+            tooltip_visual,
+            ecm,
+            &theme,
+            Some(&self.context_provider.event_adapter),
+        )
+        .set("visibility", Visibility::Collapsed);
+    }
+
+    // Releases the mouse capture and notifies the widget that has lost it.
+    fn lose_mouse_capture(&self) {
+        if let Some(capture) = self.context_provider.mouse_capture.take() {
+            self.context_provider
+                .event_adapter
+                .push_event_direct(capture, LostMouseCaptureEvent);
+        }
+    }
+
+    // Sends mouse events to the widget that has captured the mouse. The event is routed from the root to the
+    // capturing widget (tunneling) and back (bubbling). Returns `true` if the event is consumed.
+    fn process_mouse_capture(
+        &self,
+        event: &EventBox,
+        ecm: &mut EntityComponentManager<Tree>,
+    ) -> bool {
+        if let Ok(WindowEvent::ActiveChanged(false)) = event.downcast_ref::<WindowEvent>() {
+            self.lose_mouse_capture();
+            return false;
+        }
+
+        let capture = match self.context_provider.mouse_capture.get() {
+            Some(capture) => capture,
+            None => return false,
+        };
+
+        if !event.is_type::<MouseMoveEvent>()
+            && !event.is_type::<MouseDownEvent>()
+            && !event.is_type::<MouseUpEvent>()
+            && !event.is_type::<ScrollEvent>()
+        {
+            return false;
+        }
+
+        // a disabled or hidden widget could not keep the capture
+        if !ecm.entity_store().parent.contains_key(&capture) || !is_reachable(capture, ecm) {
+            self.lose_mouse_capture();
+            return false;
+        }
+
+        // the hover state follows the mouse also if it is captured
+        if let Ok(event) = event.downcast_ref::<MouseMoveEvent>() {
+            let root = ecm.entity_store().root();
+            let theme = ecm
+                .component_store()
+                .get::<Rc<Theme>>("theme", root)
+                .unwrap()
+                .clone();
+
+            let hit = self.widget_at(event.position, &theme, ecm);
+            self.update_hover(hit, event.position, ecm, &theme);
+        }
+
+        let mut route = vec![capture];
+        let mut current = capture;
+
+        while let Some(parent) = ecm.entity_store().parent.get(&current).and_then(|p| *p) {
+            route.push(parent);
+            current = parent;
+        }
+
+        // tunneling phase
+        for node in route.iter().rev() {
+            if self.dispatch_phase(*node, event, true, ecm) {
+                return true;
+            }
+        }
+
+        // bubbling phase
+        for node in route.iter() {
+            if self.dispatch_phase(*node, event, false, ecm) {
+                break;
+            }
+        }
+
+        true
+    }
+
+    // Raises a `ContextMenuEvent` if the right mouse button is released or the menu key or Shift+F10 is pressed
+    // and bubbles it from the widget under the mouse (or the focused widget) up to the window until it is
+    // handled. Returns `true` if the event is consumed.
+    fn process_context_menu(
+        &self,
+        event: &EventBox,
+        ecm: &mut EntityComponentManager<Tree>,
+    ) -> bool {
+        let root = ecm.entity_store().root();
+
+        if let Ok(event) = event.downcast_ref::<MouseUpEvent>() {
+            if event.button == MouseButton::Right {
+                self.context_provider.event_adapter.push_event_direct(
+                    root,
+                    ContextMenuEvent {
+                        position: event.position,
+                        source: ContextMenuSource::Mouse,
+                    },
+                );
+            }
+
+            return false;
+        }
+
+        if let Ok(event) = event.downcast_ref::<KeyDownEvent>() {
+            let shift = ecm
+                .component_store()
+                .get::<KeyboardState>("keyboard_state", root)
+                .map_or(false, |keyboard_state| keyboard_state.is_shift_down());
+
+            if event.event.key != Key::Menu && !(event.event.key == Key::F10 && shift) {
+                return false;
+            }
+
+            let focused = self.focused_entity(ecm);
+            let bounds = ecm
+                .component_store()
+                .get::<Rectangle>("bounds", focused)
+                .map_or(Rectangle::default(), |bounds| *bounds);
+            let mut position = ecm
+                .component_store()
+                .get::<Point>("position", focused)
+                .map_or(Point::default(), |position| *position);
+            position.set_x(position.x() + bounds.width() / 2.0);
+            position.set_y(position.y() + bounds.height() / 2.0);
+
+            self.context_provider.event_adapter.push_event_direct(
+                root,
+                ContextMenuEvent {
+                    position,
+                    source: ContextMenuSource::Keyboard,
+                },
+            );
+
+            return true;
+        }
+
+        let context_menu = match event.downcast_ref::<ContextMenuEvent>() {
+            Ok(context_menu) => context_menu.clone(),
+            Err(_) => return false,
+        };
+
+        let current = match context_menu.source {
+            ContextMenuSource::Mouse => {
+                let theme = ecm
+                    .component_store()
+                    .get::<Rc<Theme>>("theme", root)
+                    .unwrap()
+                    .clone();
+                let overlay = ecm.entity_store().overlay;
+                let nodes: Vec<Entity> = ecm.entity_store().start_node(root).into_iter().collect();
+
+                // the overlay covers the whole window, only its children are hit
+                nodes
+                    .into_iter()
+                    .rev()
+                    .filter(|node| Some(*node) != overlay)
+                    .find(|node| self.is_hit(*node, context_menu.position, &theme, ecm))
+                    .unwrap_or(root)
+            }
+            ContextMenuSource::Keyboard => self.focused_entity(ecm),
+        };
+
+        let event = EventBox::new(context_menu, EventStrategy::Direct, current);
+        self.bubble_direct(current, &event, ecm);
+
+        true
+    }
+
+    // Sends the event direct to the given widget and bubbles it up to the window until it is
+    // handled. Returns the widget that has handled the event.
+    fn bubble_direct(
+        &self,
+        entity: Entity,
+        event: &EventBox,
+        ecm: &mut EntityComponentManager<Tree>,
+    ) -> Option<Entity> {
+        with_parents(entity, ecm)
+            .into_iter()
+            .find(|node| is_reachable(*node, ecm) && self.dispatch_to(*node, event, ecm))
+    }
+
+    // Returns the focused widget or the window if no widget is focused.
+    fn focused_entity(&self, ecm: &mut EntityComponentManager<Tree>) -> Entity {
+        let root = ecm.entity_store().root();
+
+        ecm.component_store()
+            .get::<FocusState>("focus_state", root)
+            .ok()
+            .and_then(|focus_state| *focus_state.focused_entity())
+            .unwrap_or(root)
+    }
+
+    // Toggles the layout debugger if its toggle key is pressed. Returns `true` if the key event is
+    // consumed.
+    fn process_layout_debugger(&self, event: &EventBox) -> bool {
+        let key = match event.downcast_ref::<KeyDownEvent>() {
+            Ok(event) => event.event.key,
+            Err(_) => return false,
+        };
+
+        self.registry
+            .borrow_mut()
+            .try_get_mut::<LayoutDebugger>("layout_debugger")
+            .map_or(false, |debugger| debugger.handle_key(key))
+    }
+
+    // Processes the registered keyboard shortcuts. If a shortcut is completed, a `CommandEvent` is sent
+    // to the focused widget and bubbles up to the window. Returns `true` if the key event is consumed.
+    fn process_shortcut(&self, event: &EventBox, ecm: &mut EntityComponentManager<Tree>) -> bool {
+        let key = match event.downcast_ref::<KeyDownEvent>() {
+            Ok(event) => event.event.key,
+            Err(_) => return false,
+        };
+
+        let root = ecm.entity_store().root();
+
+        let stroke = match ecm
+            .component_store()
+            .get::<KeyboardState>("keyboard_state", root)
+            .ok()
+            .and_then(|keyboard_state| KeyStroke::from_key(key, keyboard_state))
+        {
+            Some(stroke) => stroke,
+            None => return false,
+        };
+
+        let shortcut_match = match self
+            .registry
+            .borrow_mut()
+            .try_get_mut::<Shortcuts>("shortcuts")
+        {
+            Some(shortcuts) => shortcuts.process(stroke),
+            None => return false,
+        };
+
+        let command = match shortcut_match {
+            ShortcutMatch::None => return false,
+            ShortcutMatch::Pending => return true,
+            ShortcutMatch::Command(command) => command,
+        };
+
+        let mut current = self.focused_entity(ecm);
+
+        loop {
+            let command_event = EventBox::new(
+                CommandEvent {
+                    command: command.clone(),
+                },
+                EventStrategy::Direct,
+                current,
+            );
+
+            let enabled = ecm
+                .component_store()
+                .get::<bool>("enabled", current)
+                .map_or(true, |enabled| *enabled);
+
+            if enabled && self.dispatch_to(current, &command_event, ecm) {
+                break;
+            }
+
+            match ecm.entity_store().parent.get(&current).and_then(|p| *p) {
+                Some(parent) => current = parent,
+                None => break,
+            }
+        }
+
+        true
+    }
+
+    // Sends a close request top-down to all widgets of the window until a handler vetoes it.
+    fn process_close_request(
+        &self,
+        event: &EventBox,
+        ecm: &mut EntityComponentManager<Tree>,
+    ) -> bool {
+        let root = ecm.entity_store().root();
+        let nodes: Vec<Entity> = ecm.entity_store().start_node(root).into_iter().collect();
+        let handled = nodes
+            .into_iter()
+            .any(|node| self.dispatch_to(node, event, ecm));
 
-            drop(ctx);
+        self.confirm_close_request(event, handled);
+        handled
+    }
+
+    // Closes the window if a close request was not handled (vetoed) by a widget.
+    fn confirm_close_request(&self, event: &EventBox, handled: bool) {
+        if handled {
+            return;
         }
-        self.context_provider.states.borrow_mut().remove(&entity);
 
-        ecm.remove_entity(entity);
-        self.context_provider.layouts.borrow_mut().remove(&entity);
-        self.context_provider
-            .render_objects
-            .borrow_mut()
-            .remove(&entity);
-        self.context_provider
-            .handler_map
-            .borrow_mut()
-            .remove(&entity);
+        if let Ok(WindowEvent::CloseRequested) = event.downcast_ref::<WindowEvent>() {
+            let _ = self
+                .context_provider
+                .window_sender
+                .send(WindowRequest::Close);
+        }
     }
 
     fn process_direct(&self, event: &EventBox, ecm: &mut EntityComponentManager<Tree>) -> bool {
         // skip all direct events on first run
         if self.context_provider.first_run.get() {
+            self.confirm_close_request(event, false);
             return false;
         }
 
-        let message_adapter = self.context_provider.message_adapter.clone();
+        if let Ok(WindowEvent::CloseRequested) = event.downcast_ref::<WindowEvent>() {
+            self.process_close_request(event, ecm);
+            return true;
+        }
 
-        if event.strategy == EventStrategy::Direct {
-            if let Some(handlers) = self
+        if event.strategy == EventStrategy::Direct
+            && self
                 .context_provider
                 .handler_map
                 .borrow()
-                .get(&event.source)
-            {
-                handlers.iter().any(|handler| {
+                .contains_key(&event.source)
+        {
+            self.dispatch_to(event.source, event, ecm);
+            return true;
+        }
+
+        false
+    }
+
+    // Records a step of the current event in the event tracer of the window.
+    #[cfg(feature = "debug")]
+    fn trace<F: FnOnce(&mut EventTracer)>(&self, record: F) {
+        if let Ok(mut registry) = self.registry.try_borrow_mut() {
+            if let Some(tracer) = registry.try_get_mut::<EventTracer>("event_tracer") {
+                record(tracer);
+            }
+        }
+    }
+
+    // Checks if the widget has a handler for the event.
+    #[cfg(feature = "debug")]
+    fn has_handler(&self, node: Entity, event: &EventBox) -> bool {
+        self.context_provider
+            .handler_map
+            .borrow()
+            .get(&node)
+            .map_or(false, |handlers| {
+                handlers.iter().any(|handler| handler.handles_event(event))
+            })
+    }
+
+    // Calls the preview (tunneling phase) or the regular (bubbling phase) handlers of the given node.
+    // Returns `true` if one of the handlers marks the event as handled.
+    fn dispatch_phase(
+        &self,
+        node: Entity,
+        event: &EventBox,
+        preview: bool,
+        ecm: &mut EntityComponentManager<Tree>,
+    ) -> bool {
+        let message_adapter = self.context_provider.message_adapter.clone();
+
+        if let Some(handlers) = self.context_provider.handler_map.borrow().get(&node) {
+            let handled = handlers
+                .iter()
+                .filter(|handler| handler.is_preview() == preview)
+                .any(|handler| {
                     handler.handle_event(
                         &mut StatesContext::new(
                             &mut *self.context_provider.states.borrow_mut(),
@@ -134,14 +1289,28 @@ impl EventStateSystem {
                         event,
                     )
                 });
-                return true;
-            }
+
+            #[cfg(feature = "debug")]
+            self.trace(|tracer| {
+                let phase = if preview {
+                    EventPhase::Tunneling
+                } else {
+                    EventPhase::Bubbling
+                };
+                tracer.call(node, phase, handled)
+            });
+
+            return handled;
         }
 
         false
     }
 
-    fn process_bottom_up_event(
+    // Routes the event through the matching nodes of the tree. Preview handlers are called in the
+    // tunneling phase from the root to the target, followed by the bubbling phase from the target back
+    // to the root (`BottomUp`) or from the root to the target (`TopDown`). Routing stops as soon as a
+    // handler of either phase marks the event as handled.
+    fn process_routed_event(
         &self,
         mouse_position: Point,
         event: &EventBox,
@@ -153,7 +1322,6 @@ impl EventStateSystem {
         let mut current_node = event.source;
         let root = ecm.entity_store().root();
         let mut disabled_parents = vec![];
-        let message_adapter = self.context_provider.message_adapter.clone();
 
         let theme = ecm
             .component_store()
@@ -185,6 +1353,9 @@ impl EventStateSystem {
         let mut unknown_event = true;
         let mut clipped_parent = vec![];
 
+        // the top most widget under the mouse
+        let mut hit = None;
+
         loop {
             if !disabled_parents.is_empty() {
                 if let Some(parent) = ecm.entity_store().parent[&current_node] {
@@ -287,24 +1458,55 @@ impl EventStateSystem {
                             &theme,
                             Some(&self.context_provider.event_adapter),
                         ),
+                    ) {
+                        // todo: improve check path if exists
+                        let clipped = clipped_parent.get(0).map_or(false, |op| {
+                            !check_mouse_condition(
+                                event.position,
+                                &WidgetContainer::new(
+                                    *op,
+                                    ecm,
+                                    &theme,
+                                    Some(&self.context_provider.event_adapter),
+                                ),
+                            )
+                        });
+
+                        if !clipped {
+                            hit = Some(current_node);
+                        }
+                        if !clipped || !has_handler {
+                            matching_nodes.push(current_node);
+                        }
+                    }
+                    unknown_event = false;
+                }
+                // pointer handling
+                if let Some(position) = pointer_position(event) {
+                    if check_mouse_condition(
+                        position,
+                        &WidgetContainer::new(
+                            current_node,
+                            ecm,
+                            &theme,
+                            Some(&self.context_provider.event_adapter),
+                        ),
                     ) {
                         let mut add = true;
                         if let Some(op) = clipped_parent.get(0) {
-                            // todo: improve check path if exists
                             if !check_mouse_condition(
-                                event.position,
+                                position,
                                 &WidgetContainer::new(
                                     *op,
                                     ecm,
                                     &theme,
                                     Some(&self.context_provider.event_adapter),
                                 ),
-                            ) && has_handler
-                            {
+                            ) {
                                 add = false;
                             }
                         }
-                        if add {
+                        if add && has_handler {
                             matching_nodes.push(current_node);
                         }
                     }
@@ -321,57 +1523,20 @@ impl EventStateSystem {
                             Some(&self.context_provider.event_adapter),
                         ),
                     ) {
-                        // trigger mouse enter event if mouse cursor is first time over the current_node
-                        if !self.hovered_widgets.borrow().contains(&current_node) {
-                            // remove hover flag from last hovered node
-                            if ecm
-                                .component_store()
-                                .get::<bool>("hover", current_node)
-                                .is_ok()
-                                && !self.hovered_widgets.borrow().is_empty()
-                            {
-                                let last = *self.hovered_widgets.borrow().last().unwrap();
-
-                                remove_flag(
-                                    "hover",
-                                    &mut WidgetContainer::new(
-                                        last,
-                                        ecm,
-                                        &theme,
-                                        Some(&self.context_provider.event_adapter),
-                                    ),
-                                );
-
-                                self.remove_hover(last, ecm, &theme);
-                            }
-
-                            // set hover flag to now hovered node
-                            if ecm
-                                .component_store()
-                                .get::<bool>("hover", current_node)
-                                .is_ok()
-                            {
-                                set_flag(
-                                    "hover",
-                                    &mut WidgetContainer::new(
-                                        current_node,
-                                        ecm,
-                                        &theme,
-                                        Some(&self.context_provider.event_adapter),
-                                    ),
-                                );
-
-                                self.hovered_widgets.borrow_mut().push(current_node);
-                            }
+                        let clipped = clipped_parent.get(0).map_or(false, |op| {
+                            !check_mouse_condition(
+                                event.position,
+                                &WidgetContainer::new(
+                                    *op,
+                                    ecm,
+                                    &theme,
+                                    Some(&self.context_provider.event_adapter),
+                                ),
+                            )
+                        });
 
-                            if self.handles_enter_event(current_node) {
-                                self.context_provider.event_adapter.push_event_direct(
-                                    current_node,
-                                    EnterEvent {
-                                        position: event.position,
-                                    },
-                                );
-                            }
+                        if !clipped {
+                            hit = Some(current_node);
                         }
 
                         // todo add check to block mouse move inside of clipped areas of a widget
@@ -379,19 +1544,6 @@ impl EventStateSystem {
                             matching_nodes.push(current_node);
                         }
                     }
-                    // trigger mouse leave event when cursor leaves the current_node
-                    else if self.hovered_widgets.borrow().contains(&current_node) {
-                        if self.handles_enter_leave(current_node) {
-                            self.context_provider.event_adapter.push_event_direct(
-                                current_node,
-                                LeaveEvent {
-                                    position: event.position,
-                                },
-                            );
-                        }
-
-                        self.remove_hover(current_node, ecm, &theme);
-                    }
 
                     unknown_event = false;
                 }
@@ -408,6 +1560,28 @@ impl EventStateSystem {
                 {
                     matching_nodes.push(current_node);
                 }
+
+                #[cfg(feature = "debug")]
+                {
+                    if has_handler && matching_nodes.last() != Some(&current_node) {
+                        let position = routed_position(event, mouse_position);
+                        let reason = if check_mouse_condition(
+                            position,
+                            &WidgetContainer::new(
+                                current_node,
+                                ecm,
+                                &theme,
+                                Some(&self.context_provider.event_adapter),
+                            ),
+                        ) {
+                            SkipReason::Clipped
+                        } else {
+                            SkipReason::Missed
+                        };
+                        self.trace(|tracer| tracer.skip(current_node, reason));
+                    }
+                }
+
                 if let Ok(clip) = ecm.component_store().get::<bool>("clip", current_node) {
                     if *clip {
                         clipped_parent.clear();
@@ -416,6 +1590,22 @@ impl EventStateSystem {
                 }
             }
 
+            #[cfg(feature = "debug")]
+            {
+                if !disabled_parents.is_empty() && self.has_handler(current_node, event) {
+                    let enabled = *ecm
+                        .component_store()
+                        .get::<bool>("enabled", disabled_parents[0])
+                        .unwrap_or(&true);
+                    let reason = if enabled {
+                        SkipReason::Hidden
+                    } else {
+                        SkipReason::Disabled
+                    };
+                    self.trace(|tracer| tracer.skip(current_node, reason));
+                }
+            }
+
             let mut it = ecm.entity_store().start_node(current_node).into_iter();
             it.next();
 
@@ -426,25 +1616,38 @@ impl EventStateSystem {
             }
         }
 
-        let mut handled = false;
+        if !matching_nodes.is_empty() {
+            update = true;
+        }
 
-        for node in matching_nodes.iter().rev() {
-            if let Some(handlers) = self.context_provider.handler_map.borrow().get(node) {
-                handled = handlers.iter().any(|handler| {
-                    handler.handle_event(
-                        &mut StatesContext::new(
-                            &mut *self.context_provider.states.borrow_mut(),
-                            ecm,
-                            &message_adapter,
-                        ),
-                        event,
-                    )
-                });
+        // updates the hover and pressed states of the widget under the mouse and its parents
+        if let Ok(event) = event.downcast_ref::<MouseMoveEvent>() {
+            self.update_hover(hit, event.position, ecm, &theme);
+        }
+
+        if let Ok(event) = event.downcast_ref::<MouseDownEvent>() {
+            if let (MouseButton::Left, Some(hit)) = (event.button, hit) {
+                self.press(hit, ecm, &theme);
+            }
+        }
 
-                update = true;
+        #[cfg(feature = "debug")]
+        self.trace(|tracer| matching_nodes.iter().for_each(|node| tracer.route(*node)));
+
+        // tunneling phase
+        for node in matching_nodes.iter() {
+            if self.dispatch_phase(*node, event, true, ecm) {
+                return update;
             }
+        }
+
+        // bubbling phase
+        if event.strategy == EventStrategy::TopDown {
+            matching_nodes.reverse();
+        }
 
-            if handled {
+        for node in matching_nodes.iter().rev() {
+            if self.dispatch_phase(*node, event, false, ecm) {
                 break;
             }
         }
@@ -453,6 +1656,83 @@ impl EventStateSystem {
     }
 }
 
+// Returns the given widget and its parents ordered from the widget to the root.
+fn with_parents(entity: Entity, ecm: &mut EntityComponentManager<Tree>) -> Vec<Entity> {
+    let mut widgets = vec![entity];
+    let mut current = entity;
+
+    while let Some(parent) = ecm.entity_store().parent.get(&current).and_then(|p| *p) {
+        widgets.push(parent);
+        current = parent;
+    }
+
+    widgets
+}
+
+// Checks if the widget and its parents are enabled and visible.
+fn is_reachable(entity: Entity, ecm: &mut EntityComponentManager<Tree>) -> bool {
+    let mut current = Some(entity);
+
+    while let Some(node) = current {
+        if !*ecm
+            .component_store()
+            .get::<bool>("enabled", node)
+            .unwrap_or(&true)
+        {
+            return false;
+        }
+
+        if let Ok(visibility) = ecm.component_store().get::<Visibility>("visibility", node) {
+            if *visibility != Visibility::Visible {
+                return false;
+            }
+        }
+
+        current = ecm.entity_store().parent.get(&node).cloned().flatten();
+    }
+
+    true
+}
+
+// Returns the position of touch and gesture events. These events are delivered to the widgets under the position.
+fn pointer_position(event: &EventBox) -> Option<Point> {
+    if let Ok(event) = event.downcast_ref::<TouchBeginEvent>() {
+        return Some(event.pointer.position);
+    }
+
+    if let Ok(event) = event.downcast_ref::<TouchMoveEvent>() {
+        return Some(event.pointer.position);
+    }
+
+    if let Ok(event) = event.downcast_ref::<TouchEndEvent>() {
+        return Some(event.pointer.position);
+    }
+
+    if let Ok(event) = event.downcast_ref::<GestureEvent>() {
+        return Some(event.position());
+    }
+
+    None
+}
+
+// Returns the position of a routed mouse or pointer event, otherwise the current mouse position.
+#[cfg(feature = "debug")]
+fn routed_position(event: &EventBox, mouse_position: Point) -> Point {
+    if let Ok(event) = event.downcast_ref::<ClickEvent>() {
+        return event.position;
+    }
+
+    if let Ok(event) = event.downcast_ref::<MouseDownEvent>() {
+        return event.position;
+    }
+
+    if let Ok(event) = event.downcast_ref::<MouseMoveEvent>() {
+        return event.position;
+    }
+
+    pointer_position(event).unwrap_or(mouse_position)
+}
+
 impl System<Tree, RenderContext2D> for EventStateSystem {
     fn run_with_context(
         &self,
@@ -461,6 +1741,8 @@ impl System<Tree, RenderContext2D> for EventStateSystem {
     ) {
         let mut update = false;
 
+        self.context_provider.event_adapter.start_frame();
+
         loop {
             {
                 let mouse_position = self.context_provider.mouse_position.get();
@@ -474,24 +1756,57 @@ impl System<Tree, RenderContext2D> for EventStateSystem {
                         }
                     }
 
+                    #[cfg(feature = "debug")]
+                    self.trace(|tracer| tracer.begin(&event));
+
+                    self.process_tooltip(&event, ecm, render_context);
+                    self.process_pressed(&event, ecm);
+
+                    if self.process_drag_drop(&event, ecm) {
+                        update = true;
+                        continue;
+                    }
+
+                    if self.process_context_menu(&event, ecm) {
+                        update = true;
+                        continue;
+                    }
+
+                    if self.process_mouse_capture(&event, ecm) {
+                        update = true;
+                        continue;
+                    }
+
+                    if self.process_layout_debugger(&event) {
+                        update = true;
+                        continue;
+                    }
+
+                    if self.process_shortcut(&event, ecm) {
+                        update = true;
+                        continue;
+                    }
+
                     match event.strategy {
                         EventStrategy::Direct => {
                             if event.strategy == EventStrategy::Direct {
                                 update = self.process_direct(&event, ecm) || update;
                             }
                         }
-                        // EventStrategy::TopDown => {
-                        //     self.process_top_down_event(&event, ecm);
-                        // }
-                        EventStrategy::BottomUp => {
+                        EventStrategy::TopDown | EventStrategy::BottomUp => {
                             let should_update =
-                                self.process_bottom_up_event(mouse_position, &event, ecm);
+                                self.process_routed_event(mouse_position, &event, ecm);
                             update = update || should_update;
                         }
                     }
                 }
+
+                #[cfg(feature = "debug")]
+                self.trace(|tracer| tracer.end());
             }
 
+            self.update_tooltip(ecm, render_context);
+
             // handle states
 
             // crate::shell::CONSOLE.time("update-time:");
@@ -604,16 +1919,7 @@ impl System<Tree, RenderContext2D> for EventStateSystem {
                     }
 
                     while let Some(remove_widget) = remove_widget_list.pop() {
-                        let mut children = vec![];
-                        get_all_children(&mut children, remove_widget, ecm.entity_store());
-
-                        // remove children of target widget.
-                        for entity in children.iter().rev() {
-                            self.remove_widget(*entity, &theme, ecm, render_context);
-                        }
-
-                        // remove target widget
-                        self.remove_widget(remove_widget, &theme, ecm, render_context);
+                        self.remove_widget_tree(remove_widget, &theme, ecm, render_context);
                     }
                 }
 
@@ -622,9 +1928,353 @@ impl System<Tree, RenderContext2D> for EventStateSystem {
 
             // crate::shell::CONSOLE.time_end("update-time:");
 
-            if self.context_provider.event_adapter.is_empty() {
+            if self.context_provider.event_adapter.is_frame_done() {
                 break;
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use super::*;
+    use crate::shell::ShellRequest;
+
+    // Records the widgets it is called for and marks the events as handled if `handles` is set.
+    struct TestHandler {
+        entity: Entity,
+        handles: bool,
+        calls: Rc<RefCell<Vec<Entity>>>,
+    }
+
+    impl EventHandler for TestHandler {
+        fn handle_event(&self, _: &mut StatesContext, _: &EventBox) -> bool {
+            self.calls.borrow_mut().push(self.entity);
+            self.handles
+        }
+
+        fn handles_event(&self, _: &EventBox) -> bool {
+            true
+        }
+    }
+
+    // An event system with a window that contains a chain of widgets.
+    struct Harness {
+        system: EventStateSystem,
+        world: World<Tree, RenderContext2D>,
+        widgets: Vec<Entity>,
+        calls: Rc<RefCell<Vec<Entity>>>,
+        window_receiver: mpsc::Receiver<WindowRequest>,
+        _shell_receiver: mpsc::Receiver<ShellRequest<WindowAdapter>>,
+    }
+
+    impl Harness {
+        // Creates the window (`widgets[0]`) and the given number of widgets, each widget is the
+        // parent of the next one.
+        fn new(count: usize) -> Self {
+            let (window_sender, window_receiver) = mpsc::channel();
+            let (shell_sender, shell_receiver) = mpsc::channel();
+
+            let mut world: World<Tree, RenderContext2D> = World::from_entity_store(Tree::default());
+            let ecm = world.entity_component_manager();
+            let mut widgets: Vec<Entity> = vec![ecm.create_entity().build()];
+
+            for _ in 0..count {
+                let widget = ecm.create_entity().build();
+                ecm.entity_store_mut()
+                    .append_child(*widgets.last().unwrap(), widget)
+                    .unwrap();
+                widgets.push(widget);
+            }
+
+            Harness {
+                system: EventStateSystem::new(
+                    ContextProvider::new(window_sender, shell_sender, "", None),
+                    Rc::new(RefCell::new(Registry::new())),
+                    RefCell::new(vec![]),
+                    RefCell::new(vec![]),
+                    RefCell::new(DragDropState::default()),
+                    RefCell::new(TooltipState::default()),
+                ),
+                world,
+                widgets,
+                calls: Rc::new(RefCell::new(vec![])),
+                window_receiver,
+                _shell_receiver: shell_receiver,
+            }
+        }
+
+        fn handler(&self, entity: Entity, handles: bool) {
+            self.system
+                .context_provider
+                .handler_map
+                .borrow_mut()
+                .entry(entity)
+                .or_insert_with(Vec::new)
+                .push(Rc::new(TestHandler {
+                    entity,
+                    handles,
+                    calls: self.calls.clone(),
+                }));
+        }
+
+        fn calls(&self) -> Vec<Entity> {
+            self.calls.borrow().clone()
+        }
+
+        fn window_closed(&self) -> bool {
+            self.window_receiver
+                .try_iter()
+                .any(|request| matches!(request, WindowRequest::Close))
+        }
+    }
+
+    fn context_menu_event(source: Entity) -> EventBox {
+        EventBox::new(
+            ContextMenuEvent {
+                position: Point::default(),
+                source: ContextMenuSource::Keyboard,
+            },
+            EventStrategy::Direct,
+            source,
+        )
+    }
+
+    #[test]
+    fn test_bubble_direct_to_nearest_handler() {
+        let mut harness = Harness::new(3);
+        let (window, panel, child) = (harness.widgets[0], harness.widgets[1], harness.widgets[3]);
+
+        harness.handler(window, true);
+        harness.handler(panel, true);
+        harness.handler(child, false);
+
+        let handled = harness.system.bubble_direct(
+            child,
+            &context_menu_event(child),
+            harness.world.entity_component_manager(),
+        );
+
+        assert_eq!(handled, Some(panel));
+        assert_eq!(harness.calls(), vec![child, panel]);
+    }
+
+    #[test]
+    fn test_bubble_direct_unhandled() {
+        let mut harness = Harness::new(2);
+        let (window, child) = (harness.widgets[0], harness.widgets[2]);
+
+        harness.handler(window, false);
+
+        let handled = harness.system.bubble_direct(
+            child,
+            &context_menu_event(child),
+            harness.world.entity_component_manager(),
+        );
+
+        assert_eq!(handled, None);
+        assert_eq!(harness.calls(), vec![window]);
+    }
+
+    #[test]
+    fn test_bubble_direct_skips_hidden_widgets() {
+        let mut harness = Harness::new(2);
+        let (panel, child) = (harness.widgets[1], harness.widgets[2]);
+
+        harness.handler(panel, true);
+        harness.handler(child, true);
+        harness
+            .world
+            .entity_component_manager()
+            .component_store_mut()
+            .register("visibility", child, Visibility::Collapsed);
+
+        let handled = harness.system.bubble_direct(
+            child,
+            &context_menu_event(child),
+            harness.world.entity_component_manager(),
+        );
+
+        assert_eq!(handled, Some(panel));
+        assert_eq!(harness.calls(), vec![panel]);
+    }
+
+    fn close_requested_event(window: Entity) -> EventBox {
+        EventBox::new(WindowEvent::CloseRequested, EventStrategy::Direct, window)
+    }
+
+    #[test]
+    fn test_close_request_vetoed_by_child() {
+        let mut harness = Harness::new(2);
+        let (window, child) = (harness.widgets[0], harness.widgets[2]);
+
+        harness.handler(window, false);
+        harness.handler(child, true);
+
+        let handled = harness.system.process_close_request(
+            &close_requested_event(window),
+            harness.world.entity_component_manager(),
+        );
+
+        assert!(handled);
+        assert_eq!(harness.calls(), vec![window, child]);
+        assert!(!harness.window_closed());
+    }
+
+    #[test]
+    fn test_close_request_not_vetoed() {
+        let mut harness = Harness::new(2);
+        let (window, child) = (harness.widgets[0], harness.widgets[2]);
+
+        harness.handler(window, false);
+        harness.handler(child, false);
+
+        let handled = harness.system.process_close_request(
+            &close_requested_event(window),
+            harness.world.entity_component_manager(),
+        );
+
+        assert!(!handled);
+        assert_eq!(harness.calls(), vec![window, child]);
+        assert!(harness.window_closed());
+    }
+
+    #[test]
+    fn test_tooltip_target() {
+        let mut harness = Harness::new(3);
+        let (panel, child) = (harness.widgets[1], harness.widgets[3]);
+
+        assert_eq!(
+            harness
+                .system
+                .tooltip_target(harness.world.entity_component_manager()),
+            None
+        );
+
+        harness
+            .system
+            .hovered_widgets
+            .replace(harness.widgets.clone());
+        harness
+            .world
+            .entity_component_manager()
+            .component_store_mut()
+            .register("tooltip", panel, TooltipContent::Text("panel".to_string()));
+
+        assert_eq!(
+            harness
+                .system
+                .tooltip_target(harness.world.entity_component_manager()),
+            Some(panel)
+        );
+
+        harness
+            .world
+            .entity_component_manager()
+            .component_store_mut()
+            .register("tooltip", child, TooltipContent::Text("child".to_string()));
+
+        assert_eq!(
+            harness
+                .system
+                .tooltip_target(harness.world.entity_component_manager()),
+            Some(child)
+        );
+    }
+
+    // Places the widget at the given position with the given size.
+    fn place(harness: &mut Harness, widget: Entity, x: f64, y: f64, size: f64) {
+        let store = harness
+            .world
+            .entity_component_manager()
+            .component_store_mut();
+        store.register("enabled", widget, true);
+        store.register("position", widget, Point::new(x, y));
+        store.register("bounds", widget, Rectangle::new((0.0, 0.0), (size, size)));
+    }
+
+    fn mouse_move_event(window: Entity, x: f64, y: f64) -> EventBox {
+        EventBox::new(
+            MouseMoveEvent {
+                position: Point::new(x, y),
+            },
+            EventStrategy::BottomUp,
+            window,
+        )
+    }
+
+    #[test]
+    fn test_mouse_capture() {
+        let mut harness = Harness::new(2);
+        let (window, panel, child) = (harness.widgets[0], harness.widgets[1], harness.widgets[2]);
+
+        harness.handler(window, true);
+        harness.handler(panel, true);
+        harness.handler(child, false);
+
+        let event = mouse_move_event(window, 500.0, 500.0);
+
+        assert!(!harness
+            .system
+            .process_mouse_capture(&event, harness.world.entity_component_manager()));
+        assert!(harness.calls().is_empty());
+
+        // the captured widget and its parents receive the mouse also outside of their bounds
+        harness
+            .system
+            .context_provider
+            .mouse_capture
+            .set(Some(child));
+        harness
+            .world
+            .entity_component_manager()
+            .component_store_mut()
+            .register("theme", window, Rc::new(Theme::default()));
+
+        assert!(harness
+            .system
+            .process_mouse_capture(&event, harness.world.entity_component_manager()));
+        assert_eq!(harness.calls(), vec![child, panel]);
+    }
+
+    #[test]
+    fn test_mouse_capture_updates_hover() {
+        let mut harness = Harness::new(2);
+        let (window, panel, child) = (harness.widgets[0], harness.widgets[1], harness.widgets[2]);
+
+        place(&mut harness, window, 0.0, 0.0, 100.0);
+        place(&mut harness, panel, 0.0, 0.0, 50.0);
+        place(&mut harness, child, 0.0, 0.0, 10.0);
+        harness
+            .world
+            .entity_component_manager()
+            .component_store_mut()
+            .register("theme", window, Rc::new(Theme::default()));
+        harness
+            .system
+            .context_provider
+            .mouse_capture
+            .set(Some(child));
+
+        harness.system.process_mouse_capture(
+            &mouse_move_event(window, 5.0, 5.0),
+            harness.world.entity_component_manager(),
+        );
+        assert_eq!(
+            *harness.system.hovered_widgets.borrow(),
+            vec![window, panel, child]
+        );
+
+        // the mouse is dragged out of the captured widget
+        harness.system.process_mouse_capture(
+            &mouse_move_event(window, 30.0, 30.0),
+            harness.world.entity_component_manager(),
+        );
+        assert_eq!(
+            *harness.system.hovered_widgets.borrow(),
+            vec![window, panel]
+        );
+    }
+}
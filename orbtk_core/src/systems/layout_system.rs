@@ -1,16 +1,213 @@
+use std::{collections::BTreeMap, mem};
+
 use dces::prelude::*;
 
 use crate::{prelude::*, render::RenderContext2D, tree::Tree, utils::*};
 
+// Maximum number of additional layout passes, if layouts are invalidated during the arrangement.
+const MAX_LAYOUT_PASSES: usize = 4;
+
 /// The `LayoutSystem` takes care to rebuild the layout of the current
 /// `UI` on a per iteration cycle. `layout widgets` are organized in
 /// layout objects, that will in term calculate the resulting
 /// positions of it objects inside the view.
+///
+/// Each layout caches its last measure and arrangement. Only widgets
+/// with changed layout properties are measured again, and their
+/// ancestors only as long as their desired size changes.
 #[derive(Constructor)]
 pub struct LayoutSystem {
     context_provider: ContextProvider,
 }
 
+impl LayoutSystem {
+    // Measures the given widget again and walks up the tree as long as the desired size of
+    // the measured widget has changed. Returns the widget from which the arrangement has to
+    // be repeated.
+    fn measure_up(
+        &self,
+        render_context: &mut RenderContext2D,
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) -> Entity {
+        let root = ecm.entity_store().root();
+        let mut current = entity;
+
+        loop {
+            let layout = &layouts[&current];
+            // layouts without a cache count as not measured, their parents are measured again
+            let was_measured = layout.cache().map_or(false, LayoutCache::is_measured);
+            let old_size = layout.cache().map(LayoutCache::desired_size);
+
+            if let Some(cache) = layout.cache() {
+                cache.invalidate(LayoutInvalidation::Measure);
+            }
+            let desired_size = layout.measure(render_context, current, ecm, layouts, theme);
+
+            if current == root || (was_measured && Some(desired_size.size()) == old_size) {
+                return current;
+            }
+
+            match ecm.entity_store().parent.get(&current).copied().flatten() {
+                Some(parent) if layouts.contains_key(&parent) => current = parent,
+                _ => return current,
+            }
+        }
+    }
+
+    // Arranges the given widget again with its last available size and walks up the tree
+    // as long as the size of the arranged widget has changed.
+    fn arrange_up(
+        &self,
+        render_context: &mut RenderContext2D,
+        entity: Entity,
+        window_size: (f64, f64),
+        ecm: &mut EntityComponentManager<Tree>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) {
+        let root = ecm.entity_store().root();
+        let mut current = entity;
+
+        loop {
+            let layout = &layouts[&current];
+
+            let parent_size = if current == root {
+                window_size
+            } else if let Some(cache) = layout.cache() {
+                match cache.parent_size() {
+                    Some(parent_size) => parent_size,
+                    // the widget was never arranged, its parent takes care of it
+                    None => return,
+                }
+            } else {
+                // the available size of a layout without a cache is not known, therefore its
+                // parent is arranged again
+                match ecm.entity_store().parent.get(&current).copied().flatten() {
+                    Some(parent) if layouts.contains_key(&parent) => {
+                        invalidate_arrange(&layouts[&parent]);
+                        current = parent;
+                        continue;
+                    }
+                    _ => return,
+                }
+            };
+
+            let old_size = ecm
+                .component_store()
+                .get::<Rectangle>("bounds", current)
+                .map(|bounds| bounds.size())
+                .ok();
+
+            layout.arrange(render_context, parent_size, current, ecm, layouts, theme);
+
+            let new_size = ecm
+                .component_store()
+                .get::<Rectangle>("bounds", current)
+                .map(|bounds| bounds.size())
+                .ok();
+
+            if current == root || old_size == new_size {
+                return;
+            }
+
+            match ecm.entity_store().parent.get(&current).copied().flatten() {
+                Some(parent) if layouts.contains_key(&parent) => {
+                    invalidate_arrange(&layouts[&parent]);
+                    current = parent;
+                }
+                _ => return,
+            }
+        }
+    }
+
+    // Measures and arranges the invalidated widgets and the ancestors that are affected.
+    fn apply_invalidations(
+        &self,
+        render_context: &mut RenderContext2D,
+        invalidations: Vec<(Entity, LayoutInvalidation)>,
+        window_size: (f64, f64),
+        ecm: &mut EntityComponentManager<Tree>,
+        layouts: &BTreeMap<Entity, Box<dyn Layout>>,
+        theme: &Theme,
+    ) {
+        // keeps the strongest invalidation of each widget
+        let mut dirty_layouts: BTreeMap<Entity, LayoutInvalidation> = BTreeMap::new();
+
+        for (entity, invalidation) in invalidations {
+            if !layouts.contains_key(&entity) || !ecm.entity_store().parent.contains_key(&entity) {
+                continue;
+            }
+
+            let current = dirty_layouts
+                .entry(entity)
+                .or_insert(LayoutInvalidation::None);
+            *current = (*current).max(invalidation);
+        }
+
+        let mut arrange_starts = vec![];
+
+        for (entity, invalidation) in dirty_layouts {
+            match invalidation {
+                LayoutInvalidation::Measure => arrange_starts.push(self.measure_up(
+                    render_context,
+                    entity,
+                    ecm,
+                    layouts,
+                    theme,
+                )),
+                LayoutInvalidation::Arrange => {
+                    invalidate_arrange(&layouts[&entity]);
+
+                    match ecm.entity_store().parent.get(&entity).copied().flatten() {
+                        Some(parent) if layouts.contains_key(&parent) => {
+                            invalidate_arrange(&layouts[&parent]);
+                            arrange_starts.push(parent);
+                        }
+                        _ => arrange_starts.push(entity),
+                    }
+                }
+                LayoutInvalidation::None => {}
+            }
+        }
+
+        for entity in arrange_starts {
+            self.arrange_up(render_context, entity, window_size, ecm, layouts, theme);
+        }
+    }
+}
+
+// Invalidates the arrangement of the given layout, if it has a cache.
+fn invalidate_arrange(layout: &dyn Layout) {
+    if let Some(cache) = layout.cache() {
+        cache.invalidate(LayoutInvalidation::Arrange);
+    }
+}
+
+// Returns the registered layout invalidations and clears them.
+fn take_invalidations(
+    root: Entity,
+    ecm: &mut EntityComponentManager<Tree>,
+) -> Vec<(Entity, LayoutInvalidation)> {
+    ecm.component_store_mut()
+        .get_mut::<Vec<(Entity, LayoutInvalidation)>>("dirty_layouts", root)
+        .map(mem::take)
+        .unwrap_or_default()
+}
+
+// Returns the properties that are changed by the layouts and clears them.
+fn take_layout_changes(
+    root: Entity,
+    ecm: &mut EntityComponentManager<Tree>,
+) -> Vec<(Entity, String)> {
+    ecm.component_store_mut()
+        .get_mut::<Vec<(Entity, String)>>("layout_changes", root)
+        .map(mem::take)
+        .unwrap_or_default()
+}
+
 impl System<Tree, RenderContext2D> for LayoutSystem {
     fn run_with_context(
         &self,
@@ -18,19 +215,22 @@ impl System<Tree, RenderContext2D> for LayoutSystem {
         render_context: &mut RenderContext2D,
     ) {
         let root = ecm.entity_store().root();
+        let first_run = self.context_provider.first_run.get();
+
+        let invalidations = take_invalidations(root, ecm);
 
         if ecm
             .component_store()
             .get::<Vec<Entity>>("dirty_widgets", root)
             .unwrap()
             .is_empty()
-            && !self.context_provider.first_run.get()
+            && invalidations.is_empty()
+            && !first_run
         {
             return;
         }
 
         let mut window_size = (0.0, 0.0);
-        let root = ecm.entity_store().root();
 
         if let Ok(bounds) = ecm.component_store().get::<Rectangle>("bounds", root) {
             window_size.0 = bounds.width();
@@ -43,22 +243,56 @@ impl System<Tree, RenderContext2D> for LayoutSystem {
             .unwrap()
             .clone();
 
-        self.context_provider.layouts.borrow()[&root].measure(
-            render_context,
-            root,
-            ecm,
-            &self.context_provider.layouts.borrow(),
-            &theme,
-        );
-
-        self.context_provider.layouts.borrow()[&root].arrange(
-            render_context,
-            window_size,
-            root,
-            ecm,
-            &self.context_provider.layouts.borrow(),
-            &theme,
-        );
+        let layouts = &self.context_provider.layouts.borrow();
+
+        if !first_run {
+            self.apply_invalidations(
+                render_context,
+                invalidations,
+                window_size,
+                ecm,
+                layouts,
+                &theme,
+            );
+        }
+
+        // cheap if nothing has changed, the cached results are reused
+        layouts[&root].measure(render_context, root, ecm, layouts, &theme);
+        layouts[&root].arrange(render_context, window_size, root, ecm, layouts, &theme);
+
+        // layouts whose desired size depends on the available size, e.g. the `WrapLayout`,
+        // invalidate their measure during the arrangement if the available size has changed
+        for _ in 0..MAX_LAYOUT_PASSES {
+            let invalidations = take_invalidations(root, ecm);
+
+            if invalidations.is_empty() {
+                break;
+            }
+
+            self.apply_invalidations(
+                render_context,
+                invalidations,
+                window_size,
+                ecm,
+                layouts,
+                &theme,
+            );
+
+            layouts[&root].measure(render_context, root, ecm, layouts, &theme);
+            layouts[&root].arrange(render_context, window_size, root, ecm, layouts, &theme);
+        }
+
+        // the properties set by the layouts are not written by a widget container, therefore
+        // their widgets are marked as dirty and the observers are notified afterwards
+        for (entity, key) in take_layout_changes(root, ecm) {
+            WidgetContainer::new(
+                entity,
+                ecm,
+                &theme,
+                Some(&self.context_provider.event_adapter),
+            )
+            .notify_changed(&key);
+        }
 
         // if self.debug_flag.get() {
         //     println!("\n------ End layout update   ------\n");
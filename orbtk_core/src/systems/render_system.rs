@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::{cell::RefCell, collections::BTreeMap};
 
 use dces::prelude::*;
 
@@ -9,9 +9,13 @@ use crate::{prelude::*, render::RenderContext2D, tree::Tree};
 /// For any widgets that have been marked dirty, new bounds have to be
 /// recalculated. The resulting tree is rendered to the render buffer
 /// which is then drawn to the screen.
+///
+/// If the `LayoutDebugger` service is enabled, its overlay is drawn on
+/// top of the widgets.
 #[derive(Constructor)]
 pub struct RenderSystem {
     context_provider: ContextProvider,
+    registry: Rc<RefCell<Registry>>,
 }
 
 impl System<Tree, RenderContext2D> for RenderSystem {
@@ -28,10 +32,24 @@ impl System<Tree, RenderContext2D> for RenderSystem {
             .unwrap()
             .clone();
 
+        let mouse_position = self.context_provider.mouse_position.get();
+
+        // the registry is only borrowed for the layout debugger, widgets could access it while
+        // they are rendered
+        let debugger_needs_render = self
+            .registry
+            .borrow_mut()
+            .try_get_mut::<LayoutDebugger>("layout_debugger")
+            .map_or(false, |debugger| debugger.needs_render(mouse_position));
+
         // Only process, if
         // * there are `dirty` elements inside the entity vector
         // * context_provider it marked for first_run.
-        if dirty_widgets.is_empty() && !self.context_provider.first_run.get() {
+        // * the overlay of the layout debugger has changed.
+        if dirty_widgets.is_empty()
+            && !self.context_provider.first_run.get()
+            && !debugger_needs_render
+        {
             return;
         }
 
@@ -75,6 +93,15 @@ impl System<Tree, RenderContext2D> for RenderSystem {
             &mut offsets,
             debug,
         );
+
+        if let Some(layout_debugger) = self
+            .registry
+            .borrow_mut()
+            .try_get_mut::<LayoutDebugger>("layout_debugger")
+        {
+            layout_debugger.render(render_context, ecm, &offsets, mouse_position);
+        }
+
         render_context.finish();
 
         if self.context_provider.first_run.get() {
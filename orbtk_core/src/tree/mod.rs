@@ -88,6 +88,30 @@ impl Tree {
         Ok(child)
     }
 
+    /// Moves the child at index `from` of the given `parent` to the index `to`.
+    /// Raised `NotFound` error if the parent is not part of the tree or if one of the indices is out of range.
+    pub fn move_child(
+        &mut self,
+        parent: impl Into<Entity>,
+        from: usize,
+        to: usize,
+    ) -> Result<(), NotFound> {
+        let parent = parent.into();
+
+        if let Some(children) = self.children.get_mut(&parent) {
+            if from >= children.len() || to >= children.len() {
+                return Err(NotFound::Child(parent));
+            }
+
+            let child = children.remove(from);
+            children.insert(to, child);
+
+            return Ok(());
+        }
+
+        Err(NotFound::Parent(parent))
+    }
+
     /// Returns true if the tree has no entities.
     pub fn is_empty(&self) -> bool {
         self.children.is_empty()
@@ -278,6 +302,34 @@ mod tests {
         assert_eq!(tree.parent.get(&child).unwrap().unwrap(), parent);
     }
 
+    #[test]
+    fn test_move_child() {
+        let parent = Entity(0);
+
+        let mut tree = Tree::new();
+        tree.register_node(parent);
+
+        for child in 1..4 {
+            tree.register_node(child);
+            tree.append_child(parent, child).unwrap();
+        }
+
+        tree.move_child(parent, 0, 2).unwrap();
+        assert_eq!(
+            tree.children.get(&parent).unwrap(),
+            &vec![Entity(2), Entity(3), Entity(1)]
+        );
+
+        tree.move_child(parent, 2, 1).unwrap();
+        assert_eq!(
+            tree.children.get(&parent).unwrap(),
+            &vec![Entity(2), Entity(1), Entity(3)]
+        );
+
+        assert_eq!(tree.move_child(parent, 0, 3), Err(NotFound::Child(parent)));
+        assert_eq!(tree.move_child(4, 0, 1), Err(NotFound::Parent(Entity(4))));
+    }
+
     #[test]
     fn test_len() {
         let mut tree = Tree::new();
@@ -44,6 +44,7 @@ impl<'a> BuildContext<'a> {
             .entity_store_mut()
             .append_child(parent, child)
             .unwrap();
+        invalidate_layout(parent, LayoutInvalidation::Measure, self.ecm);
     }
 
     /// Appends a child to overlay (on the top of the main tree). If
@@ -3,14 +3,14 @@ use std::{collections::BTreeMap, sync::mpsc};
 use dces::prelude::*;
 
 use crate::{
-    application::{create_window, ContextProvider},
+    application::{create_owned_window, create_window, ContextProvider, WindowOwner},
     prelude::*,
     render::RenderContext2D,
-    shell::{ShellRequest, WindowRequest},
+    shell::{ShellRequest, WindowId, WindowRequest},
     tree::Tree,
 };
 
-use super::WidgetContainer;
+use super::{invalidate_layout, WidgetContainer};
 
 /// The `Context` structure provides access to widget entities.
 ///
@@ -69,6 +69,12 @@ impl<'a> Context<'a> {
         self.entity
     }
 
+    /// Returns the flow direction of the current widget. It is inherited from the parent
+    /// if the widget doesn't set its own.
+    pub fn flow_direction(&mut self) -> FlowDirection {
+        flow_direction(self.ecm, self.entity)
+    }
+
     /// Switch current `Context` to context of given widget `another`.
     /// Don't forget to change back to the original context once you
     /// are done.
@@ -88,6 +94,35 @@ impl<'a> Context<'a> {
         None
     }
 
+    // -- Mouse capture --
+
+    /// Captures the mouse for the given widget. All mouse events are sent to the widget and bubble up to
+    /// its parents until the capture is released, also if the mouse is outside of the widget. A widget
+    /// that had captured the mouse before receives a `LostMouseCaptureEvent`.
+    pub fn capture_mouse(&mut self, entity: Entity) {
+        if let Some(old) = self.provider.mouse_capture.replace(Some(entity)) {
+            if old != entity {
+                self.provider
+                    .event_adapter
+                    .push_event_direct(old, LostMouseCaptureEvent);
+            }
+        }
+    }
+
+    /// Releases the mouse capture. The widget that had captured the mouse receives a `LostMouseCaptureEvent`.
+    pub fn release_mouse(&mut self) {
+        if let Some(old) = self.provider.mouse_capture.take() {
+            self.provider
+                .event_adapter
+                .push_event_direct(old, LostMouseCaptureEvent);
+        }
+    }
+
+    /// Returns the widget that has captured the mouse.
+    pub fn mouse_capture(&self) -> Option<Entity> {
+        self.provider.mouse_capture.get()
+    }
+
     // -- Widgets --
 
     /// Returns a specific widget.
@@ -312,9 +347,11 @@ impl<'a> Context<'a> {
                 .iter()
                 .position(|&r| r == remove_entity)
                 .unwrap();
-            if let Some(parent) = self.ecm.entity_store().children.get_mut(&parent) {
-                parent.remove(index);
+            if let Some(children) = self.ecm.entity_store().children.get_mut(&parent) {
+                children.remove(index);
             }
+
+            invalidate_layout(parent, LayoutInvalidation::Measure, self.ecm);
         }
     }
 
@@ -428,6 +465,13 @@ impl<'a> Context<'a> {
         None
     }
 
+    /// Moves the child at index `from` of the given parent to the index `to`.
+    pub fn move_child(&mut self, parent: Entity, from: usize, to: usize) -> Result<(), NotFound> {
+        self.ecm.entity_store_mut().move_child(parent, from, to)?;
+        invalidate_layout(parent, LayoutInvalidation::Measure, self.ecm);
+        Ok(())
+    }
+
     /// Creates and shows a new window.
     pub fn show_window<F: Fn(&mut BuildContext) -> Entity + 'static>(&mut self, create_fn: F) {
         let (adapter, settings, receiver) = create_window(
@@ -443,6 +487,67 @@ impl<'a> Context<'a> {
             .expect("Context.show_window: Could not send shell request.");
     }
 
+    /// Creates and shows a new window that is owned by the current window.
+    ///
+    /// The owned window is closed together with its owner. If `modal` is set
+    /// to `true` the current window does not receive input until the owned
+    /// window is closed. The widget of the current state can receive a result
+    /// of the owned window as message, see `close_window_with_result`.
+    pub fn show_owned_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
+        &mut self,
+        modal: bool,
+        create_fn: F,
+    ) {
+        let owner = WindowOwner {
+            window_id: self.provider.window_id,
+            entity: self.entity,
+            message_adapter: self.provider.message_adapter.clone(),
+        };
+
+        let (adapter, settings, receiver) = create_owned_window(
+            self.provider.application_name.clone(),
+            &self.theme,
+            self.provider.shell_sender.clone(),
+            create_fn,
+            self.provider.localization.clone(),
+            Some(owner),
+            modal,
+        );
+        self.provider
+            .shell_sender
+            .send(ShellRequest::CreateWindow(adapter, settings, receiver))
+            .expect("Context.show_owned_window: Could not send shell request.");
+    }
+
+    /// Creates and shows a new modal window that is owned by the current window.
+    pub fn show_modal_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
+        &mut self,
+        create_fn: F,
+    ) {
+        self.show_owned_window(true, create_fn);
+    }
+
+    /// Returns the id of the current window.
+    pub fn window_id(&self) -> WindowId {
+        self.provider.window_id
+    }
+
+    /// Returns the owner of the current window, if it is an owned window.
+    pub fn window_owner(&self) -> Option<&WindowOwner> {
+        self.provider.owner.as_ref()
+    }
+
+    /// Sends the given result as message to the widget that has opened the
+    /// current window and closes the window afterwards. If the current window
+    /// has no owner, the window is closed without sending the result.
+    pub fn close_window_with_result<R: Any + Send>(&self, result: R) {
+        if let Some(owner) = &self.provider.owner {
+            owner.message_adapter.send_message(result, owner.entity);
+        }
+
+        self.send_window_request(WindowRequest::Close);
+    }
+
     /// Returns a mutable reference of the 2d render ctx.
     pub fn render_context_2_d(&mut self) -> &mut RenderContext2D {
         self.render_context
@@ -0,0 +1,119 @@
+use super::WidgetContainer;
+
+/// Describes the standard interaction states that are maintained for each widget and pushed
+/// to its `Selector`, so the theme can style them without any code in the widget.
+///
+/// `Hover` and `Pressed` are set by the event system for the widget under the mouse and its
+/// parents, `Focused` by the focus state of the window. `Disabled`, `Selected` and `Checked`
+/// follow the `enabled`, `selected` and `checked` properties of the widget.
+///
+/// If a widget declares a `bool` property with the name of the state (e.g. `hover`), the
+/// property mirrors the state and could be used e.g. for bindings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InteractionState {
+    /// The mouse is over the widget or one of its children.
+    Hover,
+
+    /// The left mouse button is pressed on the widget or one of its children and not yet
+    /// released.
+    Pressed,
+
+    /// The widget has the keyboard focus.
+    Focused,
+
+    /// The widget is disabled.
+    Disabled,
+
+    /// The widget is selected.
+    Selected,
+
+    /// The widget is checked.
+    Checked,
+}
+
+impl InteractionState {
+    /// All interaction states.
+    pub const ALL: [InteractionState; 6] = [
+        InteractionState::Hover,
+        InteractionState::Pressed,
+        InteractionState::Focused,
+        InteractionState::Disabled,
+        InteractionState::Selected,
+        InteractionState::Checked,
+    ];
+
+    /// Returns the name of the state inside of the selector and theme.
+    pub fn name(self) -> &'static str {
+        match self {
+            InteractionState::Hover => "hover",
+            InteractionState::Pressed => "pressed",
+            InteractionState::Focused => "focused",
+            InteractionState::Disabled => "disabled",
+            InteractionState::Selected => "selected",
+            InteractionState::Checked => "checked",
+        }
+    }
+
+    /// Returns the key of the `bool` property that mirrors the state.
+    pub fn property(self) -> &'static str {
+        match self {
+            InteractionState::Disabled => "enabled",
+            state => state.name(),
+        }
+    }
+
+    /// Returns the state that is mirrored by the property with the given key.
+    pub fn from_property(key: &str) -> Option<Self> {
+        InteractionState::ALL
+            .iter()
+            .find(|state| state.property() == key)
+            .copied()
+    }
+
+    /// Converts the value of the mirrored property to the value of the state and vice versa.
+    /// Only `Disabled` is mirrored inverted by the `enabled` property.
+    pub fn convert(self, value: bool) -> bool {
+        if self == InteractionState::Disabled {
+            !value
+        } else {
+            value
+        }
+    }
+
+    /// Returns `true` if the mirrored property of the given widget sets the state.
+    pub fn is_set_by(self, widget: &WidgetContainer) -> bool {
+        widget
+            .try_get::<bool>(self.property())
+            .map_or(false, |value| self.convert(*value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_property() {
+        for state in InteractionState::ALL.iter() {
+            assert_eq!(
+                InteractionState::from_property(state.property()),
+                Some(*state)
+            );
+        }
+
+        assert_eq!(
+            InteractionState::from_property("enabled"),
+            Some(InteractionState::Disabled)
+        );
+        assert_eq!(InteractionState::from_property("disabled"), None);
+        assert_eq!(InteractionState::from_property("text"), None);
+    }
+
+    #[test]
+    fn test_convert() {
+        assert!(InteractionState::Disabled.convert(false));
+        assert!(!InteractionState::Disabled.convert(true));
+        assert!(InteractionState::Hover.convert(true));
+        assert!(!InteractionState::Checked.convert(false));
+    }
+}
@@ -8,6 +8,7 @@ use crate::{event::EventHandler, properties::AttachedProperty, theming::Selector
 
 pub use self::build_context::*;
 pub use self::context::*;
+pub use self::interaction_state::*;
 pub use self::message_adapter::*;
 pub use self::registry::*;
 pub use self::state::*;
@@ -17,6 +18,7 @@ pub use self::widget_container::*;
 
 mod build_context;
 mod context;
+mod interaction_state;
 mod message_adapter;
 mod registry;
 mod state;
@@ -2,7 +2,12 @@ use std::any::type_name;
 
 use dces::prelude::*;
 
-use crate::{event::ChangedEvent, event::*, theming::*, tree::*, utils::prelude::*};
+use crate::{
+    event::ChangedEvent, event::*, layout::LayoutInvalidation, theming::*, tree::*,
+    utils::prelude::*,
+};
+
+use super::InteractionState;
 
 /// Mark the widget and shared widgets as dirty.
 pub fn mark_as_dirty(key: &str, entity: Entity, ecm: &mut EntityComponentManager<Tree>) {
@@ -22,6 +27,50 @@ pub fn mark_as_dirty(key: &str, entity: Entity, ecm: &mut EntityComponentManager
                 dirty_widgets.push(entity);
             }
         }
+
+        invalidate_property(key, entity, ecm);
+    }
+}
+
+// Registers the layout invalidation that is caused by a change of the property with the given
+// key. A change of an inherited property invalidates the whole subtree.
+fn invalidate_property(key: &str, entity: Entity, ecm: &mut EntityComponentManager<Tree>) {
+    let invalidation = LayoutInvalidation::of(key);
+
+    if !LayoutInvalidation::is_inherited(key) {
+        invalidate_layout(entity, invalidation, ecm);
+        return;
+    }
+
+    let mut entities = vec![entity];
+
+    while let Some(entity) = entities.pop() {
+        invalidate_layout(entity, invalidation, ecm);
+
+        if let Some(children) = ecm.entity_store().children.get(&entity) {
+            entities.extend(children.iter().copied());
+        }
+    }
+}
+
+/// Registers the widget for the given layout invalidation. The `LayoutSystem` measures and
+/// arranges only the registered widgets and the ancestors that are affected by their changes.
+pub fn invalidate_layout(
+    entity: Entity,
+    invalidation: LayoutInvalidation,
+    ecm: &mut EntityComponentManager<Tree>,
+) {
+    if invalidation == LayoutInvalidation::None {
+        return;
+    }
+
+    let root = ecm.entity_store().root();
+
+    if let Ok(dirty_layouts) = ecm
+        .component_store_mut()
+        .get_mut::<Vec<(Entity, LayoutInvalidation)>>("dirty_layouts", root)
+    {
+        dirty_layouts.push((entity, invalidation));
     }
 }
 
@@ -76,6 +125,12 @@ impl<'a> WidgetContainer<'a> {
         mark_as_dirty_self(entity, self.ecm);
     }
 
+    /// Invalidates the layout of the widget, e.g. if a layout relevant value has changed
+    /// without setting a property.
+    pub fn invalidate_layout(&mut self, invalidation: LayoutInvalidation) {
+        invalidate_layout(self.current_node, invalidation, self.ecm);
+    }
+
     /// Gets the entity of the widget.
     pub fn entity(&self) -> Entity {
         self.current_node
@@ -218,19 +273,41 @@ impl<'a> WidgetContainer<'a> {
         None
     }
 
-    fn toggle_enabled_state(&mut self) {
-        if *self.get::<bool>("enabled") && self.get::<Selector>("selector").has_state("disabled") {
-            self.get_mut::<Selector>("selector")
-                .remove_state("disabled");
-            self.update(false);
-        } else if !*self.get::<bool>("enabled")
-            && !self.get::<Selector>("selector").has_state("disabled")
-        {
-            self.get_mut::<Selector>("selector").push_state("disabled");
+    // Pushes the interaction state to the selector or removes it.
+    fn sync_interaction_state(&mut self, state: InteractionState, value: bool) {
+        let changed = match self.try_get_mut::<Selector>("selector") {
+            Some(selector) if selector.has_state(state.name()) != value => {
+                if value {
+                    selector.push_state(state.name());
+                } else {
+                    selector.remove_state(state.name());
+                }
+                true
+            }
+            _ => false,
+        };
+
+        if changed {
             self.update(false);
         }
     }
 
+    /// Sets the given interaction state of the widget. The state is pushed to the selector
+    /// and the mirrored property is updated if the widget has one.
+    pub fn set_interaction_state(&mut self, state: InteractionState, value: bool) {
+        if self.has::<bool>(state.property()) {
+            self.set(state.property(), state.convert(value));
+        }
+
+        self.sync_interaction_state(state, value);
+    }
+
+    /// Returns `true` if the given interaction state of the widget is set.
+    pub fn has_interaction_state(&self, state: InteractionState) -> bool {
+        self.try_get::<Selector>("selector")
+            .map_or(false, |selector| selector.has_state(state.name()))
+    }
+
     /// Sets the property of type `P`. Sets the `dirty` flag of the widget to `true`.
     ///
     /// # Panics
@@ -250,6 +327,20 @@ impl<'a> WidgetContainer<'a> {
             return;
         }
 
+        self.notify_changed(key);
+        self.set_non_dirty(key, value);
+
+        if let Some(state) = InteractionState::from_property(key) {
+            if let Some(value) = self.try_get::<bool>(key).copied() {
+                self.sync_interaction_state(state, state.convert(value));
+            }
+        }
+    }
+
+    /// Marks the widget and the widgets that share the property with the given key as dirty and
+    /// sends a `ChangedEvent` to the observing widgets like `set` does. Used if the property is
+    /// written directly to the component store, e.g. by a layout.
+    pub fn notify_changed(&mut self, key: &str) {
         let mut on_changed = false;
 
         let mut source = self.current_node;
@@ -278,6 +369,7 @@ impl<'a> WidgetContainer<'a> {
             }
 
             self.mark_as_dirty_self(entity);
+            invalidate_property(&target_key, entity, self.ecm);
 
             // each widget has this filter therefore unwrap.
             match self
@@ -303,12 +395,6 @@ impl<'a> WidgetContainer<'a> {
                 }
             }
         }
-
-        self.set_non_dirty(key, value);
-
-        if key.eq("enabled") {
-            self.toggle_enabled_state();
-        }
     }
 
     /// Sets the property of type `P` without setting the widget dirty.
@@ -391,6 +477,8 @@ impl<'a> WidgetContainer<'a> {
             .component_store_mut()
             .get_mut::<Constraint>("constraint", self.current_node)
         {
+            let old_constraint = *constraint;
+
             match key {
                 "width" => constraint.set_width(value),
                 "height" => constraint.set_height(value),
@@ -398,8 +486,13 @@ impl<'a> WidgetContainer<'a> {
                 "min_height" => constraint.set_min_height(value),
                 "max_width" => constraint.set_max_width(value),
                 "max_height" => constraint.set_max_height(value),
+                "aspect_ratio" => constraint.set_aspect_ratio(value),
                 _ => {}
             }
+
+            if *constraint != old_constraint {
+                self.invalidate_layout(LayoutInvalidation::Measure);
+            }
         }
     }
 
@@ -410,7 +503,7 @@ impl<'a> WidgetContainer<'a> {
             0.0
         };
 
-        if let Some(padding) = self.try_get_mut::<Thickness>("padding") {
+        if let Some(mut padding) = self.try_clone::<Thickness>("padding") {
             match key {
                 "padding_left" => padding.set_left(value),
                 "padding_top" => padding.set_top(value),
@@ -418,20 +511,29 @@ impl<'a> WidgetContainer<'a> {
                 "padding_bottom" => padding.set_bottom(value),
                 _ => {}
             }
+
+            // prevents a new measure if the theme sets the same padding again
+            if !self.eq("padding", &padding) {
+                self.set_non_dirty("padding", padding);
+                self.mark_as_dirty("padding", self.current_node);
+            }
         }
     }
 
     fn update_value<T, V>(&mut self, key: &str, value: V)
     where
-        T: Component + Clone,
+        T: Component + Clone + PartialEq,
         V: Into<T>,
     {
-        if self.has::<T>(key) {
+        let value = value.into();
+
+        if self.has::<T>(key) && !self.eq(key, &value) {
             *self
                 .ecm
                 .component_store_mut()
                 .get_mut::<T>(key, self.current_node)
-                .unwrap() = value.into();
+                .unwrap() = value;
+            self.invalidate_layout(LayoutInvalidation::of(key));
         }
     }
 
@@ -475,7 +577,7 @@ impl<'a> WidgetContainer<'a> {
                         self.update_padding(&key, Value(value));
                     }
                     "width" | "height" | "min_width" | "min_height" | "max_width"
-                    | "max_height" => self.update_constraint(&key, Value(value)),
+                    | "max_height" | "aspect_ratio" => self.update_constraint(&key, Value(value)),
                     _ => {
                         // common mapping
                         if self.is::<Brush>(&key) {
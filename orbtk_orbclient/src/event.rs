@@ -2,10 +2,12 @@
 
 use std::char;
 
+use serde_derive::{Deserialize, Serialize};
+
 use orbtk_utils::Point;
 
 /// Represents a keyboard key.
-#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub enum Key {
     A(bool),
     B(bool),
@@ -50,11 +52,24 @@ pub enum Key {
     Enter,
     Escape,
     ExclamationMark,
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
     Five,
     Four,
     Hash,
     Home,
     Left,
+    Menu,
     Right,
     ShiftL,
     ShiftR,
@@ -374,15 +389,17 @@ impl From<char> for Key {
 }
 
 /// Describes a specific mouse button.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum MouseButton {
     Left,
     Middle,
     Right,
+    Back,
+    Forward,
 }
 
 /// Describes the position / state of a button.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum ButtonState {
     Down,
     Up,
@@ -399,6 +416,35 @@ pub struct MouseEvent {
     pub state: ButtonState,
 }
 
+/// Describes the device kind of a pointer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum PointerKind {
+    Mouse,
+    Touch,
+    Pen,
+}
+
+/// Describes the phase of a pointer contact.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum PointerPhase {
+    Begin,
+    Move,
+    End,
+}
+
+/// Represents a pointer event e.g. of a finger on a touch screen.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PointerEvent {
+    /// The id of the pointer. Is unique as long as the pointer has contact.
+    pub id: u64,
+    /// The kind of the pointer device
+    pub kind: PointerKind,
+    /// The phase of the pointer contact
+    pub phase: PointerPhase,
+    /// The position of the pointer
+    pub position: Point,
+}
+
 /// Represents a keyboard key event.
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct KeyEvent {
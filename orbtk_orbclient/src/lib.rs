@@ -40,7 +40,25 @@ pub mod native;
 
 pub use orbtk_tinyskia::prelude as render;
 
-use std::{collections::HashMap, sync::mpsc};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        mpsc,
+    },
+};
+
+/// Identifies a window of the application shell.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct WindowId(pub u32);
+
+impl WindowId {
+    /// Creates a new unique window id.
+    pub fn new() -> Self {
+        static NEXT_ID: AtomicU32 = AtomicU32::new(1);
+        WindowId(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
 
 /// Used to send a request to the window.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -53,6 +71,9 @@ pub enum WindowRequest {
 
     /// Request redraw of the `Windows`s content.
     Redraw,
+
+    /// Request to resize the `Windows` to the given width and height.
+    Resize(u32, u32),
 }
 
 /// Used to send a request to the application shell.
@@ -87,6 +108,15 @@ pub struct WindowSettings {
     /// List of fonts to register.
     pub fonts: HashMap<String, &'static [u8]>,
 
+    /// Unique id of the window.
+    pub id: WindowId,
+
+    /// If set, the window is owned by the window with the given id and will be closed together with it.
+    pub owner: Option<WindowId>,
+
+    /// If set to `true` and the window has an owner, the owner will not receive input while this window is open.
+    pub modal: bool,
+
     /// The initial position of the window.
     pub position: (f64, f64),
 
@@ -11,6 +11,8 @@ pub use self::window::*;
 pub use self::window_builder::*;
 
 mod states;
+#[cfg(not(target_os = "redox"))]
+mod touch;
 mod window;
 mod window_builder;
 
@@ -68,6 +70,26 @@ where
         }
     }
 
+    // Gets the ids of all windows whose input is blocked by an open modal window.
+    fn blocked_windows(&self) -> Vec<WindowId> {
+        blocked_owners(
+            self.window_shells
+                .iter()
+                .map(|w| (w.owner(), w.is_modal(), w.is_open())),
+        )
+    }
+
+    // Closes all windows that are owned by the window with the given id.
+    fn close_owned_windows(&mut self, owner: WindowId) {
+        for window_shell in self
+            .window_shells
+            .iter_mut()
+            .filter(|w| w.owner() == Some(owner))
+        {
+            window_shell.close();
+        }
+    }
+
     /// Runs (starts) the application shell and its windows.
     pub fn run(&mut self) {
         loop {
@@ -75,9 +97,12 @@ where
                 return;
             }
 
+            let blocked_windows = self.blocked_windows();
+
             for i in 0..self.window_shells.len() {
                 let mut remove = false;
                 if let Some(window_shell) = self.window_shells.get_mut(i) {
+                    window_shell.set_blocked(blocked_windows.contains(&window_shell.id()));
                     window_shell.update();
                     window_shell.render();
 
@@ -91,7 +116,8 @@ where
                 }
 
                 if remove {
-                    self.window_shells.remove(i);
+                    let window_shell = self.window_shells.remove(i);
+                    self.close_owned_windows(window_shell.id());
                     break;
                 }
             }
@@ -100,3 +126,51 @@ where
         }
     }
 }
+
+// Returns the owners of the open modal windows. The windows are given as owner, modal and open
+// flag.
+fn blocked_owners(windows: impl Iterator<Item = (Option<WindowId>, bool, bool)>) -> Vec<WindowId> {
+    windows
+        .filter(|(_, modal, open)| *modal && *open)
+        .filter_map(|(owner, _, _)| owner)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocked_owners() {
+        let (main, dialog) = (WindowId::new(), WindowId::new());
+
+        // an open modal window blocks its owner, a nested one blocks the modal window
+        assert_eq!(
+            blocked_owners(
+                vec![
+                    (None, false, true),
+                    (Some(main), true, true),
+                    (Some(dialog), true, true)
+                ]
+                .into_iter()
+            ),
+            vec![main, dialog]
+        );
+
+        // owned windows that are not modal or closed block nothing
+        assert!(blocked_owners(
+            vec![
+                (None, false, true),
+                (Some(main), false, true),
+                (Some(main), true, false)
+            ]
+            .into_iter()
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_window_id() {
+        assert_ne!(WindowId::new(), WindowId::new());
+    }
+}
@@ -5,6 +5,8 @@ pub struct MouseState {
     pub button_middle: bool,
     pub button_right: bool,
     pub mouse_pos: (f32, f32),
+    // id of the touch screen finger that is mapped to the mouse
+    pub finger: Option<u64>,
 }
 
 /// Internal helper state to handle current window state.
@@ -0,0 +1,179 @@
+use std::{
+    os::raw::{c_int, c_void},
+    ptr,
+    sync::{Mutex, Once},
+};
+
+use sdl2::sys;
+
+use crate::event::{MouseButton, PointerPhase};
+
+// Id of the mouse events that sdl2 synthesizes for touches (`SDL_TOUCH_MOUSEID`).
+const TOUCH_MOUSE_ID: u32 = u32::MAX;
+
+// The side buttons of a mouse (`SDL_BUTTON_X1` and `SDL_BUTTON_X2`).
+const BUTTON_X1: u8 = 4;
+const BUTTON_X2: u8 = 5;
+
+static FILTER: Once = Once::new();
+
+/// A finger event of a touch screen. The position is relative to the window size (`0.0` to `1.0`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FingerEvent {
+    pub id: u64,
+    pub phase: PointerPhase,
+    pub x: f64,
+    pub y: f64,
+}
+
+/// A press or release of a side button of the mouse (`Back` or `Forward`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SideButtonEvent {
+    pub button: MouseButton,
+    pub pressed: bool,
+}
+
+struct Watch {
+    window_id: u32,
+    events: Mutex<Vec<FingerEvent>>,
+    side_buttons: Mutex<Vec<SideButtonEvent>>,
+}
+
+/// Collects the finger events and the side mouse buttons of a sdl2 window. orbclient drops them
+/// while it polls the sdl2 events, therefore they are read by an event watch that is called before.
+pub struct FingerWatch {
+    // boxed to keep the address, that is passed to the event watch, stable
+    watch: Box<Watch>,
+}
+
+impl FingerWatch {
+    /// Creates a new finger watch for the window with the given sdl2 window id.
+    pub fn new(window_id: u32) -> Self {
+        // the touches are delivered as pointer events, the synthesized mouse events of sdl2
+        // would deliver them twice
+        FILTER.call_once(|| unsafe {
+            sys::SDL_SetEventFilter(Some(filter_touch_mouse), ptr::null_mut());
+        });
+
+        let watch = Box::new(Watch {
+            window_id,
+            events: Mutex::new(vec![]),
+            side_buttons: Mutex::new(vec![]),
+        });
+
+        // Safety: the watch lives until it is removed from sdl2 on drop.
+        unsafe {
+            sys::SDL_AddEventWatch(
+                Some(watch_fingers),
+                watch.as_ref() as *const Watch as *mut c_void,
+            );
+        }
+
+        FingerWatch { watch }
+    }
+
+    /// Returns the collected finger events and clears the list.
+    pub fn drain(&self) -> Vec<FingerEvent> {
+        self.watch
+            .events
+            .lock()
+            .map(|mut events| events.drain(..).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the collected side button events and clears the list.
+    pub fn drain_side_buttons(&self) -> Vec<SideButtonEvent> {
+        self.watch
+            .side_buttons
+            .lock()
+            .map(|mut events| events.drain(..).collect())
+            .unwrap_or_default()
+    }
+}
+
+impl Drop for FingerWatch {
+    fn drop(&mut self) {
+        unsafe {
+            sys::SDL_DelEventWatch(
+                Some(watch_fingers),
+                self.watch.as_ref() as *const Watch as *mut c_void,
+            );
+        }
+    }
+}
+
+// Is called by sdl2 for each new event. Only the finger and side button events of the watched
+// window are stored.
+unsafe extern "C" fn watch_fingers(user_data: *mut c_void, event: *mut sys::SDL_Event) -> c_int {
+    let watch = &*(user_data as *const Watch);
+    let event = &*event;
+
+    if event.type_ == sys::SDL_EventType::SDL_MOUSEBUTTONDOWN as u32
+        || event.type_ == sys::SDL_EventType::SDL_MOUSEBUTTONUP as u32
+    {
+        watch_side_button(watch, event.button);
+        return 0;
+    }
+
+    let phase = match event.type_ {
+        t if t == sys::SDL_EventType::SDL_FINGERDOWN as u32 => PointerPhase::Begin,
+        t if t == sys::SDL_EventType::SDL_FINGERMOTION as u32 => PointerPhase::Move,
+        t if t == sys::SDL_EventType::SDL_FINGERUP as u32 => PointerPhase::End,
+        _ => return 0,
+    };
+
+    let finger = event.tfinger;
+
+    if finger.windowID != watch.window_id {
+        return 0;
+    }
+
+    if let Ok(mut events) = watch.events.lock() {
+        events.push(FingerEvent {
+            id: finger.fingerId as u64,
+            phase,
+            x: finger.x as f64,
+            y: finger.y as f64,
+        });
+    }
+
+    0
+}
+
+fn watch_side_button(watch: &Watch, event: sys::SDL_MouseButtonEvent) {
+    let button = match event.button {
+        BUTTON_X1 => MouseButton::Back,
+        BUTTON_X2 => MouseButton::Forward,
+        _ => return,
+    };
+
+    if event.windowID != watch.window_id || event.which == TOUCH_MOUSE_ID {
+        return;
+    }
+
+    if let Ok(mut side_buttons) = watch.side_buttons.lock() {
+        side_buttons.push(SideButtonEvent {
+            button,
+            pressed: event.state == sys::SDL_PRESSED as u8,
+        });
+    }
+}
+
+// Is called by sdl2 before an event is queued. Drops the mouse events that are synthesized for
+// touches.
+unsafe extern "C" fn filter_touch_mouse(_: *mut c_void, event: *mut sys::SDL_Event) -> c_int {
+    let event = &*event;
+
+    let which = match event.type_ {
+        t if t == sys::SDL_EventType::SDL_MOUSEMOTION as u32 => event.motion.which,
+        t if t == sys::SDL_EventType::SDL_MOUSEBUTTONDOWN as u32
+            || t == sys::SDL_EventType::SDL_MOUSEBUTTONUP as u32 =>
+        {
+            event.button.which
+        }
+        t if t == sys::SDL_EventType::SDL_MOUSEWHEEL as u32 => event.wheel.which,
+        _ => return 1,
+    };
+
+    (which != TOUCH_MOUSE_ID) as c_int
+}
@@ -11,9 +11,14 @@ use crate::{
     event::{ButtonState, Key, KeyEvent, MouseButton, MouseEvent},
     render::RenderContext2D,
     window_adapter::WindowAdapter,
-    WindowRequest,
+    WindowId, WindowRequest,
 };
 
+#[cfg(not(target_os = "redox"))]
+use super::touch::FingerWatch;
+#[cfg(not(target_os = "redox"))]
+use crate::event::{PointerEvent, PointerKind, PointerPhase};
+
 #[cfg(not(target_os = "redox"))]
 use sdl2::event;
 
@@ -24,6 +29,9 @@ use raw_window_handle::HasRawWindowHandle;
 
 use orbtk_utils::Point;
 
+// Scancode of the menu (application) key, orbclient provides no constant for it.
+const K_MENU: u8 = 0x5D;
+
 /// Represents a wrapper structure consumed by an orbclient window.
 ///
 /// Events are handled and propagated to the window adapter. The
@@ -36,9 +44,15 @@ where
     A: WindowAdapter,
 {
     adapter: A,
+    blocked: bool,
     close: bool,
+    #[cfg(not(target_os = "redox"))]
+    fingers: FingerWatch,
     has_clipboard_update: bool,
+    id: WindowId,
+    modal: bool,
     mouse: MouseState,
+    owner: Option<WindowId>,
     update: bool,
     redraw: Arc<AtomicBool>,
     render_context: RenderContext2D,
@@ -77,6 +91,19 @@ fn init_sync(
     (internal_receiver, _sdl2_sync_thread)
 }
 
+// Checks if the given event is an user input event.
+fn is_input_event(event: &orbclient::EventOption) -> bool {
+    matches!(
+        event,
+        orbclient::EventOption::Key(_)
+            | orbclient::EventOption::TextInput(_)
+            | orbclient::EventOption::Mouse(_)
+            | orbclient::EventOption::Button(_)
+            | orbclient::EventOption::Scroll(_)
+            | orbclient::EventOption::Drop(_)
+    )
+}
+
 impl<A> Window<A>
 where
     A: WindowAdapter,
@@ -93,6 +120,10 @@ where
             render_context,
             request_receiver,
             // window_state: WindowState::default(),
+            blocked: false,
+            id: WindowId::default(),
+            modal: false,
+            owner: None,
             mouse: MouseState::default(),
             update: true,
             redraw: Arc::new(AtomicBool::new(true)),
@@ -125,9 +156,14 @@ where
 
         Window {
             adapter,
+            blocked: false,
             close: false,
+            fingers: FingerWatch::new(window.id()),
             has_clipboard_update: true,
+            id: WindowId::default(),
+            modal: false,
             mouse: MouseState::default(),
+            owner: None,
             redraw,
             render_context,
             request_receiver,
@@ -166,9 +202,17 @@ where
     A: WindowAdapter,
 {
     /// Drain events and propagate the events to the adapter.
+    ///
+    /// If the window is blocked by a modal window, input events are discarded.
     pub fn drain_events(&mut self) {
         for event in self.window.events() {
-            match event.to_option() {
+            let event = event.to_option();
+
+            if self.blocked && is_input_event(&event) {
+                continue;
+            }
+
+            match event {
                 orbclient::EventOption::Key(event) => {
                     self.push_key_event(event);
                     self.update = true;
@@ -222,7 +266,9 @@ where
                     self.update = true;
                 }
                 orbclient::EventOption::Quit(_) => {
-                    self.close = true;
+                    if self.adapter.close_requested() {
+                        self.close = true;
+                    }
                     self.update = true
                 }
                 orbclient::EventOption::Focus(_) => {}
@@ -251,13 +297,117 @@ where
                 orbclient::EventOption::Hover(_) => {}
             }
         }
+
+        self.drain_finger_events();
+        self.drain_side_buttons();
+    }
+
+    // Sends the collected side buttons of the mouse, orbclient only reports the left, middle and
+    // right button.
+    #[cfg(not(target_os = "redox"))]
+    fn drain_side_buttons(&mut self) {
+        for event in self.fingers.drain_side_buttons() {
+            if !self.blocked {
+                self.push_mouse_event(event.pressed, event.button);
+                self.update = true;
+            }
+        }
+    }
+
+    // Orbital provides no side buttons of the mouse.
+    #[cfg(target_os = "redox")]
+    fn drain_side_buttons(&mut self) {}
+
+    // Sends the collected touch screen fingers as pointer events. Like in the web backend the
+    // first finger is also mapped to the mouse, so the widgets without touch handling could be
+    // used.
+    #[cfg(not(target_os = "redox"))]
+    fn drain_finger_events(&mut self) {
+        let width = self.window.width() as f64;
+        let height = self.window.height() as f64;
+
+        for event in self.fingers.drain() {
+            if self.blocked {
+                continue;
+            }
+
+            let position = Point::new(event.x * width, event.y * height);
+
+            self.adapter.pointer_event(PointerEvent {
+                id: event.id,
+                kind: PointerKind::Touch,
+                phase: event.phase,
+                position,
+            });
+            self.update = true;
+
+            if event.phase == PointerPhase::Begin && self.mouse.finger.is_none() {
+                self.mouse.finger = Some(event.id);
+            }
+
+            if self.mouse.finger != Some(event.id) {
+                continue;
+            }
+
+            self.mouse.mouse_pos = (position.x() as f32, position.y() as f32);
+            self.adapter.mouse(position.x(), position.y());
+
+            match event.phase {
+                PointerPhase::Begin => {
+                    self.push_mouse_event(true, MouseButton::Left);
+                    self.mouse.button_left = true;
+                }
+                PointerPhase::Move => {}
+                PointerPhase::End => {
+                    self.push_mouse_event(false, MouseButton::Left);
+                    self.mouse.button_left = false;
+                    self.mouse.finger = None;
+                }
+            }
+        }
     }
 
+    // Orbital provides no touch events.
+    #[cfg(target_os = "redox")]
+    fn drain_finger_events(&mut self) {}
+
     /// Check if the window is open.
     pub fn is_open(&self) -> bool {
         !self.close
     }
 
+    /// Marks the window as closed. It will be removed from the shell on the next run.
+    pub fn close(&mut self) {
+        self.close = true;
+    }
+
+    /// Gets the id of the window.
+    pub fn id(&self) -> WindowId {
+        self.id
+    }
+
+    /// Gets the id of the window that owns this window.
+    pub fn owner(&self) -> Option<WindowId> {
+        self.owner
+    }
+
+    /// Returns `true` if the window blocks the input of its owner.
+    pub fn is_modal(&self) -> bool {
+        self.modal
+    }
+
+    /// Blocks or unblocks the input of the window.
+    pub fn set_blocked(&mut self, blocked: bool) {
+        self.blocked = blocked;
+    }
+
+    /// Sets the id of the window, its owner and its modality.
+    pub(crate) fn set_relation(&mut self, id: WindowId, owner: Option<WindowId>, modal: bool) {
+        self.id = id;
+        self.owner = owner;
+        self.modal = modal;
+    }
+
     fn push_key_event(&mut self, key_event: orbclient::KeyEvent) {
         let mut key = Key::from(key_event.character);
         let state = {
@@ -284,11 +434,24 @@ where
                     orbclient::K_DOWN => key = Key::Down,
                     orbclient::K_ENTER => key = Key::Enter,
                     orbclient::K_ESC => key = Key::Escape,
+                    orbclient::K_F1 => key = Key::F1,
+                    orbclient::K_F2 => key = Key::F2,
+                    orbclient::K_F3 => key = Key::F3,
+                    orbclient::K_F4 => key = Key::F4,
+                    orbclient::K_F5 => key = Key::F5,
+                    orbclient::K_F6 => key = Key::F6,
+                    orbclient::K_F7 => key = Key::F7,
+                    orbclient::K_F8 => key = Key::F8,
+                    orbclient::K_F9 => key = Key::F9,
+                    orbclient::K_F10 => key = Key::F10,
+                    orbclient::K_F11 => key = Key::F11,
+                    orbclient::K_F12 => key = Key::F12,
                     orbclient::K_HOME => {
                         key = Key::Home;
                     }
                     orbclient::K_LEFT => key = Key::Left,
                     orbclient::K_LEFT_SHIFT => key = Key::ShiftL,
+                    K_MENU => key = Key::Menu,
                     orbclient::K_RIGHT => key = Key::Right,
                     orbclient::K_RIGHT_SHIFT => key = Key::ShiftR,
                     orbclient::K_TAB => key = Key::Tab,
@@ -332,6 +495,12 @@ where
                         self.update = true;
                         self.redraw.store(true, Ordering::Relaxed)
                     }
+                    WindowRequest::Resize(width, height) => {
+                        self.window.set_size(width, height);
+                        self.render_context.resize(width as f64, height as f64);
+                        self.update = true;
+                        self.redraw.store(true, Ordering::Relaxed)
+                    }
                     WindowRequest::Close => {
                         self.close = true;
                     }
@@ -2,8 +2,8 @@ use std::{collections::HashMap, sync::mpsc};
 
 use super::{Shell, Window};
 use crate::{
-    render::RenderContext2D, utils::Rectangle, window_adapter::WindowAdapter, WindowRequest,
-    WindowSettings,
+    render::RenderContext2D, utils::Rectangle, window_adapter::WindowAdapter, WindowId,
+    WindowRequest, WindowSettings,
 };
 
 /// The `WindowBuilder` is used to construct an os independent window
@@ -17,6 +17,9 @@ where
     borderless: bool,
     bounds: Rectangle,
     fonts: HashMap<String, &'static [u8]>,
+    id: WindowId,
+    modal: bool,
+    owner: Option<WindowId>,
     request_receiver: Option<mpsc::Receiver<WindowRequest>>,
     resizeable: bool,
     shell: &'a mut Shell<A>,
@@ -35,6 +38,9 @@ where
             borderless: settings.borderless,
             bounds: Rectangle::new(settings.position, (settings.size.0, settings.size.1)),
             fonts: settings.fonts,
+            id: settings.id,
+            modal: settings.modal,
+            owner: settings.owner,
             request_receiver: None,
             resizeable: settings.resizeable,
             shell,
@@ -92,12 +98,11 @@ where
             render_context.register_font(&family, font);
         }
 
-        self.shell.window_shells.push(Window::new(
-            self.adapter,
-            render_context,
-            self.request_receiver,
-            window,
-        ));
+        let mut window_shell =
+            Window::new(self.adapter, render_context, self.request_receiver, window);
+        window_shell.set_relation(self.id, self.owner, self.modal);
+
+        self.shell.window_shells.push(window_shell);
     }
 
     /// Registers a new font via a string that will identify the font family.
@@ -114,6 +119,9 @@ where
             borderless: false,
             bounds: Rectangle::new((0.0, 0.0), (100.0, 75.0)),
             fonts: HashMap::new(),
+            id: WindowId::new(),
+            modal: false,
+            owner: None,
             request_receiver: None,
             resizeable: false,
             shell,
@@ -121,6 +129,24 @@ where
         }
     }
 
+    /// Sets the id of the window.
+    pub fn id(mut self, id: WindowId) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// Marks the window as modal. A modal window blocks the input of its owner.
+    pub fn modal(mut self, modal: bool) -> Self {
+        self.modal = modal;
+        self
+    }
+
+    /// Sets the window that owns this window. An owned window is closed together with its owner.
+    pub fn owner(mut self, owner: impl Into<Option<WindowId>>) -> Self {
+        self.owner = owner.into();
+        self
+    }
+
     /// Mark window as resizeable.
     pub fn resizeable(mut self, resizeable: bool) -> Self {
         self.resizeable = resizeable;
@@ -4,12 +4,15 @@ use stdweb::{
     js,
     traits::*,
     unstable::TryInto,
-    web::{document, event, html_element::CanvasElement, window, CanvasRenderingContext2d},
+    web::{document, event, html_element::CanvasElement, window, CanvasRenderingContext2d, Touch},
 };
 
 use super::EventState;
 use crate::{
-    event::{ButtonState, Key, KeyEvent, MouseButton, MouseEvent},
+    event::{
+        ButtonState, Key, KeyEvent, MouseButton, MouseEvent, PointerEvent, PointerKind,
+        PointerPhase,
+    },
     render::RenderContext2D,
     window_adapter::WindowAdapter,
     WindowRequest,
@@ -127,37 +130,44 @@ where
             self.update = true;
         }
 
-        // TODO: cleanup given temporary solution to map touch events to mouse event
+        // the first changed touch is also mapped to the mouse, so the widgets without touch
+        // handling could still be clicked
         while let Some(event) = self.event_state.touch_start_events.borrow_mut().pop() {
-            self.adapter.mouse_event(MouseEvent {
-                position: Point::new(
-                    event.changed_touches()[0].client_x() as f64,
-                    event.changed_touches()[0].client_y() as f64,
-                ),
-                button: MouseButton::Left,
-                state: ButtonState::Down,
-            });
+            let touches = event.changed_touches();
+            self.push_pointer_events(&touches, PointerPhase::Begin);
+
+            if let Some(touch) = touches.first() {
+                self.adapter.mouse_event(MouseEvent {
+                    position: Point::new(touch.client_x() as f64, touch.client_y() as f64),
+                    button: MouseButton::Left,
+                    state: ButtonState::Down,
+                });
+            }
             self.update = true;
         }
 
         while let Some(event) = self.event_state.touch_end_events.borrow_mut().pop() {
-            self.adapter.mouse_event(MouseEvent {
-                position: Point::new(
-                    event.changed_touches()[0].client_x() as f64,
-                    event.changed_touches()[0].client_y() as f64,
-                ),
-                button: MouseButton::Left,
-                state: ButtonState::Up,
-            });
-
+            let touches = event.changed_touches();
+            self.push_pointer_events(&touches, PointerPhase::End);
+
+            if let Some(touch) = touches.first() {
+                self.adapter.mouse_event(MouseEvent {
+                    position: Point::new(touch.client_x() as f64, touch.client_y() as f64),
+                    button: MouseButton::Left,
+                    state: ButtonState::Up,
+                });
+            }
             self.update = true;
         }
 
         while let Some(event) = self.event_state.touch_move_events.borrow_mut().pop() {
-            self.adapter.mouse(
-                event.changed_touches()[0].client_x() as f64,
-                event.changed_touches()[0].client_y() as f64,
-            );
+            let touches = event.changed_touches();
+            self.push_pointer_events(&touches, PointerPhase::Move);
+
+            if let Some(touch) = touches.first() {
+                self.adapter
+                    .mouse(touch.client_x() as f64, touch.client_y() as f64);
+            }
             self.update = true;
         }
 
@@ -248,6 +258,18 @@ where
     }
 
     /// Receives window request from the application and handles them.
+    // Sends a pointer event for each of the given touches.
+    fn push_pointer_events(&mut self, touches: &[Touch], phase: PointerPhase) {
+        for touch in touches {
+            self.adapter.pointer_event(PointerEvent {
+                id: touch.identifier() as u64,
+                kind: PointerKind::Touch,
+                phase,
+                position: Point::new(touch.client_x() as f64, touch.client_y() as f64),
+            });
+        }
+    }
+
     pub fn receive_requests(&mut self) {
         if let Some(request_receiver) = &self.request_receiver {
             for request in request_receiver.try_iter() {
@@ -261,6 +283,11 @@ where
                         self.update = true;
                         self.redraw = true;
                     }
+                    // the browser window could not be resized
+                    WindowRequest::Resize(..) => {
+                        self.update = true;
+                        self.redraw = true;
+                    }
                     WindowRequest::Close => {
                         self.close = true;
                     }
@@ -302,7 +329,9 @@ fn get_mouse_button(button: event::MouseButton) -> MouseButton {
     match button {
         event::MouseButton::Wheel => MouseButton::Middle,
         event::MouseButton::Right => MouseButton::Right,
-        _ => MouseButton::Left,
+        event::MouseButton::Button4 => MouseButton::Back,
+        event::MouseButton::Button5 => MouseButton::Forward,
+        event::MouseButton::Left => MouseButton::Left,
     }
 }
 
@@ -322,10 +351,23 @@ fn get_key(code: &str, key: String) -> (Key, String) {
             text = key.clone();
             Key::from(key.chars().next().unwrap())
         }
+        "ContextMenu" => Key::Menu,
         "ControlLeft" | "ControlRight" => Key::Control,
         "Delete" => Key::Delete,
         "Enter" => Key::Enter,
         "Escape" => Key::Escape,
+        "F1" => Key::F1,
+        "F2" => Key::F2,
+        "F3" => Key::F3,
+        "F4" => Key::F4,
+        "F5" => Key::F5,
+        "F6" => Key::F6,
+        "F7" => Key::F7,
+        "F8" => Key::F8,
+        "F9" => Key::F9,
+        "F10" => Key::F10,
+        "F11" => Key::F11,
+        "F12" => Key::F12,
         "OSLeft" | "OSRight" => Key::Home,
         "ShiftLeft" => Key::ShiftL,
         "ShiftRight" => Key::ShiftR,
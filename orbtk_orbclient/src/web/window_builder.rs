@@ -168,6 +168,11 @@ where
             .body()
             .unwrap()
             .add_event_listener(move |e: event::MouseUpEvent| {
+                // the back and forward buttons should not navigate away from the application
+                if let event::MouseButton::Button4 | event::MouseButton::Button5 = e.button() {
+                    e.prevent_default();
+                }
+
                 if !mouse_blocked_c.get() {
                     mouse_up_c.borrow_mut().push(e);
                 }
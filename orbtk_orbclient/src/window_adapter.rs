@@ -15,6 +15,13 @@ pub trait WindowAdapter {
     /// This method is called when a file is dropped on the window.
     fn file_drop_event(&mut self, file_name: String);
 
+    /// Is called if the user requests to close the window e.g. by clicking the close button
+    /// of the window decoration. Returns `true` if the window should be closed immediately.
+    /// If `false` is returned, the window stays open until it receives a `WindowRequest::Close`.
+    fn close_requested(&mut self) -> bool {
+        true
+    }
+
     /// Is called after the state of a keyboard key is changed.
     fn key_event(&mut self, _event: KeyEvent) {}
 
@@ -27,6 +34,9 @@ pub trait WindowAdapter {
     /// Gets the current mouse position.
     fn mouse_position(&self) -> Point;
 
+    /// Is called if a pointer e.g. a finger on a touch screen begins, moves or ends its contact.
+    fn pointer_event(&mut self, _event: PointerEvent) {}
+
     /// Is called after the quit event of the window is called.
     fn quit_event(&mut self) {}
 
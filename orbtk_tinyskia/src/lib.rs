@@ -53,6 +53,9 @@ impl Default for RenderConfig {
 pub struct TextMetrics {
     pub width: f64,
     pub height: f64,
+
+    /// Distance between the top of the text and its baseline.
+    pub ascent: f64,
 }
 
 /// Internal font helper.
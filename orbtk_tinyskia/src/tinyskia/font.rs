@@ -80,12 +80,19 @@ impl Font {
         (width, pixel_height)
     }
 
+    /// Returns the distance between the top of a text line and its baseline (given in pixels).
+    pub fn ascent(&self, size: f64) -> f64 {
+        let scale = rusttype::Scale::uniform(size as f32);
+        self.inner.v_metrics(scale).ascent as f64
+    }
+
     /// Renders the given text object.
     pub fn render_text(
         &self,
         font_size: f64,
         paint: &Paint,
         pixmap: &mut Pixmap,
+        transform: Transform,
         position: (f64, f64),
         text: &str,
     ) {
@@ -118,7 +125,7 @@ impl Font {
             g.build_outline(&mut glyph_tracer);
         }
         if let Some(path) = glyph_tracer.path_builder.finish() {
-            pixmap.fill_path(&path, paint, FillRule::Winding, Transform::identity(), None);
+            pixmap.fill_path(&path, paint, FillRule::Winding, transform, None);
         }
     }
 }
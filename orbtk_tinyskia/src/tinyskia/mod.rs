@@ -183,7 +183,7 @@ impl RenderContext2D {
                 )
                 .unwrap(),
                 &self.fill_paint,
-                tiny_skia::Transform::identity(),
+                self.transform,
                 None,
             );
         }
@@ -389,7 +389,7 @@ impl RenderContext2D {
             y as i32,
             pixmap.as_ref(),
             &PixmapPaint::default(),
-            Transform::identity(),
+            self.transform,
             None,
         );
     }
@@ -424,7 +424,7 @@ impl RenderContext2D {
             y as i32,
             pixmap.as_ref(),
             &PixmapPaint::default(),
-            Transform::identity(),
+            self.transform,
             None,
         );
     }
@@ -442,7 +442,7 @@ impl RenderContext2D {
                 &path,
                 &self.fill_paint,
                 FillRule::EvenOdd,
-                Transform::identity(),
+                self.transform,
                 None,
             );
         }
@@ -465,6 +465,7 @@ impl RenderContext2D {
                 self.config.font_config.font_size,
                 &self.fill_paint,
                 &mut self.pixmap,
+                self.transform,
                 (x, y),
                 text,
             );
@@ -503,6 +504,7 @@ impl RenderContext2D {
 
             text_metrics.width = width;
             text_metrics.height = height;
+            text_metrics.ascent = font.ascent(self.config.font_config.font_size);
         }
 
         text_metrics
@@ -538,7 +540,7 @@ impl RenderContext2D {
                     width: self.config.line_width as f32,
                     ..Default::default()
                 },
-                Transform::identity(),
+                self.transform,
                 None,
             );
         }
@@ -573,6 +575,32 @@ impl RenderContext2D {
         self.config.line_width = line_width;
     }
 
+    // Transformations
+
+    /// Adds a translation transformation to the current matrix.
+    pub fn translate(&mut self, x: f64, y: f64) {
+        self.transform = self.transform.pre_translate(x as f32, y as f32);
+    }
+
+    /// Adds a scaling transformation to the current matrix.
+    pub fn scale(&mut self, x: f64, y: f64) {
+        self.transform = self.transform.pre_scale(x as f32, y as f32);
+    }
+
+    /// Replaces the current matrix with the given transformation matrix. The arguments
+    /// describe the horizontal scaling (a), vertical skewing (b), horizontal skewing (c),
+    /// vertical scaling (d) and the translation (e, f).
+    #[allow(clippy::many_single_char_names)]
+    pub fn set_transform(&mut self, a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) {
+        self.transform =
+            Transform::from_row(a as f32, b as f32, c as f32, d as f32, e as f32, f as f32);
+    }
+
+    /// Resets the current matrix to the identity matrix.
+    pub fn reset_transform(&mut self) {
+        self.transform = Transform::identity();
+    }
+
     // Fill and stroke style
 
     /// Specifies the fill color to use inside shapes.
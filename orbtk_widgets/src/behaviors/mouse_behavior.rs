@@ -1,10 +1,11 @@
-use crate::{api::prelude::*, proc_macros::*};
+use crate::{api::prelude::*, proc_macros::*, shell::prelude::Key};
 
 #[derive(Debug, Copy, Clone)]
 enum Action {
     Press(Mouse),
     Release(Mouse),
     Scroll(Point),
+    Activate(Key),
 }
 
 /// The `MouseBehaviorState` handles the `MouseBehavior` widget.
@@ -28,22 +29,25 @@ impl State for MouseBehaviorState {
         for message in messages.read::<Action>() {
             match message {
                 Action::Press(_) => {
-                    ctx.get_widget(self.target).set("pressed", true);
-                    toggle_flag("pressed", &mut ctx.get_widget(self.target));
+                    // keeps receiving the mouse events if the mouse is moved outside while pressed
+                    let entity = ctx.entity();
+                    ctx.capture_mouse(entity);
                 }
                 Action::Release(p) => {
-                    if !*MouseBehavior::pressed_ref(&ctx.widget()) {
-                        return;
+                    // only a press on the behavior is completed to a click, the capture is lost
+                    // if the press is cancelled
+                    if ctx.mouse_capture() != Some(ctx.entity()) {
+                        continue;
                     }
 
-                    ctx.get_widget(self.target).set("pressed", false);
-                    toggle_flag("pressed", &mut ctx.get_widget(self.target));
+                    ctx.release_mouse();
 
                     if check_mouse_condition(p.position, &ctx.widget()) {
                         ctx.event_adapter().push_event(
                             self.target,
                             ClickEvent {
                                 position: p.position,
+                                click_count: p.click_count,
                             },
                         );
                     }
@@ -52,6 +56,32 @@ impl State for MouseBehaviorState {
                     MouseBehavior::position_set(&mut ctx.widget(), p);
                     self.has_delta = true;
                 }
+                Action::Activate(key) => {
+                    if !matches!(key, Key::Space | Key::Enter | Key::NumpadEnter) {
+                        continue;
+                    }
+
+                    // only the focused target could be clicked by keyboard
+                    let target = ctx.get_widget(self.target);
+                    if !target.try_get::<bool>("focused").map_or(false, |f| *f)
+                        || !*target.get::<bool>("enabled")
+                    {
+                        continue;
+                    }
+
+                    let bounds = *target.get::<Rectangle>("bounds");
+                    let mut position = *target.get::<Point>("position");
+                    position.set_x(position.x() + bounds.width() / 2.0);
+                    position.set_y(position.y() + bounds.height() / 2.0);
+
+                    ctx.event_adapter().push_event(
+                        self.target,
+                        ClickEvent {
+                            position,
+                            click_count: 1,
+                        },
+                    );
+                }
             }
 
             ctx.get_widget(self.target).update(false);
@@ -70,14 +100,14 @@ widget!(
     /// The `MouseBehavior` widget will take care to handle the actions,
     /// that should be triggered if the mouse pressed event is triggered.
     ///
+    /// If the target is focused, pressing `Space` or `Enter` triggers a click on the target.
+    /// While pressed, the behavior captures the mouse, so the release is also noticed outside of the target.
+    ///
     /// **style:** `check_box`
-    MouseBehavior<MouseBehaviorState>: MouseHandler {
+    MouseBehavior<MouseBehaviorState>: MouseHandler, KeyDownHandler {
         /// Sets or shares the target of the behavior.
         target: u32,
 
-        /// Sets or shares the pressed property.
-        pressed: bool,
-
         /// Sets or shares the (wheel, scroll) delta property.
         delta: Point
     }
@@ -87,7 +117,6 @@ impl Template for MouseBehavior {
     fn template(self, id: Entity, _: &mut BuildContext) -> Self {
         self.name("MouseBehavior")
             .delta(0.0)
-            .pressed(false)
             .on_mouse_down(move |ctx, m| {
                 ctx.send_message(Action::Press(m), id);
                 false
@@ -99,5 +128,9 @@ impl Template for MouseBehavior {
                 ctx.send_message(Action::Scroll(p), id);
                 false
             })
+            .on_key_down(move |ctx, event| {
+                ctx.send_message(Action::Activate(event.key), id);
+                false
+            })
     }
 }
@@ -14,8 +14,6 @@ pub struct SelectionBehaviorState {
 impl State for SelectionBehaviorState {
     fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
         self.target = (*ctx.widget().get::<u32>("target")).into();
-        toggle_flag("selected", &mut ctx.get_widget(self.target));
-        ctx.get_widget(self.target).update(false);
     }
 
     fn messages(
@@ -29,8 +27,6 @@ impl State for SelectionBehaviorState {
                 SelectionAction::ToggleSelection => {
                     let selected = *ctx.get_widget(self.target).get::<bool>("selected");
                     ctx.get_widget(self.target).set("selected", !selected);
-                    toggle_flag("selected", &mut ctx.get_widget(self.target));
-                    ctx.get_widget(self.target).update(false);
                 }
             };
         }
@@ -6,7 +6,7 @@ widget!(
     /// The `Button` widget can be clicked by user. It's used to perform an action.
     ///
     /// **style:** `button`
-    Button: MouseHandler {
+    Button: MouseHandler, ContextMenuHandler {
         /// Sets or shares the background property.
         background: Brush,
 
@@ -46,15 +46,12 @@ widget!(
         /// Sets or shares the icon font property.
         icon_font: String,
 
-        /// Sets or shares the pressed property.
-        pressed: bool,
+        /// Indicates if the widget has the keyboard focus.
+        focused: bool,
 
         /// Sets or shares the spacing between icon and text.
         spacing: f64,
 
-        /// Indicates if the widget is hovered by the mouse cursor.
-        hover: bool,
-
         /// Defines the margin around the inner border.
         container_margin: Thickness
     }
@@ -79,12 +76,12 @@ impl Template for Button {
             .icon_font("MaterialIcons-Regular")
             .icon_size(orbtk_fonts::ICON_FONT_SIZE_12)
             .icon_brush(colors::LINK_WATER_COLOR)
-            .pressed(false)
+            .focused(false)
+            .focusable(true)
             .spacing(8.0)
             .container_margin(0)
             .child(
                 MouseBehavior::new()
-                    .pressed(id)
                     .enabled(id)
                     .target(id.0)
                     .child(
@@ -45,14 +45,11 @@ widget!(
         /// Sets or shares the icon font property.
         icon_font: String,
 
-        /// Sets or shares the pressed property.
-        pressed: bool,
+        /// Indicates if the widget has the keyboard focus.
+        focused: bool,
 
         /// Sets or shares the selected property.
-        selected: bool,
-
-        /// Indicates if the widget is hovered by the mouse cursor.
-        hover: bool
+        selected: bool
     }
 );
 
@@ -75,10 +72,10 @@ impl Template for CheckBox {
             .icon_font("MaterialIcons-Regular")
             .icon_size(orbtk_fonts::ICON_FONT_SIZE_12)
             .icon_brush(colors::LINK_WATER_COLOR)
-            .pressed(false)
+            .focused(false)
+            .focusable(true)
             .child(
                 MouseBehavior::new()
-                    .pressed(id)
                     .enabled(id)
                     .target(id.0)
                     .child(
@@ -69,18 +69,10 @@ impl State for ComboBoxItemState {
             .clone::<SelectedItem>("selected_item")
         {
             ctx.get_widget(item).set("selected", false);
-            ctx.get_widget(item)
-                .get_mut::<Selector>("selector")
-                .remove_state("selected");
-            ctx.get_widget(item).update(false);
         }
 
         // update new selected item status.
         ctx.widget().set("selected", true);
-        ctx.widget()
-            .get_mut::<Selector>("selector")
-            .push_state("selected");
-        ctx.widget().update(false);
         ctx.get_widget(self.combo_box)
             .set("selected_index", self.index as i32);
         ctx.get_widget(self.combo_box)
@@ -139,18 +131,9 @@ widget!(
         /// Sets or shares the font property.
         font: String,
 
-        /// Indicates if the widget is hovered by the mouse cursor.
-        hover: bool,
-
         /// Sets or shares the padding property.
         padding: Thickness,
 
-        /// Sets or shares the pressed property.
-        ///
-        /// The boolean indicates, if an item inside
-        /// the item list was pressed.
-        pressed: bool,
-
         /// Sets or shares the selected property.
         ///
         /// The boolean indicates that the status
@@ -199,15 +182,8 @@ impl Template for ComboBoxItem {
             .font("Roboto-Regular")
             .min_width(64)
             .padding(0)
-            .pressed(false)
             .selected(false)
-            .child(
-                MouseBehavior::new()
-                    .pressed(id)
-                    .enabled(id)
-                    .target(id.0)
-                    .build(ctx),
-            )
+            .child(MouseBehavior::new().enabled(id).target(id.0).build(ctx))
             .on_click(move |states, _| {
                 states.get::<ComboBoxItemState>(id).toggle_selection();
                 false
@@ -267,13 +243,9 @@ impl ComboBoxState {
 
         if !combo_box_global_bounds.contains(p) {
             ctx.widget().set("selected", false);
-            ctx.widget()
-                .get_mut::<Selector>("selector")
-                .remove_state("selected");
             ctx.get_widget(self.popup)
                 .set("visibility", Visibility::Collapsed);
             ctx.get_widget(self.popup).update(false);
-            ctx.widget().update(false);
         }
     }
 
@@ -290,9 +262,6 @@ impl ComboBoxState {
         // open the popup if `mouse down` point is inside of the ComboBox bounds.
         if combo_box_global_bounds.contains(p) {
             ctx.widget().set("selected", true);
-            ctx.widget()
-                .get_mut::<Selector>("selector")
-                .push_state("selected");
             ctx.get_widget(self.popup)
                 .set("visibility", Visibility::Visible);
             ctx.get_widget(self.popup).update(true);
@@ -356,11 +325,6 @@ impl State for ComboBoxState {
                             mouse_behavior,
                             item,
                         );
-                        build_context.register_shared_property::<bool>(
-                            "pressed",
-                            mouse_behavior,
-                            item,
-                        );
                         build_context.append_child(item, mouse_behavior);
 
                         build_context.register_shared_property::<Brush>("foreground", child, item);
@@ -469,9 +433,6 @@ widget!(
         /// Sets or shares the font property.
         font: String,
 
-        /// Indicates if the widget is hovered by the mouse cursor.
-        hover: bool,
-
         /// Sets or shares the icon property.
         icon: String,
 
@@ -497,12 +458,6 @@ widget!(
         /// [`placement`]: ../orbtk_core/render_object/enum.Placement.html
         placement: Placement,
 
-        /// Sets or shares the pressed property.
-        ///
-        /// The boolean indicates, if the selection popup
-        /// should be toggled.
-        pressed: bool,
-
         /// Sets or shares the offset property.
         ///
         /// The offset value assignes a margin between the selection box
@@ -603,11 +558,11 @@ impl Template for ComboBox {
             .icon_brush(colors::LINK_WATER_COLOR)
             .height(32.0)
             .min_width(40.0)
+            .focusable(true)
             .selected(false)
             .selected_index(-1)
             .child(
                 MouseBehavior::new()
-                    .pressed(id)
                     .enabled(id)
                     .target(id.0)
                     .child(
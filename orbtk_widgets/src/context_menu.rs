@@ -0,0 +1,725 @@
+use super::behaviors::MouseBehavior;
+
+use crate::{
+    api::prelude::*, prelude::*, proc_macros::*, shell::prelude::Key, themes::theme_orbtk::*,
+};
+
+/// Use this enum to open or close a `ContextMenu`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ContextMenuAction {
+    /// Opens the menu with its top left corner at the given position, e.g. the position of a
+    /// `ContextMenuEvent`. If there is not enough space the menu is moved into the window.
+    Open(Point),
+
+    /// Closes the menu and all of its open submenus.
+    Close,
+}
+
+// Internal actions of the context menu.
+#[derive(Clone, Copy, Debug)]
+enum MenuAction {
+    // Opens a submenu next to the (global) bounds of the item that owns it.
+    OpenSubmenu {
+        item: Rectangle,
+        parent: Entity,
+        select_first: bool,
+    },
+
+    // Informs the parent menu about an opened submenu.
+    SubmenuOpened(Entity),
+
+    // Informs the parent menu about a closed submenu.
+    SubmenuClosed(Entity),
+
+    // Closes the menu and all of its parent menus.
+    CloseAll,
+
+    Key(Key),
+    MouseMove(Point),
+    MouseUp(Point),
+}
+
+// Internal actions of the menu item.
+#[derive(Clone, Copy, Debug)]
+enum MenuItemAction {
+    // Opens the submenu of the item or closes all menus if the item has no submenu.
+    Activate,
+
+    // Opens the submenu of the item if it has one.
+    OpenSubmenu { select_first: bool },
+}
+
+// Keyboard commands of a menu.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum MenuKey {
+    // Moves the selection by the given step.
+    Move(i32),
+
+    // Opens the submenu of the selected item.
+    OpenSubmenu,
+
+    // Closes the menu.
+    Close,
+
+    // Clicks the selected item.
+    Click,
+}
+
+impl MenuKey {
+    // Returns the command of the given key. The root menu is only closed by escape, a submenu
+    // also by left.
+    fn from_key(key: Key, is_submenu: bool) -> Option<MenuKey> {
+        match key {
+            Key::Up => Some(MenuKey::Move(-1)),
+            Key::Down => Some(MenuKey::Move(1)),
+            Key::Right => Some(MenuKey::OpenSubmenu),
+            Key::Left if is_submenu => Some(MenuKey::Close),
+            Key::Escape => Some(MenuKey::Close),
+            Key::Enter | Key::Space => Some(MenuKey::Click),
+            _ => None,
+        }
+    }
+}
+
+// Returns the selected index after moving the selection by the given step. The selection wraps
+// around at the first and the last item, without a selection up selects the last item.
+fn moved_index(index: i32, step: i32, count: i32) -> i32 {
+    if count == 0 {
+        return -1;
+    }
+
+    if index < 0 && step < 0 {
+        count - 1
+    } else {
+        (index + step).rem_euclid(count)
+    }
+}
+
+// Describes where an opened menu is placed after the layout.
+#[derive(Clone, Copy, Debug)]
+enum MenuPlacement {
+    // The top left corner is placed at the point, or the menu is flipped if there is no space.
+    Point(Point),
+
+    // The menu is placed right of the item, or left of it if there is no space.
+    Item(Rectangle),
+}
+
+/// The `MenuItemState` opens the submenu of a `MenuItem`.
+#[derive(Default, AsAny)]
+pub struct MenuItemState {
+    submenu: Option<Entity>,
+}
+
+impl MenuItemState {
+    // Sends the open request to the submenu. Returns `false` if the item has no submenu.
+    fn open_submenu(&self, ctx: &mut Context, select_first: bool) -> bool {
+        let submenu = match self.submenu {
+            Some(submenu) => submenu,
+            None => return false,
+        };
+
+        if let Some(menu) = ctx.entity_of_parent() {
+            let position: Point = ctx.widget().clone("position");
+            let mut item: Rectangle = ctx.widget().clone("bounds");
+            item.set_position(position);
+
+            ctx.send_message(
+                MenuAction::OpenSubmenu {
+                    item,
+                    parent: menu,
+                    select_first,
+                },
+                submenu,
+            );
+        }
+
+        true
+    }
+}
+
+impl State for MenuItemState {
+    fn messages(
+        &mut self,
+        mut messages: MessageReader,
+        _registry: &mut Registry,
+        ctx: &mut Context,
+    ) {
+        for action in messages.read::<MenuItemAction>() {
+            match action {
+                MenuItemAction::Activate => {
+                    if !self.open_submenu(ctx, false) {
+                        if let Some(menu) = ctx.entity_of_parent() {
+                            ctx.send_message(MenuAction::CloseAll, menu);
+                        }
+                    }
+                }
+                MenuItemAction::OpenSubmenu { select_first } => {
+                    self.open_submenu(ctx, select_first);
+                }
+            }
+        }
+    }
+}
+
+widget!(
+    /// The `MenuItem` describes an entry of a `ContextMenu`. Use its `on_click` handler to
+    /// perform the action of the entry. After the click all open menus are closed, except the
+    /// item has a submenu, then the submenu is opened.
+    ///
+    /// **style:** `menu_item`
+    MenuItem<MenuItemState>: MouseHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: f64,
+
+        /// Sets or shares the foreground property.
+        foreground: Brush,
+
+        /// Sets or shares the text property.
+        text: String,
+
+        /// Sets or shares the text of the keyboard shortcut that is displayed right of the text,
+        /// e.g. `Ctrl+C`.
+        shortcut: String,
+
+        /// Sets or share the font size property.
+        font_size: f64,
+
+        /// Sets or shares the font property.
+        font: String,
+
+        /// Sets or shares the icon brush of the submenu arrow.
+        icon_brush: Brush,
+
+        /// Sets or share the icon font size of the submenu arrow.
+        icon_size: f64,
+
+        /// Sets or shares the icon font of the submenu arrow.
+        icon_font: String,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Indicates if the item is selected by the mouse or the keyboard.
+        selected: bool
+    }
+);
+
+impl MenuItem {
+    /// Sets the submenu that is opened next to the item. The submenu is a `ContextMenu` that is
+    /// appended to the overlay by the item.
+    pub fn submenu(mut self, submenu: Entity) -> Self {
+        self.state_mut().submenu = Some(submenu);
+        self
+    }
+}
+
+impl Template for MenuItem {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let arrow = match self.state().submenu {
+            Some(submenu) => {
+                ctx.append_child_to_overlay(submenu)
+                    .expect("MenuItem.template: Could not find overlay.");
+                material_icons_font::MD_CHEVRON_RIGHT
+            }
+            None => "",
+        };
+
+        self.name("MenuItem")
+            .style("menu_item")
+            .height(28.0)
+            .min_width(120.0)
+            .background("transparent")
+            .border_radius(0.0)
+            .foreground(colors::LINK_WATER_COLOR)
+            .text("")
+            .shortcut("")
+            .font_size(orbtk_fonts::FONT_SIZE_12)
+            .font("Roboto-Regular")
+            .icon_font("MaterialIcons-Regular")
+            .icon_size(orbtk_fonts::ICON_FONT_SIZE_12)
+            .icon_brush(colors::LINK_WATER_COLOR)
+            .padding((8.0, 0.0, 4.0, 0.0))
+            .selected(false)
+            .on_preview::<ClickEvent, _>(move |states, _| {
+                states.send_message(MenuItemAction::Activate, id);
+                false
+            })
+            .child(
+                MouseBehavior::new()
+                    .enabled(id)
+                    .target(id.0)
+                    .child(
+                        Container::new()
+                            .background(id)
+                            .border_radius(id)
+                            .padding(id)
+                            .opacity(id)
+                            .child(
+                                Grid::new()
+                                    .columns("*, 24, auto, 16")
+                                    .child(
+                                        TextBlock::new()
+                                            .v_align("center")
+                                            .foreground(id)
+                                            .text(id)
+                                            .font_size(id)
+                                            .font(id)
+                                            .opacity(id)
+                                            .build(ctx),
+                                    )
+                                    .child(
+                                        TextBlock::new()
+                                            .attach(Grid::column(2))
+                                            .v_align("center")
+                                            .foreground(id)
+                                            .text(("shortcut", id))
+                                            .font_size(id)
+                                            .font(id)
+                                            .opacity(id)
+                                            .build(ctx),
+                                    )
+                                    .child(
+                                        FontIconBlock::new()
+                                            .attach(Grid::column(3))
+                                            .v_align("center")
+                                            .h_align("end")
+                                            .icon(arrow)
+                                            .icon_brush(id)
+                                            .icon_size(id)
+                                            .icon_font(id)
+                                            .opacity(id)
+                                            .build(ctx),
+                                    )
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
+
+/// The `ContextMenuState` handles the open and close behavior, the placement and the keyboard
+/// navigation of the `ContextMenu` widget.
+#[derive(Default, AsAny)]
+pub struct ContextMenuState {
+    parent: Option<Entity>,
+    submenu: Option<Entity>,
+    placement: Option<MenuPlacement>,
+}
+
+impl ContextMenuState {
+    // Returns the selectable items of the menu.
+    fn items(ctx: &mut Context) -> Vec<Entity> {
+        let mut items = vec![];
+        let mut index = 0;
+
+        while let Some(child) = ctx.try_child_from_index(index) {
+            if child.has::<bool>("selected")
+                && *child.get::<bool>("enabled")
+                && *child.get::<Visibility>("visibility") == Visibility::Visible
+            {
+                items.push(child.entity());
+            }
+            index += 1;
+        }
+
+        items
+    }
+
+    fn selected_item(ctx: &mut Context) -> Option<Entity> {
+        let index = *ContextMenu::selected_index_ref(&ctx.widget());
+
+        if index < 0 {
+            return None;
+        }
+
+        Self::items(ctx).get(index as usize).copied()
+    }
+
+    // Selects the item on the given index of the selectable items, `-1` clears the selection.
+    fn select(ctx: &mut Context, index: i32) {
+        for (i, item) in Self::items(ctx).iter().enumerate() {
+            let mut widget = ctx.get_widget(*item);
+            let selected = i as i32 == index;
+
+            if *widget.get::<bool>("selected") == selected {
+                continue;
+            }
+
+            widget.set("selected", selected);
+        }
+
+        ContextMenu::selected_index_set(&mut ctx.widget(), index);
+    }
+
+    // Moves the selection up or down and wraps around at the first and the last item.
+    fn move_selection(ctx: &mut Context, step: i32) {
+        let count = Self::items(ctx).len() as i32;
+
+        if count == 0 {
+            return;
+        }
+
+        let index = *ContextMenu::selected_index_ref(&ctx.widget());
+        Self::select(ctx, moved_index(index, step, count));
+    }
+
+    fn open(
+        &mut self,
+        ctx: &mut Context,
+        placement: MenuPlacement,
+        parent: Option<Entity>,
+        select_first: bool,
+    ) {
+        self.close_submenu(ctx);
+
+        let position = match placement {
+            MenuPlacement::Point(point) => point,
+            MenuPlacement::Item(item) => Point::new(item.x() + item.width(), item.y()),
+        };
+
+        ctx.widget()
+            .get_mut::<Rectangle>("bounds")
+            .set_position(position);
+        ctx.widget().set("visibility", Visibility::Visible);
+        ContextMenu::open_set(&mut ctx.widget(), true);
+        Self::select(ctx, if select_first { 0 } else { -1 });
+
+        self.placement = Some(placement);
+        self.parent = parent;
+
+        if let Some(parent) = parent {
+            ctx.send_message(MenuAction::SubmenuOpened(ctx.entity()), parent);
+        }
+    }
+
+    // Closes the menu and its submenus. Returns the parent menu.
+    fn close(&mut self, ctx: &mut Context) -> Option<Entity> {
+        if !*ContextMenu::open_ref(&ctx.widget()) {
+            return None;
+        }
+
+        self.close_submenu(ctx);
+        Self::select(ctx, -1);
+        ctx.widget().set("visibility", Visibility::Collapsed);
+        ContextMenu::open_set(&mut ctx.widget(), false);
+
+        let parent = self.parent.take();
+
+        if let Some(parent) = parent {
+            ctx.send_message(MenuAction::SubmenuClosed(ctx.entity()), parent);
+        }
+
+        parent
+    }
+
+    fn close_submenu(&mut self, ctx: &mut Context) {
+        if let Some(submenu) = self.submenu.take() {
+            ctx.send_message(ContextMenuAction::Close, submenu);
+        }
+    }
+
+    fn key_down(&mut self, ctx: &mut Context, key: Key) {
+        let command = match MenuKey::from_key(key, self.parent.is_some()) {
+            Some(command) => command,
+            None => return,
+        };
+
+        match command {
+            MenuKey::Move(step) => Self::move_selection(ctx, step),
+            MenuKey::OpenSubmenu => {
+                if let Some(item) = Self::selected_item(ctx) {
+                    ctx.send_message(MenuItemAction::OpenSubmenu { select_first: true }, item);
+                }
+            }
+            MenuKey::Close => {
+                self.close(ctx);
+            }
+            MenuKey::Click => {
+                if let Some(item) = Self::selected_item(ctx) {
+                    let position: Point = ctx.get_widget(item).clone("position");
+                    let bounds: Rectangle = ctx.get_widget(item).clone("bounds");
+
+                    ctx.event_adapter().push_event(
+                        item,
+                        ClickEvent {
+                            position: Point::new(
+                                position.x() + bounds.width() / 2.0,
+                                position.y() + bounds.height() / 2.0,
+                            ),
+                            click_count: 1,
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    fn mouse_move(&mut self, ctx: &mut Context, position: Point) {
+        let items = Self::items(ctx);
+        let index = items
+            .iter()
+            .position(|item| check_mouse_condition(position, &ctx.get_widget(*item)));
+
+        let index = match index {
+            Some(index) => index as i32,
+            None => return,
+        };
+
+        if index == *ContextMenu::selected_index_ref(&ctx.widget()) {
+            return;
+        }
+
+        self.close_submenu(ctx);
+        Self::select(ctx, index);
+        ctx.send_message(
+            MenuItemAction::OpenSubmenu {
+                select_first: false,
+            },
+            items[index as usize],
+        );
+    }
+
+    // Only the innermost open menu checks the position. If the mouse is released outside of the
+    // menu it is closed and the parent menu repeats the check.
+    fn mouse_up(&mut self, ctx: &mut Context, position: Point) {
+        if self.submenu.is_some() || check_mouse_condition(position, &ctx.widget()) {
+            return;
+        }
+
+        if let Some(parent) = self.close(ctx) {
+            ctx.send_message(MenuAction::MouseUp(position), parent);
+        }
+    }
+}
+
+impl State for ContextMenuState {
+    fn messages(
+        &mut self,
+        mut messages: MessageReader,
+        _registry: &mut Registry,
+        ctx: &mut Context,
+    ) {
+        // the internal actions are handled first. A right click outside of the open menu sends
+        // the mouse up before the request to open the menu again, the mouse up has to close the
+        // menu at its old position and not the reopened menu.
+        for action in messages.read::<MenuAction>() {
+            let open = *ContextMenu::open_ref(&ctx.widget());
+
+            match action {
+                MenuAction::OpenSubmenu {
+                    item,
+                    parent,
+                    select_first,
+                } => self.open(ctx, MenuPlacement::Item(item), Some(parent), select_first),
+                MenuAction::SubmenuOpened(submenu) => {
+                    if self.submenu != Some(submenu) {
+                        self.close_submenu(ctx);
+                        self.submenu = Some(submenu);
+                    }
+                }
+                MenuAction::SubmenuClosed(submenu) => {
+                    if self.submenu == Some(submenu) {
+                        self.submenu = None;
+                    }
+                }
+                MenuAction::CloseAll => {
+                    if let Some(parent) = self.close(ctx) {
+                        ctx.send_message(MenuAction::CloseAll, parent);
+                    }
+                }
+                MenuAction::Key(key) if open => self.key_down(ctx, key),
+                MenuAction::MouseMove(position) if open => self.mouse_move(ctx, position),
+                MenuAction::MouseUp(position) if open => self.mouse_up(ctx, position),
+                _ => {}
+            }
+        }
+
+        for action in messages.read::<ContextMenuAction>() {
+            match action {
+                ContextMenuAction::Open(position) => {
+                    self.open(ctx, MenuPlacement::Point(position), None, false)
+                }
+                ContextMenuAction::Close => {
+                    self.close(ctx);
+                }
+            }
+        }
+    }
+
+    fn update_post_layout(&mut self, _registry: &mut Registry, ctx: &mut Context) {
+        let placement = match self.placement.take() {
+            Some(placement) => placement,
+            None => return,
+        };
+
+        let window: Rectangle = ctx.window().clone("bounds");
+        let size: Rectangle = ctx.widget().clone("bounds");
+
+        let (x, y) = match placement {
+            MenuPlacement::Point(point) => (
+                if point.x() + size.width() > window.width() {
+                    point.x() - size.width()
+                } else {
+                    point.x()
+                },
+                if point.y() + size.height() > window.height() {
+                    point.y() - size.height()
+                } else {
+                    point.y()
+                },
+            ),
+            MenuPlacement::Item(item) => (
+                if item.x() + item.width() + size.width() > window.width() {
+                    item.x() - size.width()
+                } else {
+                    item.x() + item.width()
+                },
+                item.y(),
+            ),
+        };
+
+        // keeps the menu inside of the window
+        let x = x.min(window.width() - size.width()).max(0.0);
+        let y = y.min(window.height() - size.height()).max(0.0);
+
+        ctx.widget()
+            .get_mut::<Rectangle>("bounds")
+            .set_position((x, y));
+    }
+}
+
+widget!(
+    /// The `ContextMenu` presents a list of `MenuItem`s on top of the window. It is appended to
+    /// the overlay and opened by sending `ContextMenuAction::Open` to it, usually from an
+    /// `on_context_menu` handler.
+    ///
+    /// The items are selected with the mouse or the up and down keys. Right opens the submenu of
+    /// the selected item, left and escape close the current menu, enter and space click the
+    /// selected item. The menu is closed if the mouse is released outside of it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let menu = ContextMenu::new()
+    ///     .child(MenuItem::new().text("Copy").shortcut("Ctrl+C").on_click(|_, _| true).build(ctx))
+    ///     .build(ctx);
+    /// ctx.append_child_to_overlay(menu).unwrap();
+    ///
+    /// TextBlock::new()
+    ///     .text("Right click me")
+    ///     .on_context_menu(move |states, position| {
+    ///         states.send_message(ContextMenuAction::Open(position), menu);
+    ///         true
+    ///     })
+    ///     .build(ctx)
+    /// ```
+    ///
+    /// **style:** `context_menu`
+    ContextMenu<ContextMenuState>: KeyDownHandler, MouseHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the border brush property.
+        border_brush: Brush,
+
+        /// Sets or shares the border radius property.
+        border_radius: f64,
+
+        /// Sets or shares the border thickness property.
+        border_width: Thickness,
+
+        /// Sets or shares the orientation property.
+        orientation: Orientation,
+
+        /// Indicates if the menu is open.
+        open: bool,
+
+        /// Sets or shares the index of the selected item.
+        /// Value: "-1" -> no item is selected.
+        selected_index: i32
+    }
+);
+
+impl Template for ContextMenu {
+    fn template(self, id: Entity, _: &mut BuildContext) -> Self {
+        self.name("ContextMenu")
+            .style("context_menu")
+            .background(colors::BRIGHT_GRAY_COLOR)
+            .border_brush(colors::BOMBAY_COLOR)
+            .border_radius(2.0)
+            .border_width(1.0)
+            .orientation("vertical")
+            .open(false)
+            .selected_index(-1)
+            .h_align("start")
+            .v_align("start")
+            .visibility(Visibility::Collapsed)
+            .on_key_down(move |states, event| {
+                // the key is handled by the innermost open menu
+                if states.get::<ContextMenuState>(id).submenu.is_some() {
+                    return false;
+                }
+
+                states.send_message(MenuAction::Key(event.key), id);
+                true
+            })
+            .on_mouse_move(move |states, position| {
+                states.send_message(MenuAction::MouseMove(position), id);
+                false
+            })
+            .on_global_mouse_up(move |states, mouse| {
+                states.send_message(MenuAction::MouseUp(mouse.position), id);
+            })
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        RectangleRenderObject.into()
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        StackLayout::new().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_moved_index() {
+        assert_eq!(moved_index(-1, 1, 3), 0);
+        assert_eq!(moved_index(0, 1, 3), 1);
+        assert_eq!(moved_index(2, 1, 3), 0);
+        assert_eq!(moved_index(0, -1, 3), 2);
+        assert_eq!(moved_index(-1, -1, 3), 2);
+        assert_eq!(moved_index(-1, 1, 0), -1);
+    }
+
+    #[test]
+    fn test_menu_key() {
+        assert_eq!(MenuKey::from_key(Key::Up, false), Some(MenuKey::Move(-1)));
+        assert_eq!(MenuKey::from_key(Key::Down, false), Some(MenuKey::Move(1)));
+        assert_eq!(
+            MenuKey::from_key(Key::Right, false),
+            Some(MenuKey::OpenSubmenu)
+        );
+        assert_eq!(MenuKey::from_key(Key::Enter, false), Some(MenuKey::Click));
+        assert_eq!(MenuKey::from_key(Key::Space, false), Some(MenuKey::Click));
+        assert_eq!(MenuKey::from_key(Key::Tab, false), None);
+    }
+
+    #[test]
+    fn test_menu_key_close() {
+        // left closes only a submenu, escape every menu
+        assert_eq!(MenuKey::from_key(Key::Left, false), None);
+        assert_eq!(MenuKey::from_key(Key::Left, true), Some(MenuKey::Close));
+        assert_eq!(MenuKey::from_key(Key::Escape, false), Some(MenuKey::Close));
+        assert_eq!(MenuKey::from_key(Key::Escape, true), Some(MenuKey::Close));
+    }
+}
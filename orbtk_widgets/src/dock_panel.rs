@@ -0,0 +1,49 @@
+use crate::{api::prelude::*, proc_macros::*};
+
+widget!(
+    /// The `DockPanel` docks each child to the top, bottom, left or right edge of the space
+    /// that is left by the previous children. The last child fills the remaining space if
+    /// `last_child_fill` is `true`.
+    ///
+    /// **style:** `dock_panel`
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// DockPanel::new()
+    ///     .child(ToolBar::new().attach(DockPanel::dock("top")).build(ctx))
+    ///     .child(StatusBar::new().attach(DockPanel::dock("bottom")).build(ctx))
+    ///     .child(SideBar::new().attach(DockPanel::dock("left")).build(ctx))
+    ///     .child(Content::new().build(ctx))
+    ///     .build(ctx)
+    /// ```
+    DockPanel: ContextMenuHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the flag if the last child fills the remaining space.
+        last_child_fill: bool
+
+        attached_properties: {
+            /// Attach the edge the widget is docked to.
+            dock: Dock
+        }
+    }
+);
+
+impl Template for DockPanel {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("DockPanel")
+            .style("dock_panel")
+            .background("transparent")
+            .last_child_fill(true)
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        RectangleRenderObject.into()
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(DockLayout::new())
+    }
+}
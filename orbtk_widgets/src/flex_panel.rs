@@ -0,0 +1,69 @@
+use crate::{api::prelude::*, proc_macros::*};
+
+widget!(
+    /// The `FlexPanel` arranges its children like a CSS flexbox container. The children share
+    /// the free space of a line by their `flex_grow` factors and give up space by their
+    /// `flex_shrink` factors if there is not enough.
+    ///
+    /// **style:** `flex_panel`
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// FlexPanel::new()
+    ///     .gap(4)
+    ///     .align_items("center")
+    ///     .child(Button::new().text("Back").build(ctx))
+    ///     .child(
+    ///         TextBox::new()
+    ///             .attach(FlexPanel::flex_grow(1.0))
+    ///             .attach(FlexPanel::flex_basis(120.0))
+    ///             .build(ctx),
+    ///     )
+    ///     .child(Button::new().text("Go").build(ctx))
+    ///     .build(ctx)
+    /// ```
+    FlexPanel: ContextMenuHandler {
+        /// Sets or shares the main axis and the direction of the children.
+        direction: FlexDirection,
+
+        /// Sets or shares if the children wrap onto multiple lines.
+        wrap: FlexWrap,
+
+        /// Sets or shares the distribution of the free space of a line on the main axis.
+        justify_content: Justification,
+
+        /// Sets or shares the alignment of the children inside of a line on the cross axis.
+        align_items: Alignment,
+
+        /// Sets or shares the space between two children and between two lines.
+        gap: f64
+
+        attached_properties: {
+            /// Attach the share of the free space the child grows by.
+            flex_grow: f64,
+
+            /// Attach the share of the missing space the child shrinks by.
+            flex_shrink: f64,
+
+            /// Attach the main size of the child before growing or shrinking.
+            flex_basis: f64,
+
+            /// Attach an alignment on the cross axis that overwrites `align_items`.
+            align_self: Alignment
+        }
+    }
+);
+
+impl Template for FlexPanel {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("FlexPanel")
+            .direction("row")
+            .align_items("stretch")
+            .style("flex_panel")
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(FlexLayout::new())
+    }
+}
@@ -3,8 +3,21 @@ use crate::{api::prelude::*, proc_macros::*};
 widget!(
     /// The `Grid` defines a flexible grid area that consists of columns and rows.
     ///
+    /// The size of a column or row is `auto` (size of the largest child), a fixed size,
+    /// a percentage of the grid size or a weighted share of the rest size (star sizing).
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Grid::new()
+    ///     .columns("2*, auto, 120, 1*")
+    ///     .rows("50%, *")
+    ///     .column_gap(4.0)
+    ///     .build(ctx)
+    /// ```
+    ///
     /// **style:** `grid`
-    Grid {
+    Grid: ContextMenuHandler, CloseRequestedHandler {
         /// Sets or shares the background property.
         background: Brush,
 
@@ -14,6 +27,12 @@ widget!(
         /// Sets or shares the rows property.
         rows: Blocks,
 
+        /// Sets or shares the space between two columns.
+        column_gap: f64,
+
+        /// Sets or shares the space between two rows.
+        row_gap: f64,
+
         /// Sets or shares the border radius property.
         border_radius: f64
 
@@ -0,0 +1,301 @@
+use crate::{api::prelude::*, proc_macros::*, shell::prelude::Key};
+
+#[derive(Debug, Copy, Clone)]
+enum GridSplitterAction {
+    Press(Point),
+    Move(Point),
+    Release,
+    LostCapture,
+    Key(Key),
+}
+
+/// The `GridSplitterState` resizes the blocks of the parent grid that are next to the block of
+/// the splitter.
+#[derive(Default, AsAny)]
+pub struct GridSplitterState {
+    // position of the mouse and the blocks at the start of a drag
+    drag: Option<(Point, Blocks)>,
+    changed: bool,
+}
+
+impl GridSplitterState {
+    // Returns the grid, the key of the resized blocks and the index of the block of the splitter.
+    fn target(&self, ctx: &mut Context) -> Option<(Entity, &'static str, usize)> {
+        let grid = ctx.entity_of_parent()?;
+        let orientation = *ctx.widget().get::<Orientation>("orientation");
+
+        let (key, index_key) = match orientation {
+            Orientation::Vertical => ("columns", "column"),
+            Orientation::Horizontal => ("rows", "row"),
+        };
+
+        let index = ctx
+            .widget()
+            .try_get::<usize>(index_key)
+            .copied()
+            .unwrap_or_default();
+
+        Some((grid, key, index))
+    }
+
+    fn blocks(&self, ctx: &mut Context) -> Option<Blocks> {
+        let (grid, key, _) = self.target(ctx)?;
+        ctx.get_widget(grid).try_clone::<Blocks>(key)
+    }
+
+    // Resizes the blocks before and after the splitter by the given (visual) delta.
+    fn resize(&mut self, ctx: &mut Context, mut blocks: Blocks, delta: f64) {
+        let (grid, key, index) = match self.target(ctx) {
+            Some(target) => target,
+            None => return,
+        };
+
+        if index == 0 {
+            return;
+        }
+
+        // columns are mirrored in a right-to-left flow
+        let delta = if key == "columns" && ctx.flow_direction().is_right_to_left() {
+            -delta
+        } else {
+            delta
+        };
+
+        blocks.resize(index - 1, index + 1, delta);
+
+        if ctx.get_widget(grid).get::<Blocks>(key) == &blocks {
+            return;
+        }
+
+        ctx.get_widget(grid).set(key, blocks);
+        self.changed = true;
+    }
+
+    // Sends the changed event with the resized blocks.
+    fn finish(&mut self, ctx: &mut Context) {
+        if !self.changed {
+            return;
+        }
+
+        self.changed = false;
+
+        if let Some(blocks) = self.blocks(ctx) {
+            let entity = ctx.entity();
+            ctx.event_adapter()
+                .push_event_direct(entity, SplitterChangedEvent(entity, blocks));
+        }
+    }
+}
+
+impl State for GridSplitterState {
+    fn messages(
+        &mut self,
+        mut messages: MessageReader,
+        _registry: &mut Registry,
+        ctx: &mut Context,
+    ) {
+        for message in messages.read::<GridSplitterAction>() {
+            match message {
+                GridSplitterAction::Press(position) => {
+                    if !*ctx.widget().get::<bool>("enabled") {
+                        continue;
+                    }
+
+                    // keeps receiving the mouse events if the mouse is moved outside while pressed
+                    let entity = ctx.entity();
+                    ctx.capture_mouse(entity);
+
+                    self.drag = self.blocks(ctx).map(|blocks| (position, blocks));
+                }
+                GridSplitterAction::Move(position) => {
+                    if let Some((start, blocks)) = self.drag.clone() {
+                        let delta = match *ctx.widget().get::<Orientation>("orientation") {
+                            Orientation::Vertical => position.x() - start.x(),
+                            Orientation::Horizontal => position.y() - start.y(),
+                        };
+
+                        self.resize(ctx, blocks, delta);
+                    }
+                }
+                GridSplitterAction::Release => {
+                    if ctx.mouse_capture() == Some(ctx.entity()) {
+                        ctx.release_mouse();
+                    }
+
+                    if self.drag.take().is_some() {
+                        self.finish(ctx);
+                    }
+                }
+                GridSplitterAction::LostCapture => {
+                    if self.drag.take().is_some() {
+                        self.finish(ctx);
+                    }
+                }
+                GridSplitterAction::Key(key) => {
+                    if self.drag.is_some() || !*ctx.widget().get::<bool>("enabled") {
+                        continue;
+                    }
+
+                    let delta = match key_delta(
+                        key,
+                        *ctx.widget().get::<Orientation>("orientation"),
+                        *ctx.widget().get::<f64>("step"),
+                        ctx.widget()
+                            .has_interaction_state(InteractionState::Focused),
+                    ) {
+                        Some(delta) => delta,
+                        None => continue,
+                    };
+
+                    if let Some(blocks) = self.blocks(ctx) {
+                        self.resize(ctx, blocks, delta);
+                        self.finish(ctx);
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Returns the delta the blocks are resized by with the given key. The key events are sent to
+// all widgets, therefore only a focused splitter handles them.
+fn key_delta(key: Key, orientation: Orientation, step: f64, focused: bool) -> Option<f64> {
+    if !focused {
+        return None;
+    }
+
+    match (orientation, key) {
+        (Orientation::Vertical, Key::Left) => Some(-step),
+        (Orientation::Vertical, Key::Right) => Some(step),
+        (Orientation::Horizontal, Key::Up) => Some(-step),
+        (Orientation::Horizontal, Key::Down) => Some(step),
+        _ => None,
+    }
+}
+
+widget!(
+    /// The `GridSplitter` lets the user resize the columns or rows of its parent `Grid`. It is
+    /// placed in its own column (`vertical` orientation) or row (`horizontal` orientation) and
+    /// resizes the blocks before and after it inside of their `min_size` and `max_size`, by
+    /// dragging with the mouse or with the arrow keys if it is focused. After a resize the
+    /// splitter sends a `SplitterChangedEvent` with the resized blocks, that could be
+    /// persisted with the `Settings` service.
+    ///
+    /// **style:** `grid_splitter`
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Grid::new()
+    ///     .columns("200, 4, *")
+    ///     .child(SideBar::new().build(ctx))
+    ///     .child(
+    ///         GridSplitter::new()
+    ///             .attach(Grid::column(1))
+    ///             .on_splitter_changed(|states, _, columns| {
+    ///                 states
+    ///                     .get_mut::<MainState>(id)
+    ///                     .save_columns(columns);
+    ///             })
+    ///             .build(ctx),
+    ///     )
+    ///     .child(Content::new().attach(Grid::column(2)).build(ctx))
+    ///     .build(ctx)
+    /// ```
+    GridSplitter<GridSplitterState>: MouseHandler, KeyDownHandler, SplitterChangedHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the orientation of the splitter. A `vertical` splitter resizes the
+        /// columns, a `horizontal` splitter the rows of the grid.
+        orientation: Orientation,
+
+        /// Sets or shares the size in pixels a block is resized by a key press.
+        step: f64,
+
+        /// Sets or shares the focused property. Only a focused splitter is resized with the
+        /// arrow keys.
+        focused: bool
+    }
+);
+
+impl Template for GridSplitter {
+    fn template(self, id: Entity, _: &mut BuildContext) -> Self {
+        self.name("GridSplitter")
+            .style("grid_splitter")
+            .background("transparent")
+            .orientation("vertical")
+            .step(10.0)
+            .focused(false)
+            .focusable(true)
+            .on_mouse_down(move |ctx, m| {
+                ctx.send_message(GridSplitterAction::Press(m.position), id);
+                true
+            })
+            .on_mouse_move(move |ctx, p| {
+                ctx.send_message(GridSplitterAction::Move(p), id);
+                false
+            })
+            .on_mouse_up(move |ctx, _| {
+                ctx.send_message(GridSplitterAction::Release, id);
+            })
+            .on_lost_mouse_capture(move |ctx| {
+                ctx.send_message(GridSplitterAction::LostCapture, id);
+            })
+            .on_key_down(move |ctx, event| {
+                ctx.send_message(GridSplitterAction::Key(event.key), id);
+                false
+            })
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        RectangleRenderObject.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_delta() {
+        assert_eq!(
+            key_delta(Key::Left, Orientation::Vertical, 10.0, true),
+            Some(-10.0)
+        );
+        assert_eq!(
+            key_delta(Key::Right, Orientation::Vertical, 10.0, true),
+            Some(10.0)
+        );
+        assert_eq!(
+            key_delta(Key::Up, Orientation::Horizontal, 5.0, true),
+            Some(-5.0)
+        );
+        assert_eq!(
+            key_delta(Key::Down, Orientation::Horizontal, 5.0, true),
+            Some(5.0)
+        );
+        assert_eq!(key_delta(Key::Up, Orientation::Vertical, 10.0, true), None);
+        assert_eq!(key_delta(Key::Tab, Orientation::Vertical, 10.0, true), None);
+    }
+
+    #[test]
+    fn test_key_delta_not_focused() {
+        assert_eq!(
+            key_delta(Key::Left, Orientation::Vertical, 10.0, false),
+            None
+        );
+        assert_eq!(
+            key_delta(Key::Right, Orientation::Vertical, 10.0, false),
+            None
+        );
+        assert_eq!(
+            key_delta(Key::Up, Orientation::Horizontal, 10.0, false),
+            None
+        );
+        assert_eq!(
+            key_delta(Key::Down, Orientation::Horizontal, 10.0, false),
+            None
+        );
+    }
+}
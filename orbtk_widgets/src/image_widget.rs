@@ -4,7 +4,7 @@ widget!(
     /// The `ImageWidget` widget is used to draw an image. It is not interactive.
     ///
     /// **style:** `image-widget`
-    ImageWidget {
+    ImageWidget: ContextMenuHandler {
         /// Sets or shares the image property.
         ///
         /// Set image property:
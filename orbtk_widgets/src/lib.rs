@@ -15,9 +15,13 @@ pub use self::canvas::*;
 pub use self::check_box::*;
 pub use self::combo_box::*;
 pub use self::container::*;
+pub use self::context_menu::*;
 pub use self::cursor::*;
+pub use self::dock_panel::*;
+pub use self::flex_panel::*;
 pub use self::font_icon_block::*;
 pub use self::grid::*;
+pub use self::grid_splitter::*;
 pub use self::image_widget::*;
 pub use self::items_widget::*;
 pub use self::list_view::*;
@@ -27,6 +31,7 @@ pub use self::pager::*;
 pub use self::password_box::*;
 pub use self::popup::*;
 pub use self::progress_bar::*;
+pub use self::relative_panel::*;
 pub use self::scroll_bar::*;
 pub use self::scroll_indicator::*;
 pub use self::scroll_viewer::*;
@@ -37,7 +42,9 @@ pub use self::tab_widget::*;
 pub use self::text_block::*;
 pub use self::text_box::*;
 pub use self::toggle_button::*;
+pub use self::viewbox::*;
 pub use self::window::*;
+pub use self::wrap_panel::*;
 
 pub mod behaviors;
 mod button;
@@ -45,9 +52,13 @@ mod canvas;
 mod check_box;
 mod combo_box;
 mod container;
+mod context_menu;
 mod cursor;
+mod dock_panel;
+mod flex_panel;
 mod font_icon_block;
 mod grid;
+mod grid_splitter;
 mod image_widget;
 mod items_widget;
 mod list_view;
@@ -57,6 +68,7 @@ mod pager;
 mod password_box;
 mod popup;
 mod progress_bar;
+mod relative_panel;
 mod scroll_bar;
 mod scroll_indicator;
 mod scroll_viewer;
@@ -68,4 +80,6 @@ mod text_block;
 mod text_box;
 pub mod themes;
 mod toggle_button;
+mod viewbox;
 mod window;
+mod wrap_panel;
@@ -8,6 +8,12 @@ use crate::{api::prelude::*, prelude::*, proc_macros::*, themes::theme_orbtk::*}
 
 static ITEMS_PANEL: &str = "items_panel";
 
+// Payload of a dragged `ListViewItem`.
+struct ListViewItemDrag {
+    list_view: Entity,
+    item: Entity,
+}
+
 /// The `ListViewState` generates the list box items and handles the selected indices.
 #[derive(Default, AsAny)]
 pub struct ListViewState {
@@ -15,9 +21,46 @@ pub struct ListViewState {
     count: usize,
     selected_entities: RefCell<HashSet<Entity>>,
     items_panel: Entity,
+    reorderable: bool,
+    move_request: Option<(Entity, Entity)>,
 }
 
 impl ListViewState {
+    // Requests to move the source item to the index of the target item.
+    fn move_item(&mut self, source: Entity, target: Entity) {
+        self.move_request = Some((source, target));
+    }
+
+    fn process_move_request(&mut self, ctx: &mut Context) {
+        let (source, target) = match self.move_request.take() {
+            Some(request) => request,
+            None => return,
+        };
+
+        let (from, to) = match (ctx.index_as_child(source), ctx.index_as_child(target)) {
+            (Some(from), Some(to)) if from != to => (from, to),
+            _ => return,
+        };
+
+        if ctx.move_child(self.items_panel, from, to).is_err() {
+            return;
+        }
+
+        // the selection follows the moved items
+        let selected_indices: HashSet<usize> = self
+            .selected_entities
+            .borrow()
+            .iter()
+            .filter_map(|entity| ctx.index_as_child(*entity))
+            .collect();
+        ctx.widget()
+            .set("selected_indices", SelectedIndices(selected_indices));
+
+        let entity = ctx.entity();
+        ctx.event_adapter()
+            .push_event_direct(entity, ReorderedEvent(entity, from, to));
+    }
+
     fn generate_items(&mut self, ctx: &mut Context) {
         let count = ctx.widget().clone_or_default::<usize>("count");
         let entity = ctx.entity();
@@ -40,11 +83,6 @@ impl ListViewState {
                             mouse_behavior,
                             item,
                         );
-                        build_context.register_shared_property::<bool>(
-                            "pressed",
-                            mouse_behavior,
-                            item,
-                        );
                         build_context.append_child(item, mouse_behavior);
 
                         build_context.register_shared_property::<Brush>("foreground", child, item);
@@ -72,11 +110,14 @@ impl State for ListViewState {
             .entity_of_child(ITEMS_PANEL)
             .expect("ListViewState.init: ItemsPanel child could not be found.");
 
+        self.reorderable = *ctx.widget().get::<bool>("reorderable");
         self.generate_items(ctx);
     }
 
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.reorderable = *ctx.widget().get::<bool>("reorderable");
         self.generate_items(ctx);
+        self.process_move_request(ctx);
     }
 
     fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
@@ -95,18 +136,6 @@ impl State for ListViewState {
 
             let selected = !widget.get::<bool>("selected");
             widget.set("selected", selected);
-
-            if selected {
-                widget
-                    .get_mut::<Selector>("selector")
-                    .push_state("selected");
-            } else {
-                widget
-                    .get_mut::<Selector>("selector")
-                    .remove_state("selected");
-            }
-
-            widget.update(false);
         }
 
         *self.selected_entities.borrow_mut() = ctx
@@ -121,15 +150,31 @@ impl State for ListViewState {
 #[derive(Default, AsAny)]
 pub struct ListViewItemState {
     request_selection_toggle: Cell<bool>,
+    parent: Entity,
 }
 
 impl ListViewItemState {
     fn toggle_selection(&self) {
         self.request_selection_toggle.set(true);
     }
+
+    // Checks if the dragged data is another item of the same reorderable list view.
+    fn accepts(&self, states: &StatesContext, id: Entity, data: &DragData) -> bool {
+        data.payload::<ListViewItemDrag>().map_or(false, |drag| {
+            drag.list_view == self.parent
+                && drag.item != id
+                && states
+                    .try_get::<ListViewState>(self.parent)
+                    .map_or(false, |state| state.reorderable)
+        })
+    }
 }
 
 impl State for ListViewItemState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.parent = (*ctx.widget().get::<u32>("parent")).into();
+    }
+
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
         if !ctx.widget().get::<bool>("enabled") || !self.request_selection_toggle.get() {
             return;
@@ -206,7 +251,7 @@ widget!(
     /// The `ListViewItem` describes an item inside of a `ListView`.
     ///
     /// **style:** `list-view``
-    ListViewItem<ListViewItemState>: MouseHandler {
+    ListViewItem<ListViewItemState>: MouseHandler, DragSourceHandler, DropTargetHandler, ContextMenuHandler {
         /// Sets or shares the background property.
         background: Brush,
 
@@ -231,17 +276,11 @@ widget!(
         /// Sets or shares the padding property.
         padding: Thickness,
 
-        /// Sets or shares the pressed property.
-        pressed: bool,
-
         /// Sets or shares the selected property.
         selected: bool,
 
         /// Sets or shares the parent id.
-        parent: u32,
-
-        /// Indicates if the widget is hovered by the mouse cursor.
-        hover: bool
+        parent: u32
     }
 );
 
@@ -252,7 +291,6 @@ impl Template for ListViewItem {
             .min_width(64.0)
             .height(24.0)
             .selected(false)
-            .pressed(false)
             .padding(0.0)
             .background("transparent")
             .border_radius(0.0)
@@ -265,13 +303,44 @@ impl Template for ListViewItem {
                 states.get::<ListViewItemState>(id).toggle_selection();
                 false
             })
-            .child(
-                MouseBehavior::new()
-                    .pressed(id)
-                    .enabled(id)
-                    .target(id.0)
-                    .build(ctx),
-            )
+            .on_drag_start(move |states, _| {
+                let parent = states.get::<ListViewItemState>(id).parent;
+
+                if !states
+                    .try_get::<ListViewState>(parent)
+                    .map_or(false, |state| state.reorderable)
+                {
+                    return None;
+                }
+
+                Some(DragData::new(ListViewItemDrag {
+                    list_view: parent,
+                    item: id,
+                }))
+            })
+            .on_drag_over(move |states, data, _| {
+                states
+                    .get::<ListViewItemState>(id)
+                    .accepts(states, id, data)
+            })
+            .on_drag_drop(move |states, data, _| {
+                let item_state = states.get::<ListViewItemState>(id);
+
+                if !item_state.accepts(states, id, data) {
+                    return false;
+                }
+
+                let parent = item_state.parent;
+
+                if let Some(drag) = data.payload::<ListViewItemDrag>() {
+                    states
+                        .get_mut::<ListViewState>(parent)
+                        .move_item(drag.item, id);
+                }
+
+                true
+            })
+            .child(MouseBehavior::new().enabled(id).target(id.0).build(ctx))
     }
 
     fn render_object(&self) -> Box<dyn RenderObject> {
@@ -287,7 +356,7 @@ widget!(
     /// The `ListView` is an items drawer widget with selectable items.
     ///
     /// **style:** `items-widget`
-    ListView<ListViewState> : SelectionChangedHandler {
+    ListView<ListViewState> : SelectionChangedHandler, ReorderedHandler {
         /// Sets or shares the background property.
         background: Brush,
 
@@ -318,6 +387,9 @@ widget!(
         /// Sets or shares the list of selected indices.
         selected_entities: SelectedEntities,
 
+        /// Sets or shares the flag if the items could be reordered by drag and drop.
+        reorderable: bool,
+
         /// Use this flag to force the redrawing of the items.
         request_update: bool
     }
@@ -357,7 +429,9 @@ impl Template for ListView {
             .selection_mode("single")
             .selected_indices(HashSet::new())
             .selected_entities(HashSet::new())
+            .reorderable(false)
             .orientation("vertical")
+            .focusable(true)
             .child(
                 Container::new()
                     .background(id)
@@ -219,9 +219,6 @@ widget!(
     /// Sets or shares the current value property
     val: f64,
 
-    /// Indicates if the widget is hovered by the mouse cursor.
-    hover: bool,
-
     /// Represents the up icon of the up button.
     icon_up: String,
 
@@ -237,6 +234,7 @@ impl Template for NumericBox {
         self.name("NumericBox")
             .style("numeric_box")
             .focused(false)
+            .focusable(true)
             .lose_focus_on_activation(true)
             .min(0.0)
             //.min_width(128)
@@ -108,10 +108,7 @@ widget!(
         request_focus: bool,
 
         /// If set to `true` all character will be focused when the widget gets focus. Default is `true`
-        select_all_on_focus: bool,
-
-        /// Indicates if the widget is hovered by the mouse cursor.
-        hover: bool
+        select_all_on_focus: bool
     }
 );
 
@@ -164,6 +161,7 @@ impl Template for PasswordBox {
             .min_width(128.0)
             .height(32.0)
             .focused(false)
+            .focusable(true)
             .lose_focus_on_activation(true)
             .select_all_on_focus(true)
             .child(text_behavior)
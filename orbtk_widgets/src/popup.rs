@@ -19,6 +19,8 @@ widget!(
     /// property. An optional attribute (float), defines the
     /// margin between the target and the popup widget.
     ///
+    /// An open popup is a focus scope, the keyboard focus traversal is trapped inside of it.
+    ///
     /// [`placement`]: ../orbtk_core/render_object/enum.Placement.html
     ///
     /// **style:** `popup``
@@ -59,7 +61,10 @@ widget!(
 
 impl Template for Popup {
     fn template(self, _id: Entity, _: &mut BuildContext) -> Self {
-        self.name("Popup").style("popup").open(false)
+        self.name("Popup")
+            .style("popup")
+            .open(false)
+            .attach(Focus::scope(true))
     }
 
     fn render_object(&self) -> Box<dyn RenderObject> {
@@ -0,0 +1,62 @@
+use crate::{api::prelude::*, proc_macros::*};
+
+widget!(
+    /// The `RelativePanel` positions each child relative to the panel or to its siblings. A
+    /// sibling is referenced by its `id`. If both edges of an axis are anchored, the child is
+    /// stretched between them.
+    ///
+    /// **style:** `relative_panel`
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// RelativePanel::new()
+    ///     .child(TextBlock::new().id("name_label").text("Name").build(ctx))
+    ///     .child(
+    ///         TextBox::new()
+    ///             .id("name")
+    ///             .attach(RelativePanel::anchors(
+    ///                 "right-of(name_label), align-right-with-panel, align-vertical-center-with(name_label)",
+    ///             ))
+    ///             .build(ctx),
+    ///     )
+    ///     .child(
+    ///         TextBlock::new()
+    ///             .text("Mail")
+    ///             .attach(RelativePanel::anchors(
+    ///                 Anchors::new().below("name").align_left_with("name_label"),
+    ///             ))
+    ///             .build(ctx),
+    ///     )
+    ///     .build(ctx)
+    /// ```
+    RelativePanel: ContextMenuHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the anchor errors property. It is set by the layout to the cycles
+        /// and unknown ids of the anchors of the children, the affected anchors are ignored.
+        anchor_errors: AnchorErrors
+
+        attached_properties: {
+            /// Attach the anchors of the widget to the panel or to its siblings.
+            anchors: Anchors
+        }
+    }
+);
+
+impl Template for RelativePanel {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("RelativePanel")
+            .style("relative_panel")
+            .background("transparent")
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        RectangleRenderObject.into()
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(RelativeLayout::new())
+    }
+}
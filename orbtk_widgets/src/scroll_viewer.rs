@@ -4,18 +4,30 @@ use crate::{api::prelude::*, proc_macros::*};
 #[derive(Default, AsAny)]
 pub struct ScrollViewerState {
     delta: Option<Point>,
+    pan_delta: Option<Point>,
 }
 
 impl ScrollViewerState {
     fn scroll(&mut self, delta: Point) {
         self.delta = Some(delta);
     }
+
+    // scrolls the child by the given delta in pixels, e.g. on a pan gesture.
+    fn pan(&mut self, delta: Point) {
+        self.pan_delta = Some(delta);
+    }
 }
 
 impl State for ScrollViewerState {
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
-        if let Some(delta) = self.delta {
-            self.delta = None;
+        let speed = *ctx.widget().get::<f64>("speed");
+
+        let delta = match (self.delta.take(), self.pan_delta.take()) {
+            (Some(delta), _) => Some(delta * speed),
+            (None, pan_delta) => pan_delta,
+        };
+
+        if let Some(delta) = delta {
             let mode = *ctx.widget().get::<ScrollViewerMode>("mode");
 
             if mode.vertical != ScrollMode::Auto && mode.horizontal != ScrollMode::Auto {
@@ -23,7 +35,6 @@ impl State for ScrollViewerState {
             }
 
             let size = ctx.widget().get::<Rectangle>("bounds").size();
-            let speed = *ctx.widget().get::<f64>("speed");
             let mut padding = *ctx.widget().get::<Thickness>("padding");
 
             if let Some(child) = &mut ctx.try_child_from_index(0) {
@@ -34,7 +45,7 @@ impl State for ScrollViewerState {
                         size.height(),
                         child_size.height(),
                         padding.top(),
-                        delta.y() * speed,
+                        delta.y(),
                     ));
                 }
 
@@ -43,7 +54,7 @@ impl State for ScrollViewerState {
                         size.width(),
                         child_size.width(),
                         padding.left(),
-                        delta.x() * speed,
+                        delta.x(),
                     ));
                 }
             } else {
@@ -58,7 +69,7 @@ impl State for ScrollViewerState {
 widget!(
     /// The `ScrollViewer` is used to scroll its child vertical and or horizontal.
     /// Only the first child of the scroll viewer can be scrolled.
-    ScrollViewer<ScrollViewerState>: MouseHandler {
+    ScrollViewer<ScrollViewerState>: MouseHandler, GestureHandler {
         /// Sets or shares the scroll mode property.
         mode: ScrollViewerMode,
 
@@ -81,6 +92,14 @@ impl Template for ScrollViewer {
                 states.get_mut::<ScrollViewerState>(id).scroll(p);
                 false
             })
+            .on_gesture(move |states, gesture| {
+                if let GestureEvent::Pan { delta, .. } = gesture {
+                    states.get_mut::<ScrollViewerState>(id).pan(delta);
+                    return true;
+                }
+
+                false
+            })
     }
 
     fn layout(&self) -> Box<dyn Layout> {
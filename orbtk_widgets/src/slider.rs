@@ -10,6 +10,8 @@ static ID_ACCENT_TRACK: &str = "id_accent_track";
 #[derive(Copy, Clone)]
 enum SliderAction {
     Move { mouse_x: f64 },
+    Tap { x: f64 },
+    Pan { start: Point, x: f64 },
 }
 
 /// The `SliderState` is used to manipulate the position of the thumb of the slider widget.
@@ -79,6 +81,31 @@ impl SliderState {
         has_changes
     }
 
+    // moves the thumb to the given x position and updates the val
+    fn move_thumb(&self, ctx: &mut Context, x: f64) {
+        let thumb_width = get_widget_width(ctx, self.thumb);
+        let track_width = get_widget_width(ctx, self.track);
+        let slider_x = ctx.widget().get::<Point>("position").x();
+
+        let thumb_x = calculate_thumb_x(x, thumb_width, slider_x, track_width);
+
+        ctx.get_widget(self.accent_track)
+            .get_mut::<Constraint>("constraint")
+            .set_width(thumb_x + 2.);
+
+        ctx.get_widget(self.thumb)
+            .get_mut::<Thickness>("margin")
+            .set_left(thumb_x);
+
+        let min = *ctx.widget().get("min");
+        let max = *ctx.widget().get("max");
+
+        ctx.widget().set(
+            "val",
+            calculate_val(thumb_x, min, max, thumb_width, track_width),
+        );
+    }
+
     // adjust the thump position
     fn adjust_thumb_x(&self, ctx: &mut Context) {
         let val = *ctx.widget().get::<f64>("val");
@@ -120,29 +147,22 @@ impl State for SliderState {
         if let Some(action) = self.action {
             match action {
                 SliderAction::Move { mouse_x } => {
-                    if *ctx.get_widget(self.thumb).get::<bool>("pressed") {
-                        let thumb_width = get_widget_width(ctx, self.thumb);
-                        let track_width = get_widget_width(ctx, self.track);
-                        let slider_x = ctx.widget().get::<Point>("position").x();
-
-                        let thumb_x =
-                            calculate_thumb_x(mouse_x, thumb_width, slider_x, track_width);
-
-                        ctx.get_widget(self.accent_track)
-                            .get_mut::<Constraint>("constraint")
-                            .set_width(thumb_x + 2.);
-
-                        ctx.get_widget(self.thumb)
-                            .get_mut::<Thickness>("margin")
-                            .set_left(thumb_x);
-
-                        let min = *ctx.widget().get("min");
-                        let max = *ctx.widget().get("max");
-
-                        ctx.widget().set(
-                            "val",
-                            calculate_val(thumb_x, min, max, thumb_width, track_width),
-                        );
+                    if ctx
+                        .get_widget(self.thumb)
+                        .has_interaction_state(InteractionState::Pressed)
+                    {
+                        self.move_thumb(ctx, mouse_x);
+                    } else {
+                        ctx.widget().clear_dirty();
+                    }
+                }
+                SliderAction::Tap { x } => {
+                    self.move_thumb(ctx, x);
+                }
+                SliderAction::Pan { start, x } => {
+                    // like the mouse, only a pan that starts on the thumb moves it
+                    if check_mouse_condition(start, &ctx.get_widget(self.thumb)) {
+                        self.move_thumb(ctx, x);
                     } else {
                         ctx.widget().clear_dirty();
                     }
@@ -169,7 +189,7 @@ widget!(
     /// ```rust
     /// Slider::new().min(0).max(100).val(50).build(ctx)
     /// ```
-    Slider<SliderState>: MouseHandler {
+    Slider<SliderState>: MouseHandler, GestureHandler {
         /// Sets or shares the min val of the range.
         min: f64,
 
@@ -191,9 +211,6 @@ widget!(
         /// Sets or shares the border brush property.
         border_brush: Brush,
 
-        /// Indicates if the widget is hovered by the mouse cursor.
-        hover: bool,
-
         /// Defines the margin around the inner border.
         container_margin: Thickness,
 
@@ -216,6 +233,7 @@ impl Template for Slider {
             .border_radius(2.0)
             .container_margin((0, 11, 0, 11))
             .accent_margin((0, 11, 0, 11))
+            .focusable(true)
             .child(
                 Grid::new()
                     .id(ID_TRACK)
@@ -267,6 +285,24 @@ impl Template for Slider {
                     .action(SliderAction::Move { mouse_x: p.x() });
                 false
             })
+            .on_gesture(move |states, gesture| match gesture {
+                GestureEvent::Tap(position) => {
+                    states
+                        .get_mut::<SliderState>(id)
+                        .action(SliderAction::Tap { x: position.x() });
+                    true
+                }
+                GestureEvent::Pan {
+                    start, position, ..
+                } => {
+                    states.get_mut::<SliderState>(id).action(SliderAction::Pan {
+                        start,
+                        x: position.x(),
+                    });
+                    true
+                }
+                _ => false,
+            })
     }
 }
 
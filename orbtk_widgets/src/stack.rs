@@ -4,7 +4,7 @@ widget!(
     /// The `Stack` defines a layout that is used to stack its children vertical or horizontal.
     ///
     /// **style:** `stack`
-    Stack {
+    Stack: ContextMenuHandler, CloseRequestedHandler {
         /// Sets or shares the orientation property.
         orientation: Orientation,
 
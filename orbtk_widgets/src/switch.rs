@@ -27,8 +27,6 @@ impl SwitchState {
     fn toggle_selection(&self, ctx: &mut Context) {
         let selected: bool = *Switch::selected_ref(&ctx.widget());
         Switch::selected_set(&mut ctx.widget(), !selected);
-        toggle_flag("selected", &mut ctx.widget());
-        ctx.widget().update(false);
     }
 
     // update the visual state to the selection state.
@@ -38,17 +36,11 @@ impl SwitchState {
 
         if selected {
             switch_toggle.set("h_align", Alignment::from("end"));
-            switch_toggle
-                .get_mut::<Selector>("selector")
-                .push_state("selected");
         } else {
             switch_toggle.set("h_align", Alignment::from("start"));
-            switch_toggle
-                .get_mut::<Selector>("selector")
-                .remove_state("selected");
         }
 
-        switch_toggle.update(true);
+        switch_toggle.set_interaction_state(InteractionState::Selected, selected);
     }
 }
 
@@ -99,15 +91,12 @@ widget!(
         /// Sets or shares the padding property.
         padding: Thickness,
 
-        /// Sets or shares the pressed property.
-        pressed: bool,
+        /// Indicates if the widget has the keyboard focus.
+        focused: bool,
 
         /// Sets or shares the selected property.
         selected: bool,
 
-        /// Indicates if the widget is hovered by the mouse cursor.
-        hover: bool,
-
         /// Defines the margin around the inner border.
         container_margin: Thickness
     }
@@ -117,7 +106,8 @@ impl Template for Switch {
     fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
         self.name("Switch")
             .style("switch")
-            .pressed(false)
+            .focused(false)
+            .focusable(true)
             .selected(false)
             .width(36.0)
             .height(30.0)
@@ -127,7 +117,6 @@ impl Template for Switch {
             .container_margin((2, 8))
             .child(
                 MouseBehavior::new()
-                    .pressed(id)
                     .enabled(id)
                     .target(id.0)
                     .on_click(move |ctx, _| {
@@ -13,6 +13,12 @@ const HEADER_CONTAINER: &str = "header_container";
 const HEADER_BAR: &str = "header_bar";
 // --- KEYS --
 
+// Payload of a dragged tab header.
+struct TabDrag {
+    tab_widget: Entity,
+    body: Entity,
+}
+
 /// The `TabHeaderState` structure is used to handle state changes, store some callbacks that will be applied during template function.
 /// Once the template function is called, they are no more used.
 #[derive(Default, AsAny)]
@@ -65,7 +71,7 @@ impl State for TabHeaderState {
 
 widget!(
     /// The `TabHeader` widget is used internally to managed tabs headers. Not meant for other uses.
-    TabHeader<TabHeaderState> {
+    TabHeader<TabHeaderState>: DragSourceHandler, DropTargetHandler {
         /// Sets or shares the background property.
         background: Brush,
 
@@ -108,17 +114,11 @@ widget!(
         /// Sets or shares the selected property.
         selected: bool,
 
-        /// Sets or shares the pressed property.
-        pressed: bool,
-
         /// Sets or shares the spacing between icon and text.
         spacing: f64,
 
         /// Sets or shares the close button visibility.
-        close_button: Visibility,
-
-        /// Indicates if the widget is hovered by the mouse cursor.
-        hover: bool
+        close_button: Visibility
     }
 );
 
@@ -154,7 +154,7 @@ impl Template for TabHeader {
         }
         //if self.close_button() == false {button = button.visibility(Visibility::Collapsed);}
 
-        let mut mouse_behavior = MouseBehavior::new().enabled(id).target(id.0).pressed(id);
+        let mut mouse_behavior = MouseBehavior::new().enabled(id).target(id.0);
 
         if let Some(callback) = self.state.on_header_mouse_down_callback.take() {
             mouse_behavior = mouse_behavior.on_mouse_down(callback);
@@ -229,6 +229,7 @@ impl Template for TabHeader {
 enum TabWidgetAction {
     Add(String, Entity),
     Remove(Entity),
+    Move(Entity, Entity),
     SelectByIndex(usize),
     SelectByBody(Entity),
     SetCloseButtonVisibility(bool),
@@ -256,6 +257,8 @@ pub struct TabWidgetState {
     selected: usize,
 
     close_button_visibility: bool,
+
+    reorderable: bool,
 }
 
 impl TabWidgetState {
@@ -314,9 +317,46 @@ impl TabWidgetState {
         self.close_button_visibility
     }
 
+    /// Moves the tab identified by the passed `body` to the position of the tab identified by `target`.
+    pub fn move_by_body(&mut self, body: Entity, target: Entity) {
+        self.actions.push(TabWidgetAction::Move(body, target));
+    }
+
+    // Move a tab to the position of the target tab.
+    // Unlike the public `move_by_body` method, this happens immediatly.
+    fn move_tab_internal(&mut self, ctx: &mut Context, body: Entity, target: Entity) {
+        let (from, to) = match (self.get_index(body), self.get_index(target)) {
+            (Some(from), Some(to)) if from != to => (from, to),
+            _ => return,
+        };
+
+        if ctx.move_child(self.header_container, from, to).is_err() {
+            return;
+        }
+
+        // the selection follows the selected body
+        let selected_body = self.tabs[self.selected].1;
+        let tab = self.tabs.remove(from);
+        self.tabs.insert(to, tab);
+        self.selected = self.get_index(selected_body).unwrap_or(0);
+
+        let entity = ctx.entity();
+        ctx.event_adapter()
+            .push_event_direct(entity, ReorderedEvent(entity, from, to));
+    }
+
+    // Checks if the dragged data is another tab of the same reorderable tab widget.
+    fn accepts(&self, tab_widget: Entity, body: Entity, data: &DragData) -> bool {
+        self.reorderable
+            && data.payload::<TabDrag>().map_or(false, |drag| {
+                drag.tab_widget == tab_widget && drag.body != body
+            })
+    }
+
     // Create a new TabHeader entity and return it. For internal use.
     fn new_tab_header(&self, ctx: &mut Context, text: String, body: Entity) -> Entity {
         let cloned_entity = ctx.entity();
+        let drag_text = text.clone();
         TabHeader::new()
             .close_button(if self.close_button_visibility {
                 Visibility::Visible
@@ -336,6 +376,40 @@ impl TabWidgetState {
                     .remove_by_body(body);
                 true
             })
+            .on_drag_start(move |states, _| {
+                if !states.get::<TabWidgetState>(cloned_entity).reorderable {
+                    return None;
+                }
+
+                Some(
+                    DragData::new(TabDrag {
+                        tab_widget: cloned_entity,
+                        body,
+                    })
+                    .with_text(drag_text.as_str()),
+                )
+            })
+            .on_drag_over(move |states, data, _| {
+                states
+                    .get::<TabWidgetState>(cloned_entity)
+                    .accepts(cloned_entity, body, data)
+            })
+            .on_drag_drop(move |states, data, _| {
+                if !states
+                    .get::<TabWidgetState>(cloned_entity)
+                    .accepts(cloned_entity, body, data)
+                {
+                    return false;
+                }
+
+                if let Some(drag) = data.payload::<TabDrag>() {
+                    states
+                        .get_mut::<TabWidgetState>(cloned_entity)
+                        .move_by_body(drag.body, body);
+                }
+
+                true
+            })
             .build(&mut ctx.build_context())
     }
 
@@ -352,8 +426,6 @@ impl TabWidgetState {
         let tab = self.tabs[self.selected];
         // update its header
         ctx.get_widget(tab.0).set("selected", true);
-        toggle_flag("selected", &mut ctx.get_widget(tab.0));
-        ctx.get_widget(tab.0).update(false);
         // update its body
         ctx.get_widget(tab.1).set("visibility", Visibility::Visible);
     }
@@ -427,16 +499,12 @@ impl TabWidgetState {
 
             // Toggle current button, the new button is toggled by user click
             ctx.get_widget(current_tab.0).set("selected", false);
-            toggle_flag("selected", &mut ctx.get_widget(current_tab.0));
-            ctx.get_widget(current_tab.0).update(true);
 
             // Hide current body
             ctx.get_widget(current_tab.1)
                 .set("visibility", Visibility::Collapsed);
 
             ctx.get_widget(new_tab.0).set("selected", true);
-            toggle_flag("selected", &mut ctx.get_widget(new_tab.0));
-            ctx.get_widget(new_tab.0).update(false);
 
             // Show new body
             ctx.get_widget(new_tab.1)
@@ -472,6 +540,8 @@ impl State for TabWidgetState {
     }
 
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.reorderable = *TabWidget::reorderable_ref(&ctx.widget());
+
         let actions: Vec<TabWidgetAction> = self.actions.drain(..).collect();
         for action in actions {
             match action {
@@ -489,6 +559,9 @@ impl State for TabWidgetState {
                 TabWidgetAction::Remove(body) => {
                     self.remove_tab_internal(ctx, body);
                 }
+                TabWidgetAction::Move(body, target) => {
+                    self.move_tab_internal(ctx, body, target);
+                }
                 TabWidgetAction::SetCloseButtonVisibility(value) => {
                     self.set_close_button_visibility_internal(ctx, value);
                 }
@@ -511,7 +584,7 @@ widget!(
     /// .tab("Tab header 3",TextBlock::new().text("Tab content 3").build(ctx))
     /// .build(ctx)
     /// ```
-    TabWidget<TabWidgetState> {
+    TabWidget<TabWidgetState>: ReorderedHandler {
         /// Sets or shares the background property.
         background: Brush,
 
@@ -528,7 +601,10 @@ widget!(
         padding: Thickness,
 
         /// Sets or shares the spacing between tabs.
-        spacing: f64
+        spacing: f64,
+
+        /// Sets or shares the flag if the tabs could be reordered by drag and drop.
+        reorderable: bool
     }
 );
 
@@ -554,6 +630,7 @@ impl Template for TabWidget {
         self.name(TAB_WIDGET)
             .id(TAB_WIDGET)
             .style("tab_widget")
+            .reorderable(false)
             .child(
                 Grid::new()
                     .name(TAB_GRID)
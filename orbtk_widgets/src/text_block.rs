@@ -48,7 +48,7 @@ widget!(
     /// The `TextBlock` widget is used to draw text. It is not interactive.
     ///
     /// **style:** `text-block`
-    TextBlock<TextBlockState> {
+    TextBlock<TextBlockState>: ContextMenuHandler {
         /// Sets or shares the text property.
         text: String,
 
@@ -13,7 +13,8 @@ widget!(
     /// * style: `text_box`
     TextBox: ActivateHandler,
     KeyDownHandler,
-    TextInputHandler {
+    TextInputHandler,
+    ContextMenuHandler {
         /// Sets or shares the text property.
         text: String,
 
@@ -57,10 +58,7 @@ widget!(
         request_focus: bool,
 
         /// If set to `true` all character will be focused when the widget gets focus. Default is `true`
-        select_all_on_focus: bool,
-
-        /// Indicates if the widget is hovered by the mouse cursor.
-        hover: bool
+        select_all_on_focus: bool
     }
 );
 
@@ -100,6 +98,7 @@ impl Template for TextBox {
             .border_width(0.0)
             .border_radius(2.0)
             .focused(false)
+            .focusable(true)
             .font_size(orbtk_fonts::FONT_SIZE_12)
             .font("Roboto-Regular")
             .foreground(colors::LINK_WATER_COLOR)
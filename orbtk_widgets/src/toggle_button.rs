@@ -47,17 +47,14 @@ widget!(
         /// Sets or shares the icon font property.
         icon_font: String,
 
-        /// Sets or shares the pressed property.
-        pressed: bool,
+        /// Indicates if the widget has the keyboard focus.
+        focused: bool,
 
         /// Sets or shares the selected property.
         selected: bool,
 
         /// Sets or shares the spacing between icon and text.
-        spacing: f64,
-
-        /// Indicates if the widget is hovered by the mouse cursor.
-        hover: bool
+        spacing: f64
     }
 );
 
@@ -81,11 +78,11 @@ impl Template for ToggleButton {
             .icon_font("MaterialIcons-Regular")
             .icon_size(orbtk_fonts::ICON_FONT_SIZE_12)
             .icon_brush(colors::LINK_WATER_COLOR)
-            .pressed(false)
+            .focused(false)
+            .focusable(true)
             .spacing(8.0)
             .child(
                 MouseBehavior::new()
-                    .pressed(id)
                     .enabled(id)
                     .target(id.0)
                     .child(
@@ -0,0 +1,43 @@
+use crate::{api::prelude::*, proc_macros::*};
+
+widget!(
+    /// The `Viewbox` scales its child to fill the available space. The scaling is done with a
+    /// render transform, the child is measured and arranged with its own desired size.
+    ///
+    /// The `stretch` property defines how the child is scaled: `none` keeps the original size,
+    /// `fill` scales the child to the size of the viewbox, `uniform` (default) fits the child
+    /// into the viewbox and `uniform-to-fill` fills the viewbox while preserving the aspect ratio
+    /// of the child.
+    ///
+    /// **style:** `viewbox`
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Viewbox::new()
+    ///     .stretch("uniform-to-fill")
+    ///     .child(ImageWidget::new().image("res/orbtk_logo.png").build(ctx))
+    ///     .build(ctx)
+    /// ```
+    Viewbox: ContextMenuHandler {
+        /// Sets or shares the stretch property.
+        stretch: Stretch
+    }
+);
+
+impl Template for Viewbox {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("Viewbox")
+            .style("viewbox")
+            .stretch("uniform")
+            .clip(true)
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        ViewboxRenderObject.into()
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(ViewboxLayout::new())
+    }
+}
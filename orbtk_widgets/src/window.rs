@@ -1,7 +1,10 @@
 use std::{collections::VecDeque, rc::Rc};
 
 use crate::{
-    api::prelude::*, proc_macros::*, shell::prelude::WindowRequest, themes::theme_orbtk::*,
+    api::prelude::*,
+    proc_macros::*,
+    shell::prelude::{Key, KeyEvent, WindowRequest},
+    themes::theme_orbtk::*,
 };
 
 // --- KEYS --
@@ -11,10 +14,17 @@ pub static STYLE_WINDOW: &str = "window";
 // internal type to handle dirty widgets.
 type DirtyWidgets = Vec<Entity>;
 
+// internal type to handle the layout invalidation of widgets.
+type DirtyLayouts = Vec<(Entity, LayoutInvalidation)>;
+
+// internal type to handle the properties that are changed by layouts.
+type LayoutChanges = Vec<(Entity, String)>;
+
 #[derive(Clone)]
 enum Action {
     WindowEvent(WindowEvent),
     FocusEvent(FocusEvent),
+    KeyDown(KeyEvent),
 }
 
 // The `WindowState` handles the window events.
@@ -62,6 +72,25 @@ impl WindowState {
         Window::focus_state_set(&mut ctx.widget(), focus_state);
     }
 
+    // Moves the keyboard focus with tab and the arrow keys inside of focus groups.
+    fn key_down(&self, event: KeyEvent, ctx: &mut Context) {
+        let mut focus_state: FocusState = Window::focus_state_clone(&ctx.widget());
+
+        let moved = match event.key {
+            Key::Tab => {
+                let reverse = Window::keyboard_state_ref(&ctx.widget()).is_shift_down();
+                focus_state.focus_next(reverse, ctx)
+            }
+            Key::Left | Key::Up => focus_state.focus_in_group(true, ctx),
+            Key::Right | Key::Down => focus_state.focus_in_group(false, ctx),
+            _ => false,
+        };
+
+        if moved {
+            Window::focus_state_set(&mut ctx.widget(), focus_state);
+        }
+    }
+
     fn set_background(&mut self, ctx: &mut Context) {
         let background: Brush = ctx.widget().clone("background");
         if let Brush::SolidColor(color) = background {
@@ -108,6 +137,9 @@ impl State for WindowState {
                         self.remove_focus(entity, ctx);
                     }
                 },
+                Action::KeyDown(event) => {
+                    self.key_down(event, ctx);
+                }
             }
         }
     }
@@ -117,8 +149,12 @@ widget!(
     /// The `Window` widget provides access to the properties of an application window.
     /// It also contains global properties like keyboard modifier and focused widget.
     ///
+    /// The window moves the keyboard focus between focusable widgets with `Tab` and `Shift+Tab`
+    /// and with the arrow keys inside of focus groups (see `Focus`). Commands of keyboard shortcuts that
+    /// are not handled by the focused widget could be handled with `on_command`.
+    ///
     /// **style:** `window`
-    Window<WindowState>: ActivateHandler {
+    Window<WindowState>: ActivateHandler, CloseRequestedHandler, CommandHandler, KeyDownHandler {
         /// Sets or shares the background property.
         background: Brush,
 
@@ -137,6 +173,15 @@ widget!(
         /// Sets or shares a value that describes if the current window is active.
         active: bool,
 
+        /// Sets or shares the maximum time in milliseconds between the clicks of a double click.
+        double_click_time: u32,
+
+        /// Sets or shares the maximum distance in pixels between the clicks of a double click.
+        double_click_distance: f64,
+
+        /// Sets or shares the time in milliseconds the mouse has to rest on a widget before its tooltip is shown.
+        tooltip_delay: u32,
+
         /// Access the current keyboard state e.g. to check modifiers.
         keyboard_state: KeyboardState,
 
@@ -147,7 +192,13 @@ widget!(
         focus_state: FocusState,
 
         /// Internal property to handle dirty widgets.
-        dirty_widgets: DirtyWidgets
+        dirty_widgets: DirtyWidgets,
+
+        /// Internal property to handle the layout invalidation of changed properties.
+        dirty_layouts: DirtyLayouts,
+
+        /// Internal property to notify the changes of properties that are set by layouts.
+        layout_changes: LayoutChanges
     }
 );
 
@@ -215,7 +266,15 @@ impl Template for Window {
             .title("Window")
             .resizable(false)
             .always_on_top(false)
+            .double_click_time(DEFAULT_DOUBLE_CLICK_TIME)
+            .double_click_distance(DEFAULT_DOUBLE_CLICK_DISTANCE)
+            .tooltip_delay(DEFAULT_TOOLTIP_DELAY)
             .on_window_event(move |ctx, event| {
+                // close requests are not handled by the window itself, otherwise the window could not be closed.
+                if let WindowEvent::CloseRequested = event {
+                    return false;
+                }
+
                 ctx.get_mut::<WindowState>(id)
                     .push_action(Action::WindowEvent(event));
                 true
@@ -225,6 +284,21 @@ impl Template for Window {
                     .push_action(Action::FocusEvent(event));
                 true
             })
+            // tab is handled in the tunneling phase, before a child could mark it as handled
+            .on_preview::<KeyDownEvent, _>(move |ctx, event| {
+                if event.event.key != Key::Tab {
+                    return false;
+                }
+
+                ctx.get_mut::<WindowState>(id)
+                    .push_action(Action::KeyDown(event.event.clone()));
+                true
+            })
+            .on_key_down(move |ctx, event| {
+                ctx.get_mut::<WindowState>(id)
+                    .push_action(Action::KeyDown(event));
+                false
+            })
     }
 
     fn render_object(&self) -> Box<dyn RenderObject> {
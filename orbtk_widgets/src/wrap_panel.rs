@@ -0,0 +1,54 @@
+use crate::{api::prelude::*, proc_macros::*};
+
+widget!(
+    /// The `WrapPanel` places its children one after another in the direction of its
+    /// orientation and continues on a new line if the available space is exhausted.
+    ///
+    /// **style:** `wrap_panel`
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use orbtk::prelude::*;
+    ///
+    /// let wrap_panel = WrapPanel::new()
+    ///     .item_spacing(4)
+    ///     .line_spacing(8)
+    ///     .justification("space-between")
+    ///     .child(Button::new().text("One").build(ctx))
+    ///     .child(Button::new().text("Two").build(ctx))
+    ///     .child(Button::new().text("Three").build(ctx))
+    ///     .build(ctx);
+    /// ```
+    WrapPanel: ContextMenuHandler {
+        /// Sets or shares the orientation property. Children of a horizontal panel wrap onto
+        /// new rows, children of a vertical panel onto new columns.
+        orientation: Orientation,
+
+        /// Sets or shares the space between two children of a line.
+        item_spacing: f64,
+
+        /// Sets or shares the space between two lines.
+        line_spacing: f64,
+
+        /// Sets or shares the alignment of the children inside of a line. `Stretch` uses the
+        /// alignment of the child.
+        line_alignment: Alignment,
+
+        /// Sets or shares the distribution of the free space of a line.
+        justification: Justification
+    }
+);
+
+impl Template for WrapPanel {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("WrapPanel")
+            .orientation("horizontal")
+            .line_alignment("start")
+            .style("wrap_panel")
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(WrapLayout::new())
+    }
+}
@@ -9,6 +9,7 @@ pub struct ConstraintBuilder {
     min_height: f64,
     max_width: f64,
     max_height: f64,
+    aspect_ratio: f64,
 }
 
 /// The `ConstraintBuilder` is used to crate the constraint porperties of an entity.
@@ -79,6 +80,12 @@ impl ConstraintBuilder {
         self
     }
 
+    /// Inserts a new aspect_ratio (width / height).
+    pub fn aspect_ratio(mut self, aspect_ratio: impl Into<f64>) -> Self {
+        self.aspect_ratio = aspect_ratio.into();
+        self
+    }
+
     /// Builds the constraint.
     pub fn build(self) -> Constraint {
         Constraint {
@@ -88,6 +95,7 @@ impl ConstraintBuilder {
             min_height: self.min_height,
             max_width: self.max_width,
             max_height: self.max_height,
+            aspect_ratio: self.aspect_ratio,
         }
     }
 }
@@ -101,6 +109,7 @@ pub struct Constraint {
     min_height: f64,
     max_width: f64,
     max_height: f64,
+    aspect_ratio: f64,
 }
 
 impl Default for Constraint {
@@ -112,6 +121,7 @@ impl Default for Constraint {
             min_height: 0.0,
             max_width: f64::MAX,
             max_height: f64::MAX,
+            aspect_ratio: 0.0,
         }
     }
 }
@@ -247,10 +257,25 @@ impl Constraint {
         self.set_max_height(max_height);
     }
 
+    /// Gets aspect_ratio (width / height). `0.0` means that the aspect ratio is not constrained.
+    pub fn aspect_ratio(&self) -> f64 {
+        self.aspect_ratio
+    }
+
+    /// Sets aspect_ratio (width / height). Use `0.0` to remove the aspect ratio constraint.
+    pub fn set_aspect_ratio(&mut self, aspect_ratio: f64) {
+        self.aspect_ratio = aspect_ratio.max(0.0);
+    }
+
     /// Adjust the given `size`.
     ///
     /// Asures that size will respect the defined `box` values for min
     /// and max constraints. The value will be adapted if outside of a bound.
+    ///
+    /// If an aspect ratio is defined, a missing fixed width or height is calculated from
+    /// the other one. Without fixed values the largest size with the aspect ratio that fits
+    /// into the given size is used. If a min or max value limits one side, the other side
+    /// follows the aspect ratio. Min and max values take precedence over the aspect ratio.
     pub fn perform(&self, size: (f64, f64)) -> (f64, f64) {
         let size = {
             let width = if self.width > 0.0 { self.width } else { size.0 };
@@ -260,16 +285,56 @@ impl Constraint {
                 size.1
             };
 
-            (width, height)
+            self.apply_aspect_ratio((width, height))
         };
 
+        let (width, height) = self.constrain_size(size);
+
+        if self.aspect_ratio <= 0.0 {
+            return (width, height);
+        }
+
+        // the axis that is limited by a min or max value keeps its value, the other one
+        // follows the aspect ratio again
+        if width != size.0 && self.height <= 0.0 {
+            self.constrain_size((width, width / self.aspect_ratio))
+        } else if height != size.1 && self.width <= 0.0 {
+            self.constrain_size((height * self.aspect_ratio, height))
+        } else {
+            (width, height)
+        }
+    }
+
+    // Checks the `width` and `height` value to meet the constraint requirement.
+    fn constrain_size(&self, size: (f64, f64)) -> (f64, f64) {
         (
-            // check `width` value to meet the constraint requirement
             constrain(size.0, self.min_width, self.max_width, self.width),
-            // check `height` value to meet the constraint requirement
             constrain(size.1, self.min_height, self.max_height, self.height),
         )
     }
+
+    // Adjusts the size that is not fixed to the aspect ratio.
+    fn apply_aspect_ratio(&self, size: (f64, f64)) -> (f64, f64) {
+        let ratio = self.aspect_ratio;
+
+        if ratio <= 0.0 || (self.width > 0.0 && self.height > 0.0) {
+            return size;
+        }
+
+        if self.width > 0.0 || size.1 <= 0.0 {
+            return (size.0, size.0 / ratio);
+        }
+
+        if self.height > 0.0 || size.0 <= 0.0 {
+            return (size.1 * ratio, size.1);
+        }
+
+        if size.0 / size.1 > ratio {
+            (size.1 * ratio, size.1)
+        } else {
+            (size.0, size.0 / ratio)
+        }
+    }
 }
 
 // Check constraint for the given
@@ -456,4 +521,38 @@ mod tests {
         assert_eq!(constraint.perform((5.0, 40.0)), (10.0, 40.0));
         assert_eq!(constraint.perform((10.0, 70.0)), (10.0, 60.0));
     }
+
+    #[test]
+    fn test_perform_aspect_ratio() {
+        let mut constraint = Constraint::create().aspect_ratio(2.0).build();
+
+        // fits into the given size
+        assert_eq!(constraint.perform((100.0, 100.0)), (100.0, 50.0));
+        assert_eq!(constraint.perform((300.0, 100.0)), (200.0, 100.0));
+        assert_eq!(constraint.perform((100.0, 0.0)), (100.0, 50.0));
+
+        // derived from the fixed value
+        constraint.set_width(40.0);
+        assert_eq!(constraint.perform((100.0, 100.0)), (40.0, 20.0));
+
+        let mut constraint = Constraint::create().aspect_ratio(0.5).height(40.0).build();
+        assert_eq!(constraint.perform((100.0, 100.0)), (20.0, 40.0));
+
+        // both values fixed
+        constraint.set_width(30.0);
+        assert_eq!(constraint.perform((100.0, 100.0)), (30.0, 40.0));
+
+        // the other value follows min and max values
+        let mut constraint = Constraint::create().aspect_ratio(1.0).build();
+        constraint.set_max_width(50.0);
+        assert_eq!(constraint.perform((100.0, 100.0)), (50.0, 50.0));
+
+        let mut constraint = Constraint::create().aspect_ratio(2.0).build();
+        constraint.set_min_height(80.0);
+        assert_eq!(constraint.perform((100.0, 100.0)), (160.0, 80.0));
+
+        // min and max values take precedence over the aspect ratio
+        constraint.set_max_width(120.0);
+        assert_eq!(constraint.perform((100.0, 100.0)), (120.0, 80.0));
+    }
 }